@@ -24,11 +24,26 @@ use crate::{
     ipc,
     keyboard::KeyboardManager,
     models::{
-        overlay_resize_anchor_from_str, BootstrapOverlayState, BootstrapPayload, KeyCounters,
-        KeyMappings, OverlayBounds, OverlayResizeAnchor, SettingsDiff, SettingsState,
+        overlay_resize_anchor_from_str, AppStoreData, BootstrapOverlayState, BootstrapPayload,
+        CustomTabChangePayload, KeyCounters, KeyMappings, OverlayBounds, OverlayResizeAnchor,
+        ProfileSummary, SettingsDiff, SettingsState, TabCssResponse,
     },
-    services::{css_watcher::CssWatcher, settings::SettingsService},
-    store::AppStore,
+    services::{
+        css_watcher::CssWatcher,
+        js_watcher::JsWatcher,
+        midi_output::MidiOutput,
+        osc_output::OscOutput,
+        plugin_dir::{plugins_dir, sync_plugin_dir, PluginDirWatcher},
+        plugin_order::JsContentPayload,
+        plugin_storage::PluginStorage,
+        plugin_windows::close_inactive_plugin_windows,
+        profiles::{ProfileError, ProfileService},
+        remote_server::RemoteServer,
+        render_script::InputRecorder,
+        settings::{diff_settings, SettingsService},
+        store_watcher::StoreWatcher,
+    },
+    store::{settings_from_store, AppStore},
 };
 
 const OVERLAY_LABEL: &str = "overlay";
@@ -39,7 +54,18 @@ const OVERLAY_MARGIN: f64 = 40.0;
 pub struct AppState {
     pub store: Arc<AppStore>,
     pub settings: SettingsService,
+    pub profiles: ProfileService,
+    /// 플러그인별 네임스페이스 저장소
+    pub plugin_storage: PluginStorage,
     pub keyboard: KeyboardManager,
+    /// 외부 프로그램용 로컬 WebSocket/HTTP 서버
+    pub remote_server: RemoteServer,
+    /// 키 입력 OSC 출력
+    pub osc_output: OscOutput,
+    /// 키 입력 MIDI 출력
+    pub midi_output: MidiOutput,
+    /// 렌더 스크립트용 입력 녹화
+    pub recorder: InputRecorder,
     overlay_visible: Arc<RwLock<bool>>,
     overlay_force_close: Arc<AtomicBool>,
     keyboard_task: RwLock<Option<KeyboardDaemonTask>>,
@@ -50,6 +76,12 @@ pub struct AppState {
     raw_input_subscribers: Arc<std::sync::atomic::AtomicU32>,
     /// CSS 파일 핫리로딩 워처
     css_watcher: RwLock<Option<CssWatcher>>,
+    /// JS 플러그인 핫리로딩 워처
+    js_watcher: RwLock<Option<JsWatcher>>,
+    /// store.json 외부 수정 워처
+    store_watcher: RwLock<Option<StoreWatcher>>,
+    /// 관리 플러그인 폴더 워처
+    plugin_dir_watcher: RwLock<Option<PluginDirWatcher>>,
 }
 
 impl AppState {
@@ -59,6 +91,11 @@ impl AppState {
         let keyboard =
             KeyboardManager::new(snapshot.keys.clone(), snapshot.selected_key_type.clone());
         let settings = SettingsService::new(store.clone());
+        let profiles = ProfileService::new(store.clone());
+        let plugin_storage = PluginStorage::new(store.data_dir())?;
+        if let Err(err) = plugin_storage.migrate_legacy(&store) {
+            log::warn!("[PluginStorage] Failed to migrate legacy plugin data: {err:#}");
+        }
 
         let key_counters = Arc::new(RwLock::new(snapshot.key_counters.clone()));
         Self::sync_counters_with_keys_impl(&key_counters, &snapshot.keys);
@@ -68,7 +105,13 @@ impl AppState {
         Ok(Self {
             store,
             settings,
+            profiles,
+            plugin_storage,
             keyboard,
+            remote_server: RemoteServer::default(),
+            osc_output: OscOutput::default(),
+            midi_output: MidiOutput::default(),
+            recorder: InputRecorder::default(),
             overlay_visible: Arc::new(RwLock::new(false)),
             overlay_force_close: Arc::new(AtomicBool::new(false)),
             keyboard_task: RwLock::new(None),
@@ -77,6 +120,9 @@ impl AppState {
             active_keys,
             raw_input_subscribers: Arc::new(std::sync::atomic::AtomicU32::new(0)),
             css_watcher: RwLock::new(None),
+            js_watcher: RwLock::new(None),
+            store_watcher: RwLock::new(None),
+            plugin_dir_watcher: RwLock::new(None),
         })
     }
 
@@ -96,6 +142,36 @@ impl AppState {
         self.start_keyboard_hook(app.clone())?;
         // CSS 핫리로딩 워처 초기화
         self.initialize_css_watcher(app);
+        // store.json 외부 수정 감지
+        match StoreWatcher::start(self.store.clone(), app.clone()) {
+            Ok(watcher) => *self.store_watcher.write() = Some(watcher),
+            Err(err) => log::warn!("[AppState] failed to start store watcher: {err}"),
+        }
+        // 관리 플러그인 폴더 스캔 후 감시
+        if let Err(err) = sync_plugin_dir(self, app) {
+            log::warn!("[AppState] failed to sync plugin dir: {err:#}");
+        }
+        match PluginDirWatcher::start(plugins_dir(self.store.data_dir()), app.clone()) {
+            Ok(watcher) => *self.plugin_dir_watcher.write() = Some(watcher),
+            Err(err) => log::warn!("[AppState] failed to start plugin dir watcher: {err}"),
+        }
+        // JS 플러그인 핫리로딩 워처 초기화
+        match JsWatcher::new(self.store.clone(), app.clone()) {
+            Ok(watcher) => {
+                watcher.resync_from_store();
+                *self.js_watcher.write() = Some(watcher);
+            }
+            Err(err) => log::warn!("[AppState] failed to start JS watcher: {err}"),
+        }
+        if let Err(err) = self.restart_remote_server(app) {
+            log::warn!("[AppState] failed to start remote server: {err:#}");
+        }
+        if let Err(err) = self.restart_osc_output() {
+            log::warn!("[AppState] failed to start OSC output: {err:#}");
+        }
+        if let Err(err) = self.restart_midi_output() {
+            log::warn!("[AppState] failed to start MIDI output: {err:#}");
+        }
         Ok(())
     }
 
@@ -176,9 +252,199 @@ impl AppState {
             self.key_counter_enabled.store(value, Ordering::SeqCst);
         }
         app.emit("settings:changed", diff)?;
+        self.remote_server.broadcast("settings:changed", diff);
         Ok(())
     }
 
+    /// 스토어의 설정에 맞춰 로컬 서버 (재)시작
+    pub fn restart_remote_server(&self, app: &AppHandle) -> Result<()> {
+        let settings = self.store.read(|data| data.remote_server.clone());
+        self.remote_server.restart(app, &settings)
+    }
+
+    /// 스토어의 설정에 맞춰 OSC 출력 (재)시작
+    pub fn restart_osc_output(&self) -> Result<()> {
+        let settings = self.store.read(|data| data.osc_output.clone());
+        self.osc_output.restart(&settings)
+    }
+
+    /// 스토어의 설정에 맞춰 MIDI 출력 (재)시작
+    pub fn restart_midi_output(&self) -> Result<()> {
+        let settings = self.store.read(|data| data.midi_output.clone());
+        self.midi_output.restart(&settings)
+    }
+
+    /// 스토어가 통째로 바뀐 뒤(프로필 전환 등) 런타임 상태를 맞추고 달라진 부분만 이벤트로 알림
+    pub fn sync_with_store(&self, app: &AppHandle, previous: &AppStoreData) -> Result<()> {
+        let current = self.store.snapshot();
+
+        if previous.keys != current.keys {
+            self.keyboard.update_mappings(current.keys.clone());
+            app.emit("keys:changed", &current.keys)?;
+        }
+        if previous.key_positions != current.key_positions {
+            app.emit("positions:changed", &current.key_positions)?;
+        }
+        if previous.custom_tabs != current.custom_tabs
+            || previous.selected_key_type != current.selected_key_type
+        {
+            app.emit(
+                "customTabs:changed",
+                &CustomTabChangePayload {
+                    custom_tabs: current.custom_tabs.clone(),
+                    selected_key_type: current.selected_key_type.clone(),
+                },
+            )?;
+        }
+        if self.keyboard.current_mode() != current.selected_key_type {
+            self.keyboard.set_mode(current.selected_key_type.clone());
+            app.emit(
+                "keys:mode-changed",
+                &json!({ "mode": &current.selected_key_type }),
+            )?;
+        }
+
+        // 스토어의 카운터가 바뀐 경우에만 메모리 카운터를 교체 (평소에는 메모리 값이 최신)
+        if previous.key_counters != current.key_counters {
+            *self.key_counters.write() = current.key_counters.clone();
+        }
+        if previous.keys != current.keys || previous.key_counters != current.key_counters {
+            self.sync_counters_with_keys(&current.keys);
+            app.emit("keys:counters", &self.snapshot_key_counters())?;
+        }
+
+        let diff = diff_settings(
+            &settings_from_store(previous),
+            &settings_from_store(&current),
+        );
+        if diff.changed_count() > 0 {
+            self.emit_settings_changed(&diff, app)?;
+        }
+
+        if previous.use_custom_css != current.use_custom_css {
+            app.emit("css:use", &json!({ "enabled": current.use_custom_css }))?;
+        }
+        if previous.custom_css != current.custom_css {
+            app.emit("css:content", &current.custom_css)?;
+        }
+        let tab_ids: HashSet<&String> = previous
+            .tab_css_overrides
+            .keys()
+            .chain(current.tab_css_overrides.keys())
+            .collect();
+        for tab_id in tab_ids {
+            let before = previous.tab_css_overrides.get(tab_id);
+            let after = current.tab_css_overrides.get(tab_id);
+            if before != after {
+                app.emit(
+                    "tabCss:changed",
+                    &TabCssResponse {
+                        tab_id: tab_id.clone(),
+                        css: after.cloned(),
+                    },
+                )?;
+            }
+        }
+        if previous.custom_css != current.custom_css
+            || previous.use_custom_css != current.use_custom_css
+            || previous.tab_css_overrides != current.tab_css_overrides
+        {
+            if let Some(watcher) = self.css_watcher.read().as_ref() {
+                watcher.resync_from_store();
+            }
+        }
+
+        if previous.use_custom_js != current.use_custom_js
+            || previous.custom_js != current.custom_js
+        {
+            close_inactive_plugin_windows(app);
+        }
+        if previous.use_custom_js != current.use_custom_js {
+            app.emit("js:use", &json!({ "enabled": current.use_custom_js }))?;
+        }
+        if previous.custom_js != current.custom_js {
+            app.emit(
+                "js:content",
+                &JsContentPayload::new(current.custom_js.clone()),
+            )?;
+            self.resync_js_watcher();
+        }
+
+        if previous.remote_server != current.remote_server {
+            if let Err(err) = self.restart_remote_server(app) {
+                log::warn!("[AppState] failed to restart remote server: {err:#}");
+            }
+        }
+        if previous.osc_output != current.osc_output {
+            if let Err(err) = self.restart_osc_output() {
+                log::warn!("[AppState] failed to restart OSC output: {err:#}");
+            }
+        }
+        if previous.midi_output != current.midi_output {
+            if let Err(err) = self.restart_midi_output() {
+                log::warn!("[AppState] failed to restart MIDI output: {err:#}");
+            }
+        }
+
+        if previous.overlay_bounds != current.overlay_bounds {
+            if let Some(bounds) = current.overlay_bounds.as_ref() {
+                self.move_overlay_to(app, bounds)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 열려 있는 오버레이 창을 저장된 위치/크기로 이동
+    fn move_overlay_to(&self, app: &AppHandle, bounds: &OverlayBounds) -> Result<()> {
+        let Some(window) = app.get_webview_window(OVERLAY_LABEL) else {
+            return Ok(());
+        };
+        window.set_size(LogicalSize::new(bounds.width, bounds.height))?;
+        window.set_position(LogicalPosition::new(bounds.x, bounds.y))?;
+        app.emit(
+            "overlay:resized",
+            &json!({
+                "x": bounds.x,
+                "y": bounds.y,
+                "width": bounds.width,
+                "height": bounds.height,
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// 프로필 목록 변경 알림
+    pub fn emit_profiles_changed(&self, app: &AppHandle) -> Result<()> {
+        let profiles = self.profiles.list()?;
+        app.emit(
+            "profiles:changed",
+            &json!({
+                "profiles": profiles,
+                "activeId": self.profiles.active_id(),
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// 현재 설정을 활성 프로필에 저장한 뒤 대상 프로필로 전환 (재시작 없이 즉시 적용)
+    pub fn switch_profile(
+        &self,
+        app: &AppHandle,
+        id: &str,
+    ) -> Result<ProfileSummary, ProfileError> {
+        // 프로필에 최신 카운터가 저장되도록 메모리 카운터를 먼저 반영
+        self.persist_key_counters()?;
+        let (profile, previous) = self.profiles.switch(id)?;
+        // 다른 프로필의 상태로 되돌리지 않도록 기록 초기화
+        self.store.clear_history();
+        self.sync_with_store(app, &previous)?;
+        if let Err(err) = self.emit_profiles_changed(app) {
+            log::warn!("failed to emit profiles:changed: {err}");
+        }
+        Ok(profile)
+    }
+
     pub fn set_overlay_visibility(&self, app: &AppHandle, visible: bool) -> Result<()> {
         log::debug!("[IPC] set_overlay_visibility: visible={}", visible);
         let window = self.ensure_overlay_window(app)?;
@@ -207,9 +473,20 @@ impl AppState {
     }
 
     pub fn shutdown(&self) {
+        self.store_watcher.write().take();
+        self.plugin_dir_watcher.write().take();
+        self.remote_server.stop();
+        self.osc_output.stop();
+        self.midi_output.stop();
         if let Err(err) = self.persist_key_counters() {
             log::warn!("failed to persist key counters during shutdown: {err}");
         }
+        if let Err(err) = self.store.shutdown() {
+            log::warn!("failed to flush store during shutdown: {err}");
+        }
+        if let Err(err) = self.plugin_storage.shutdown() {
+            log::warn!("failed to flush plugin storage during shutdown: {err}");
+        }
         if let Some(task) = self.keyboard_task.write().take() {
             drop(task);
         }
@@ -217,6 +494,9 @@ impl AppState {
         if let Some(watcher) = self.css_watcher.write().take() {
             watcher.shutdown();
         }
+        if let Some(watcher) = self.js_watcher.write().take() {
+            watcher.shutdown();
+        }
     }
 
     pub fn set_overlay_anchor(&self, app: &AppHandle, anchor: &str) -> Result<String> {
@@ -480,7 +760,9 @@ impl AppState {
 
                             // Emit raw input stream only when there are subscribers
                             let app_state = app_handle.state::<AppState>();
-                            if app_state.raw_input_subscriber_count() > 0 {
+                            let has_raw_subscribers = app_state.raw_input_subscriber_count() > 0;
+                            let has_remote_clients = app_state.remote_server.has_clients();
+                            if has_raw_subscribers || has_remote_clients {
                                 let raw_payload = json!({
                                     "label": primary_label,
                                     "labels": labels_for_emit.clone(),
@@ -488,13 +770,18 @@ impl AppState {
                                     "device": device_str,
                                 });
                                 
-                                // Emit to main window first, then fallback to app-wide emit
-                                if let Some(main) = app_handle.get_webview_window("main") {
-                                    let _ = main.emit("input:raw", &raw_payload);
+                                if has_raw_subscribers {
+                                    // Emit to main window first, then fallback to app-wide emit
+                                    if let Some(main) = app_handle.get_webview_window("main") {
+                                        let _ = main.emit("input:raw", &raw_payload);
+                                    }
+                                    // Also emit to overlay for plugins running there
+                                    if let Some(overlay) = app_handle.get_webview_window(OVERLAY_LABEL) {
+                                        let _ = overlay.emit("input:raw", &raw_payload);
+                                    }
                                 }
-                                // Also emit to overlay for plugins running there
-                                if let Some(overlay) = app_handle.get_webview_window(OVERLAY_LABEL) {
-                                    let _ = overlay.emit("input:raw", &raw_payload);
+                                if has_remote_clients {
+                                    app_state.remote_server.broadcast("input:raw", &raw_payload);
                                 }
                             }
 
@@ -504,29 +791,46 @@ impl AppState {
                                 continue;
                             };
                             let mode = keyboard.current_mode();
+                            // 키 반복 입력을 제외한 눌림/뗌 전환 여부
+                            let mut is_transition = state == "UP";
                             if state == "DOWN" {
                                 if app_state.register_key_down(&mode, &key_label) {
+                                    is_transition = true;
                                     if let Some(count) = app_state.increment_key_counter(&mode, &key_label) {
                                         log::trace!(
                                             "[IPC] emit keys:counter: mode={}, key={}, count={}",
                                             mode, key_label, count
                                         );
-                                        if let Err(err) = app_handle.emit(
-                                            "keys:counter",
-                                            &json!({
-                                                "mode": mode.clone(),
-                                                "key": key_label.clone(),
-                                                "count": count,
-                                            }),
-                                        ) {
+                                        let counter_payload = json!({
+                                            "mode": mode.clone(),
+                                            "key": key_label.clone(),
+                                            "count": count,
+                                        });
+                                        if let Err(err) = app_handle.emit("keys:counter", &counter_payload) {
                                             error!("failed to emit keys:counter event: {err}");
                                         }
+                                        app_state.remote_server.broadcast("keys:counter", &counter_payload);
                                     }
                                 }
                             } else {
                                 app_state.register_key_up(&mode, &key_label);
                             }
+                            if is_transition {
+                                app_state.osc_output.send_key(
+                                    &mode,
+                                    &key_label,
+                                    state == "DOWN",
+                                    app_state.key_count(&mode, &key_label),
+                                );
+                                app_state.midi_output.send_key(
+                                    &mode,
+                                    &key_label,
+                                    state == "DOWN",
+                                );
+                                app_state.recorder.record(&mode, &key_label, state == "DOWN");
+                            }
                             let payload = json!({ "key": key_label, "state": state, "mode": mode });
+                            app_state.remote_server.broadcast("keys:state", &payload);
 
                             let mut emitted = false;
                             if let Some(overlay) = overlay_window.as_ref() {
@@ -844,6 +1148,15 @@ impl AppState {
         Some(*count)
     }
 
+    pub fn key_count(&self, mode: &str, key: &str) -> u32 {
+        self.key_counters
+            .read()
+            .get(mode)
+            .and_then(|counts| counts.get(key))
+            .copied()
+            .unwrap_or(0)
+    }
+
     pub fn snapshot_key_counters(&self) -> KeyCounters {
         self.key_counters.read().clone()
     }
@@ -979,6 +1292,13 @@ impl AppState {
             watcher.unwatch_tab(tab_id);
         }
     }
+
+    /// 플러그인 목록이 바뀐 뒤 JS 워칭 폴더 갱신
+    pub fn resync_js_watcher(&self) {
+        if let Some(watcher) = self.js_watcher.read().as_ref() {
+            watcher.resync_from_store();
+        }
+    }
 }
 
 impl Drop for AppState {
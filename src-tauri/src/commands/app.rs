@@ -1,8 +1,20 @@
 use tauri::State;
 
-use crate::{app_state::AppState, models::BootstrapPayload};
+use crate::{
+    app_state::AppState,
+    launch_args::{LaunchNotice, LaunchNotices},
+    models::BootstrapPayload,
+};
 
 #[tauri::command(permission = "dmnote-allow-all")]
 pub fn app_bootstrap(state: State<'_, AppState>) -> Result<BootstrapPayload, String> {
     Ok(state.bootstrap_payload())
 }
+
+/// 명령줄로 적용한 프리셋/프로필 결과 가져오기 (가져간 결과는 비움)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn app_take_launch_notices(
+    notices: State<'_, LaunchNotices>,
+) -> Result<Vec<LaunchNotice>, String> {
+    Ok(notices.take())
+}
@@ -1,5 +1,22 @@
+use serde::Serialize;
 use serde_json::Value;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewWindow};
+
+use crate::services::bridge_rpc::{call_timeout, BridgeRpc};
+use crate::services::plugin_windows::PluginWindows;
+
+/// 대상 윈도우로 전달되는 브릿지 요청
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct BridgeRequest {
+    request_id: String,
+    method: String,
+    data: Option<Value>,
+    /// 요청을 보낸 윈도우 레이블
+    from: String,
+    /// 요청을 받을 윈도우 레이블
+    target: String,
+}
 
 /// 플러그인 간 윈도우 브릿지 메시지 전송
 /// 모든 윈도우에 브로드캐스트
@@ -26,10 +43,11 @@ pub async fn plugin_bridge_send(
     Ok(())
 }
 
-/// 특정 윈도우에만 메시지 전송
+/// 특정 윈도우에만 메시지 전송 (앱 윈도우 또는 플러그인 윈도우 레이블)
 #[tauri::command]
 pub async fn plugin_bridge_send_to(
     app: AppHandle,
+    windows: State<'_, PluginWindows>,
     target: String,
     message_type: String,
     data: Option<Value>,
@@ -47,14 +65,10 @@ pub async fn plugin_bridge_send_to(
     });
 
     // 타겟 윈도우 레이블 결정
-    let window_label = match target.as_str() {
-        "main" => "main",
-        "overlay" => "overlay",
-        _ => return Err(format!("Unknown target window: {}", target)),
-    };
+    let window_label = windows.resolve_target(&target)?;
 
     // 특정 윈도우에만 이벤트 전송
-    if let Some(window) = app.get_webview_window(window_label) {
+    if let Some(window) = app.get_webview_window(&window_label) {
         window
             .emit("plugin-bridge:message", payload)
             .map_err(|e| e.to_string())?;
@@ -63,3 +77,70 @@ pub async fn plugin_bridge_send_to(
         Err(format!("Window '{}' not found", window_label))
     }
 }
+
+/// 다른 윈도우에 등록된 핸들러를 호출하고 응답을 기다림
+///
+/// 응답이 없으면 `timeout_ms`(기본 5초, 최대 60초) 뒤 `timeout` 오류를 반환합니다.
+#[tauri::command]
+pub async fn plugin_bridge_call(
+    window: WebviewWindow,
+    rpc: State<'_, BridgeRpc>,
+    windows: State<'_, PluginWindows>,
+    target: String,
+    method: String,
+    data: Option<Value>,
+    timeout_ms: Option<u64>,
+) -> Result<Value, String> {
+    let app = window.app_handle();
+    let window_label = windows.resolve_target(&target)?;
+    log::debug!(
+        "[IPC] plugin_bridge_call: from={}, target={}, method={}",
+        window.label(),
+        window_label,
+        method
+    );
+    if app.get_webview_window(&window_label).is_none() {
+        return Err(format!("Window '{}' not found", window_label));
+    }
+
+    let (request_id, receiver) = rpc.register(&window_label);
+    let request = BridgeRequest {
+        request_id: request_id.clone(),
+        method,
+        data,
+        from: window.label().to_string(),
+        target: window_label.clone(),
+    };
+    if let Err(err) = app.emit_to(window_label.as_str(), "plugin-bridge:request", &request) {
+        rpc.cancel(&request_id);
+        return Err(err.to_string());
+    }
+
+    let timeout = call_timeout(timeout_ms);
+    let received = tauri::async_runtime::spawn_blocking(move || receiver.recv_timeout(timeout))
+        .await
+        .map_err(|e| e.to_string())?;
+    match received {
+        Ok(reply) => reply,
+        Err(_) => {
+            rpc.cancel(&request_id);
+            Err("timeout".to_string())
+        }
+    }
+}
+
+/// 브릿지 요청에 대한 응답 (`error`가 있으면 호출자에게 오류로 전달)
+#[tauri::command]
+pub fn plugin_bridge_reply(
+    window: WebviewWindow,
+    rpc: State<'_, BridgeRpc>,
+    request_id: String,
+    data: Option<Value>,
+    error: Option<String>,
+) -> Result<(), String> {
+    let reply = match error {
+        Some(error) => Err(error),
+        None => Ok(data.unwrap_or(Value::Null)),
+    };
+    rpc.resolve(&request_id, window.label(), reply)
+}
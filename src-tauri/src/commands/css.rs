@@ -4,7 +4,10 @@ use rfd::FileDialog;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter, State};
 
-use crate::{app_state::AppState, models::{CustomCss, TabCss, TabCssOverrides}};
+use crate::{
+    app_state::AppState,
+    models::{CustomCss, TabCss, TabCssOverrides, TabCssResponse},
+};
 
 #[derive(Serialize)]
 pub struct CssToggleResponse {
@@ -31,13 +34,6 @@ pub struct CssLoadResponse {
 
 // ========== 탭별 CSS 응답 타입 ==========
 
-#[derive(Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct TabCssResponse {
-    pub tab_id: String,
-    pub css: Option<TabCss>,
-}
-
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TabCssLoadResponse {
@@ -0,0 +1,45 @@
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use crate::{app_state::AppState, models::AppStoreData};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryResult {
+    /// 실제로 상태가 바뀌었는지 (기록이 없으면 false)
+    pub applied: bool,
+    pub can_undo: bool,
+    pub can_redo: bool,
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn history_undo(state: State<'_, AppState>, app: AppHandle) -> Result<HistoryResult, String> {
+    let previous = state.store.undo().map_err(|err| err.to_string())?;
+    finish(&state, &app, previous)
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn history_redo(state: State<'_, AppState>, app: AppHandle) -> Result<HistoryResult, String> {
+    let previous = state.store.redo().map_err(|err| err.to_string())?;
+    finish(&state, &app, previous)
+}
+
+/// 복원된 상태를 키보드/CSS 워처에 반영하고 변경 이벤트 재전송
+fn finish(
+    state: &AppState,
+    app: &AppHandle,
+    previous: Option<AppStoreData>,
+) -> Result<HistoryResult, String> {
+    let applied = previous.is_some();
+    if let Some(previous) = previous {
+        state
+            .sync_with_store(app, &previous)
+            .map_err(|err| err.to_string())?;
+    }
+    let (can_undo, can_redo) = state.store.history_state();
+    Ok(HistoryResult {
+        applied,
+        can_undo,
+        can_redo,
+    })
+}
@@ -1,14 +1,19 @@
-use std::{fs, path::Path};
+use std::{fs, path::PathBuf};
 
 use rfd::FileDialog;
 use serde::Serialize;
 use tauri::{AppHandle, Emitter, State};
-use uuid::Uuid;
 use log::info;
 
 use crate::{
     app_state::AppState,
-    models::{CustomJs, JsPlugin},
+    models::{CustomJs, JsPlugin, JsPluginError, PluginPermission},
+    services::plugin_dir::plugins_dir,
+    services::plugin_manifest::{
+        check_compatibility, install_plugin, parse_manifest, plugin_from_file, PluginInstall,
+    },
+    services::plugin_order::JsContentPayload,
+    services::plugin_windows::close_inactive_plugin_windows,
 };
 
 #[derive(Serialize)]
@@ -23,26 +28,14 @@ pub struct JsSetContentResponse {
     pub error: Option<String>,
 }
 
-#[derive(Serialize, Clone)]
-pub struct JsPluginError {
-    pub path: String,
-    pub error: String,
-}
-
-impl JsPluginError {
-    fn new(path: String, error: impl Into<String>) -> Self {
-        Self {
-            path,
-            error: error.into(),
-        }
-    }
-}
-
 #[derive(Serialize)]
 pub struct JsLoadResponse {
     pub success: bool,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub added: Vec<JsPlugin>,
+    /// 선언된 ID가 같은 기존 플러그인을 갱신한 경우
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub updated: Vec<JsPlugin>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<JsPluginError>,
 }
@@ -74,7 +67,9 @@ pub struct JsPluginUpdateResponse {
 }
 
 fn emit_js_state(app: &AppHandle, script: &CustomJs) -> Result<(), String> {
-    app.emit("js:content", script)
+    // 비활성화되거나 삭제된 플러그인의 윈도우는 목록 전파 전에 닫음
+    close_inactive_plugin_windows(app);
+    app.emit("js:content", &JsContentPayload::new(script.clone()))
         .map_err(|err| err.to_string())
 }
 
@@ -92,16 +87,20 @@ fn get_normalized_script(state: &State<AppState>) -> Result<CustomJs, String> {
 }
 
 fn persist_script(state: &State<AppState>, script: &CustomJs) -> Result<CustomJs, String> {
-    state.store.update(|store| {
-        store.custom_js = script.clone();
-    })
-    .map(|data| data.custom_js.clone())
-    .map_err(|err| err.to_string())
+    let updated = state
+        .store
+        .update(|store| {
+            store.custom_js = script.clone();
+        })
+        .map(|data| data.custom_js.clone())
+        .map_err(|err| err.to_string())?;
+    state.resync_js_watcher();
+    Ok(updated)
 }
 
 #[tauri::command(permission = "dmnote-allow-all")]
-pub fn js_get(state: State<'_, AppState>) -> Result<CustomJs, String> {
-    get_normalized_script(&state)
+pub fn js_get(state: State<'_, AppState>) -> Result<JsContentPayload, String> {
+    get_normalized_script(&state).map(JsContentPayload::new)
 }
 
 #[tauri::command(permission = "dmnote-allow-all")]
@@ -128,6 +127,8 @@ pub fn js_toggle(
     if enabled {
         let script = get_normalized_script(&state)?;
         emit_js_state(&app, &script)?;
+    } else {
+        close_inactive_plugin_windows(&app);
     }
 
     Ok(JsToggleResponse { enabled })
@@ -162,8 +163,10 @@ pub fn js_set_content(
         script.content = content.clone();
     } else if let Some(plugin) = script.plugins.iter_mut().find(|plugin| plugin.enabled) {
         plugin.content = content.clone();
+        plugin.manifest = parse_manifest(&content);
     } else if let Some(plugin) = script.plugins.first_mut() {
         plugin.content = content.clone();
+        plugin.manifest = parse_manifest(&content);
     }
 
     let _ = script.normalize();
@@ -176,22 +179,6 @@ pub fn js_set_content(
     })
 }
 
-fn make_plugin_from_path(path: &Path, content: String) -> JsPlugin {
-    let name = path
-        .file_name()
-        .and_then(|value| value.to_str())
-        .map(|value| value.to_string())
-        .unwrap_or_else(|| "plugin.js".to_string());
-
-    JsPlugin {
-        id: Uuid::new_v4().to_string(),
-        name,
-        path: Some(path.to_string_lossy().to_string()),
-        content,
-        enabled: true,
-    }
-}
-
 #[tauri::command(permission = "dmnote-allow-all")]
 pub fn js_load(state: State<'_, AppState>, app: AppHandle) -> Result<JsLoadResponse, String> {
     let Some(paths) = FileDialog::new()
@@ -201,51 +188,81 @@ pub fn js_load(state: State<'_, AppState>, app: AppHandle) -> Result<JsLoadRespo
         return Ok(JsLoadResponse {
             success: false,
             added: Vec::new(),
+            updated: Vec::new(),
             errors: Vec::new(),
         });
     };
 
     let mut script = get_normalized_script(&state)?;
-    let mut added = Vec::new();
+    let app_version = app.package_info().version.to_string();
+    let mut added: Vec<JsPlugin> = Vec::new();
+    let mut updated: Vec<JsPlugin> = Vec::new();
     let mut errors = Vec::new();
 
     for path in paths {
-        match fs::read_to_string(&path) {
-            Ok(content) => {
-                added.push(make_plugin_from_path(&path, content));
-            }
+        let path_string = path.to_string_lossy().to_string();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
             Err(err) => {
-                errors.push(JsPluginError::new(
-                    path.to_string_lossy().to_string(),
-                    err.to_string(),
-                ));
+                errors.push(JsPluginError::new(path_string, err.to_string()));
+                continue;
+            }
+        };
+        let plugin = plugin_from_file(&path, content);
+        if let Err(error) = check_compatibility(&plugin, &app_version) {
+            errors.push(JsPluginError::new(path_string, error));
+            continue;
+        }
+
+        match install_plugin(&mut script.plugins, plugin) {
+            PluginInstall::Added(plugin) => added.push(plugin),
+            PluginInstall::Updated(plugin) => {
+                // 같은 선택 안에서 중복된 경우는 새로 추가된 항목을 덮어씀
+                if let Some(item) = added.iter_mut().find(|item| item.id == plugin.id) {
+                    *item = plugin;
+                } else {
+                    updated.retain(|item| item.id != plugin.id);
+                    updated.push(plugin);
+                }
             }
         }
     }
 
-    if added.is_empty() {
+    if added.is_empty() && updated.is_empty() {
         return Ok(JsLoadResponse {
             success: false,
             added,
+            updated,
             errors,
         });
     }
 
-    script.plugins.extend(added.clone());
     script.path = None;
     script.content.clear();
     let _ = script.normalize();
 
-    let updated = persist_script(&state, &script)?;
-    emit_js_state(&app, &updated)?;
+    let persisted = persist_script(&state, &script)?;
+    emit_js_state(&app, &persisted)?;
 
     Ok(JsLoadResponse {
         success: true,
         added,
+        updated,
         errors,
     })
 }
 
+/// 관리 플러그인 폴더를 파일 탐색기로 열고 경로 반환
+///
+/// 이 폴더에 넣은 `.js` 파일은 자동으로 플러그인 목록에 추가됩니다.
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn js_open_plugin_dir(state: State<'_, AppState>) -> Result<String, String> {
+    let dir = plugins_dir(state.store.data_dir());
+    fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    open::that(&dir).map_err(|err| err.to_string())?;
+    Ok(dir.to_string_lossy().to_string())
+}
+
 #[tauri::command(permission = "dmnote-allow-all")]
 pub fn js_reload(state: State<'_, AppState>, app: AppHandle) -> Result<JsReloadResponse, String> {
     let mut script = get_normalized_script(&state)?;
@@ -258,7 +275,8 @@ pub fn js_reload(state: State<'_, AppState>, app: AppHandle) -> Result<JsReloadR
         };
         match fs::read_to_string(path) {
             Ok(content) => {
-                plugin.content = content.clone();
+                plugin.manifest = parse_manifest(&content);
+                plugin.content = content;
                 updated_plugins.push(plugin.clone());
             }
             Err(err) => errors.push(JsPluginError::new(path.clone(), err.to_string())),
@@ -280,6 +298,7 @@ pub fn js_remove_plugin(
     state: State<'_, AppState>,
     app: AppHandle,
     id: String,
+    purge_data: Option<bool>,
 ) -> Result<JsRemoveResponse, String> {
     let mut script = get_normalized_script(&state)?;
     info!("js_remove_plugin: requested id={}", id);
@@ -292,6 +311,9 @@ pub fn js_remove_plugin(
             .collect::<Vec<_>>()
             .join(",")
     );
+    let removed = script.plugins.iter().find(|plugin| plugin.id == id);
+    let removed_path = removed.and_then(|plugin| plugin.path.clone());
+    let storage_namespace = removed.map(|plugin| plugin.storage_namespace());
     let initial_len = script.plugins.len();
     script.plugins.retain(|plugin| plugin.id != id);
     if script.plugins.len() == initial_len {
@@ -307,6 +329,23 @@ pub fn js_remove_plugin(
     let updated = persist_script(&state, &script)?;
     emit_js_state(&app, &updated)?;
 
+    // 관리 폴더의 파일은 남겨 두면 다음 스캔에서 다시 추가되므로 함께 삭제
+    if let Some(path) = removed_path.map(PathBuf::from) {
+        if path.parent() == Some(plugins_dir(state.store.data_dir()).as_path()) {
+            if let Err(err) = fs::remove_file(&path) {
+                log::warn!("js_remove_plugin: failed to delete {:?}: {}", path, err);
+            }
+        }
+    }
+
+    // 요청한 경우 플러그인 저장소 데이터도 함께 삭제 (저장소는 런타임의 플러그인 ID로 구분)
+    if let Some(namespace) = storage_namespace.filter(|_| purge_data.unwrap_or(false)) {
+        state
+            .plugin_storage
+            .purge(&namespace)
+            .map_err(|err| err.to_string())?;
+    }
+
     Ok(JsRemoveResponse {
         success: true,
         removed_id: Some(id),
@@ -375,3 +414,61 @@ pub fn js_set_plugin_enabled(
         error: None,
     })
 }
+
+/// 플러그인 권한 허용 목록 설정 (요청할 수 없는 권한은 무시)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn js_set_plugin_permissions(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    id: String,
+    permissions: Vec<PluginPermission>,
+) -> Result<JsPluginUpdateResponse, String> {
+    let mut script = get_normalized_script(&state)?;
+    let Some(plugin) = script.plugins.iter_mut().find(|plugin| plugin.id == id) else {
+        return Ok(JsPluginUpdateResponse {
+            success: false,
+            plugin: None,
+            error: Some("not-found".to_string()),
+        });
+    };
+
+    let requestable = plugin.requestable_permissions().to_vec();
+    plugin.granted_permissions = Some(
+        permissions
+            .into_iter()
+            .filter(|permission| requestable.contains(permission))
+            .collect(),
+    );
+    let plugin = plugin.clone();
+
+    let updated = persist_script(&state, &script)?;
+    emit_js_state(&app, &updated)?;
+
+    Ok(JsPluginUpdateResponse {
+        success: true,
+        plugin: Some(plugin),
+        error: None,
+    })
+}
+
+/// 플러그인 로드 순서 변경 (`ids` 순서대로 앞에 배치하고, 빠진 플러그인은 기존 순서대로 뒤에 배치)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn js_reorder_plugins(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    ids: Vec<String>,
+) -> Result<JsContentPayload, String> {
+    let mut script = get_normalized_script(&state)?;
+    script.plugins.sort_by_key(|plugin| {
+        ids.iter()
+            .position(|id| *id == plugin.id)
+            .unwrap_or(ids.len())
+    });
+    for (index, plugin) in script.plugins.iter_mut().enumerate() {
+        plugin.order = Some(index as u32);
+    }
+
+    let updated = persist_script(&state, &script)?;
+    emit_js_state(&app, &updated)?;
+    Ok(JsContentPayload::new(updated))
+}
@@ -7,8 +7,8 @@ use crate::{
     app_state::AppState,
     defaults::{default_keys, default_positions},
     models::{
-        CustomCssPatch, CustomTab, KeyCounters, KeyMappings, KeyPositions, NoteSettings,
-        NoteSettingsPatch, SettingsPatchInput,
+        CustomCssPatch, CustomTab, CustomTabChangePayload, KeyCounters, KeyMappings, KeyPositions,
+        NoteSettings, NoteSettingsPatch, SettingsPatchInput, MAX_CUSTOM_TABS,
     },
 };
 #[derive(Serialize)]
@@ -31,12 +31,6 @@ pub struct ResetModeResponse {
     pub mode: String,
 }
 
-#[derive(Serialize)]
-pub struct CustomTabChangePayload {
-    pub custom_tabs: Vec<CustomTab>,
-    pub selected_key_type: String,
-}
-
 #[derive(Serialize)]
 pub struct CustomTabCreateResult {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -299,7 +293,7 @@ pub fn custom_tabs_create(
             error: Some("duplicate-name".to_string()),
         });
     }
-    if snapshot.custom_tabs.len() >= 5 {
+    if snapshot.custom_tabs.len() >= MAX_CUSTOM_TABS {
         return Ok(CustomTabCreateResult {
             result: None,
             error: Some("max-reached".to_string()),
@@ -0,0 +1,113 @@
+use tauri::State;
+
+use crate::{
+    app_state::AppState,
+    models::{MidiMapping, MidiOutputPatch},
+    services::midi_output::{self, MidiOutputStatus},
+};
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn midi_output_get(state: State<'_, AppState>) -> Result<MidiOutputStatus, String> {
+    let settings = state.store.read(|data| data.midi_output.clone());
+    Ok(state.midi_output.status(settings))
+}
+
+/// MIDI 출력 설정 변경 후 출력 재시작
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn midi_output_update(
+    state: State<'_, AppState>,
+    patch: MidiOutputPatch,
+) -> Result<MidiOutputStatus, String> {
+    if patch
+        .channel
+        .is_some_and(|channel| !(1..=16).contains(&channel))
+    {
+        return Err("invalid-channel".to_string());
+    }
+    if patch
+        .velocity
+        .is_some_and(|velocity| !(1..=127).contains(&velocity))
+    {
+        return Err("invalid-velocity".to_string());
+    }
+    let updated = state
+        .store
+        .update(|data| {
+            let settings = &mut data.midi_output;
+            if let Some(enabled) = patch.enabled {
+                settings.enabled = enabled;
+            }
+            if let Some(backend) = patch.backend {
+                settings.backend = backend;
+            }
+            if let Some(port_name) = patch.port_name.as_deref() {
+                settings.port_name = port_name.trim().to_string();
+            }
+            if let Some(file_path) = patch.file_path.as_deref() {
+                settings.file_path = file_path.trim().to_string();
+            }
+            if let Some(channel) = patch.channel {
+                settings.channel = channel;
+            }
+            if let Some(velocity) = patch.velocity {
+                settings.velocity = velocity;
+            }
+        })
+        .map_err(|err| err.to_string())?;
+
+    state
+        .restart_midi_output()
+        .map_err(|err| format!("{err:#}"))?;
+    Ok(state.midi_output.status(updated.midi_output))
+}
+
+/// 모드의 키 하나에 MIDI 매핑 지정 (`mapping`이 없으면 해제)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn midi_output_set_mapping(
+    state: State<'_, AppState>,
+    mode: String,
+    key: String,
+    mapping: Option<MidiMapping>,
+) -> Result<MidiOutputStatus, String> {
+    let out_of_range = match mapping {
+        Some(MidiMapping::Note { note }) => note > 127,
+        Some(MidiMapping::Cc { controller }) => controller > 127,
+        None => false,
+    };
+    if out_of_range {
+        return Err("invalid-mapping".to_string());
+    }
+    let updated = state
+        .store
+        .update(|data| {
+            let mappings = &mut data.midi_output.mappings;
+            match mapping {
+                Some(mapping) => {
+                    mappings
+                        .entry(mode.clone())
+                        .or_default()
+                        .insert(key.clone(), mapping);
+                }
+                None => {
+                    if let Some(keys) = mappings.get_mut(&mode) {
+                        keys.remove(&key);
+                        if keys.is_empty() {
+                            mappings.remove(&mode);
+                        }
+                    }
+                }
+            }
+        })
+        .map_err(|err| err.to_string())?;
+
+    state
+        .midi_output
+        .set_mappings(&updated.midi_output.mappings);
+    Ok(state.midi_output.status(updated.midi_output))
+}
+
+/// 사용 가능한 MIDI 출력 장치 이름 목록
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn midi_output_ports() -> Result<Vec<String>, String> {
+    Ok(midi_output::port_names())
+}
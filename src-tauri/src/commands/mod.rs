@@ -1,10 +1,19 @@
 pub mod app;
 pub mod bridge;
 pub mod css;
+pub mod history;
 pub mod js;
+pub mod midi_output;
+pub mod osc_output;
 pub mod keys;
 pub mod overlay;
+pub mod plugin_session;
 pub mod plugin_storage;
+pub mod plugin_window;
 pub mod preset;
+pub mod profiles;
+pub mod remote_server;
+pub mod render_script;
 pub mod settings;
+pub mod store;
 pub mod system;
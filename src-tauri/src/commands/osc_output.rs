@@ -0,0 +1,50 @@
+use tauri::State;
+
+use crate::{app_state::AppState, models::OscOutputPatch, services::osc_output::OscOutputStatus};
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn osc_output_get(state: State<'_, AppState>) -> Result<OscOutputStatus, String> {
+    let settings = state.store.read(|data| data.osc_output.clone());
+    Ok(state.osc_output.status(settings))
+}
+
+/// OSC 출력 설정 변경 후 송신기 재시작
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn osc_output_update(
+    state: State<'_, AppState>,
+    patch: OscOutputPatch,
+) -> Result<OscOutputStatus, String> {
+    if patch.port == Some(0) {
+        return Err("invalid-port".to_string());
+    }
+    if patch
+        .host
+        .as_deref()
+        .is_some_and(|host| host.trim().is_empty())
+    {
+        return Err("invalid-host".to_string());
+    }
+    let updated = state
+        .store
+        .update(|data| {
+            let settings = &mut data.osc_output;
+            if let Some(enabled) = patch.enabled {
+                settings.enabled = enabled;
+            }
+            if let Some(host) = patch.host.as_deref() {
+                settings.host = host.trim().to_string();
+            }
+            if let Some(port) = patch.port {
+                settings.port = port;
+            }
+            if let Some(rate) = patch.max_messages_per_second {
+                settings.max_messages_per_second = rate.max(1);
+            }
+        })
+        .map_err(|err| err.to_string())?;
+
+    state
+        .restart_osc_output()
+        .map_err(|err| format!("{err:#}"))?;
+    Ok(state.osc_output.status(updated.osc_output))
+}
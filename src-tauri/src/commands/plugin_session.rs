@@ -0,0 +1,31 @@
+use tauri::{State, Webview};
+
+use crate::{app_state::AppState, services::plugin_permissions::PluginSessions};
+
+/// 웹뷰의 앱 세션 토큰 발급 (페이지 로드마다 한 번만, 앱 번들이 플러그인 실행 전에 가져감)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn plugin_session_open(
+    webview: Webview,
+    sessions: State<'_, PluginSessions>,
+) -> Result<String, String> {
+    sessions
+        .open(webview.label())
+        .ok_or_else(|| "session-already-open".to_string())
+}
+
+/// 플러그인을 실행하기 전에 그 플러그인 전용 토큰 발급 (앱 세션에서만 호출 가능)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn plugin_session_token(
+    webview: Webview,
+    state: State<'_, AppState>,
+    sessions: State<'_, PluginSessions>,
+    id: String,
+) -> Result<String, String> {
+    let exists = state
+        .store
+        .read(|data| data.custom_js.plugins.iter().any(|plugin| plugin.id == id));
+    if !exists {
+        return Err("not-found".to_string());
+    }
+    Ok(sessions.issue(webview.label(), &id))
+}
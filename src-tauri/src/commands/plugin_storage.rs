@@ -1,11 +1,104 @@
-use serde_json::Value;
-use tauri::State;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Emitter, State, WebviewWindow};
 
 use crate::app_state::AppState;
+use crate::services::plugin_storage::{
+    BatchOutcome, StorageChange, StorageConflict, StorageOp, STORE_KEY_PREFIX,
+};
+
+// `plugin_id`가 있으면 플러그인별 저장소를, 없으면 기존 공유 키 공간(store.json)을 사용합니다.
 
 /// 플러그인 스토리지 키 생성 (네임스페이스 자동 적용)
 fn make_storage_key(key: &str) -> String {
-    format!("plugin_data_{}", key)
+    format!("{}{}", STORE_KEY_PREFIX, key)
+}
+
+/// 값 변경 이벤트 (모든 윈도우에 전파, 구독 측에서 키 접두사로 필터링)
+///
+/// 일괄 작업은 변경된 키 전체를 하나의 이벤트로 전달합니다.
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStorageChangeEvent {
+    pub plugin_id: Option<String>,
+    /// 변경을 일으킨 윈도우 레이블
+    pub origin: String,
+    pub changes: Vec<StorageChange>,
+}
+
+fn emit_changes(
+    app: &AppHandle,
+    origin: &WebviewWindow,
+    plugin_id: Option<&str>,
+    changes: Vec<StorageChange>,
+) {
+    let changes: Vec<StorageChange> = changes
+        .into_iter()
+        .filter(|change| change.old_value != change.new_value)
+        .collect();
+    if changes.is_empty() {
+        return;
+    }
+    let payload = PluginStorageChangeEvent {
+        plugin_id: plugin_id.map(str::to_string),
+        origin: origin.label().to_string(),
+        changes,
+    };
+    if let Err(err) = app.emit("plugin-storage:changed", payload) {
+        log::warn!("[PluginStorage] Failed to emit change: {}", err);
+    }
+}
+
+fn removed_changes(removed: Vec<(String, Value)>) -> Vec<StorageChange> {
+    removed
+        .into_iter()
+        .map(|(key, value)| StorageChange {
+            key,
+            old_value: Some(value),
+            new_value: None,
+        })
+        .collect()
+}
+
+/// store.json 키에서 네임스페이스 접두사를 뗀 항목만 남김
+fn strip_storage_prefix<T>(entries: Vec<(String, T)>) -> Vec<(String, T)> {
+    entries
+        .into_iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(STORE_KEY_PREFIX)
+                .map(|stripped| (stripped.to_string(), value))
+        })
+        .collect()
+}
+
+fn strip_change_prefix(changes: Vec<StorageChange>) -> Vec<StorageChange> {
+    changes
+        .into_iter()
+        .filter_map(|change| {
+            let key = change.key.strip_prefix(STORE_KEY_PREFIX)?.to_string();
+            Some(StorageChange { key, ..change })
+        })
+        .collect()
+}
+
+/// 트랜잭션 결과
+///
+/// 조건이 맞지 않으면 `success: false`, `error: "conflict"`와 함께 충돌한 키의 현재 값을 반환합니다.
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStorageTransactionResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<StorageConflict>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginStorageUsage {
+    pub used_bytes: usize,
+    pub quota_bytes: usize,
 }
 
 /// 플러그인 데이터 조회
@@ -13,7 +106,14 @@ fn make_storage_key(key: &str) -> String {
 pub async fn plugin_storage_get(
     state: State<'_, AppState>,
     key: String,
+    plugin_id: Option<String>,
 ) -> Result<Option<Value>, String> {
+    if let Some(plugin_id) = plugin_id {
+        return state
+            .plugin_storage
+            .get(&plugin_id, &key)
+            .map_err(|e| e.to_string());
+    }
     let storage_key = make_storage_key(&key);
     state
         .store
@@ -24,42 +124,97 @@ pub async fn plugin_storage_get(
 /// 플러그인 데이터 저장
 #[tauri::command]
 pub async fn plugin_storage_set(
+    app: AppHandle,
+    window: WebviewWindow,
     state: State<'_, AppState>,
     key: String,
     value: Value,
+    plugin_id: Option<String>,
 ) -> Result<(), String> {
-    let storage_key = make_storage_key(&key);
-    state
-        .store
-        .set_plugin_data(&storage_key, value)
-        .map_err(|e| e.to_string())
+    let previous = match plugin_id.as_deref() {
+        Some(plugin_id) => state.plugin_storage.set(plugin_id, &key, value.clone()),
+        None => state
+            .store
+            .set_plugin_data(&make_storage_key(&key), value.clone())
+            .map_err(Into::into),
+    }
+    .map_err(|e| e.to_string())?;
+    let change = StorageChange {
+        key,
+        old_value: previous,
+        new_value: Some(value),
+    };
+    emit_changes(&app, &window, plugin_id.as_deref(), vec![change]);
+    Ok(())
 }
 
 /// 플러그인 데이터 삭제
 #[tauri::command]
 pub async fn plugin_storage_remove(
+    app: AppHandle,
+    window: WebviewWindow,
     state: State<'_, AppState>,
     key: String,
+    plugin_id: Option<String>,
 ) -> Result<(), String> {
-    let storage_key = make_storage_key(&key);
-    state
-        .store
-        .remove_plugin_data(&storage_key)
-        .map_err(|e| e.to_string())
+    let previous = match plugin_id.as_deref() {
+        Some(plugin_id) => state.plugin_storage.remove(plugin_id, &key),
+        None => state
+            .store
+            .remove_plugin_data(&make_storage_key(&key))
+            .map_err(Into::into),
+    }
+    .map_err(|e| e.to_string())?;
+    let change = StorageChange {
+        key,
+        old_value: previous,
+        new_value: None,
+    };
+    emit_changes(&app, &window, plugin_id.as_deref(), vec![change]);
+    Ok(())
 }
 
 /// 모든 플러그인 데이터 삭제
 #[tauri::command]
-pub async fn plugin_storage_clear(state: State<'_, AppState>) -> Result<(), String> {
-    state
-        .store
-        .clear_all_plugin_data()
-        .map_err(|e| e.to_string())
+pub async fn plugin_storage_clear(
+    app: AppHandle,
+    window: WebviewWindow,
+    state: State<'_, AppState>,
+    plugin_id: Option<String>,
+) -> Result<(), String> {
+    let removed = match plugin_id.as_deref() {
+        Some(plugin_id) => state
+            .plugin_storage
+            .clear(plugin_id)
+            .map_err(|e| e.to_string())?,
+        None => strip_storage_prefix(
+            state
+                .store
+                .clear_all_plugin_data()
+                .map_err(|e| e.to_string())?,
+        ),
+    };
+    emit_changes(
+        &app,
+        &window,
+        plugin_id.as_deref(),
+        removed_changes(removed),
+    );
+    Ok(())
 }
 
 /// 플러그인 데이터 키 목록 조회
 #[tauri::command]
-pub async fn plugin_storage_keys(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+pub async fn plugin_storage_keys(
+    state: State<'_, AppState>,
+    plugin_id: Option<String>,
+) -> Result<Vec<String>, String> {
+    if let Some(plugin_id) = plugin_id {
+        return state
+            .plugin_storage
+            .keys(&plugin_id)
+            .map_err(|e| e.to_string());
+    }
     let all_keys = state
         .store
         .get_all_plugin_keys()
@@ -81,7 +236,14 @@ pub async fn plugin_storage_keys(state: State<'_, AppState>) -> Result<Vec<Strin
 pub async fn plugin_storage_has_data(
     state: State<'_, AppState>,
     prefix: String,
+    plugin_id: Option<String>,
 ) -> Result<bool, String> {
+    if let Some(plugin_id) = plugin_id {
+        return state
+            .plugin_storage
+            .has_prefix(&plugin_id, &prefix)
+            .map_err(|e| e.to_string());
+    }
     let all_keys = state
         .store
         .get_all_plugin_keys()
@@ -96,27 +258,185 @@ pub async fn plugin_storage_has_data(
 /// 특정 접두사로 시작하는 모든 플러그인 데이터 삭제
 #[tauri::command]
 pub async fn plugin_storage_clear_by_prefix(
+    app: AppHandle,
+    window: WebviewWindow,
     state: State<'_, AppState>,
     prefix: String,
+    plugin_id: Option<String>,
 ) -> Result<usize, String> {
+    if let Some(plugin_id) = plugin_id.as_deref() {
+        let removed = state
+            .plugin_storage
+            .clear_prefix(plugin_id, &prefix)
+            .map_err(|e| e.to_string())?;
+        let count = removed.len();
+        emit_changes(&app, &window, Some(plugin_id), removed_changes(removed));
+        return Ok(count);
+    }
     let all_keys = state
         .store
         .get_all_plugin_keys()
         .map_err(|e| e.to_string())?;
 
-    let storage_prefix = format!("plugin_data_{}", prefix);
+    let storage_prefix = make_storage_key(&prefix);
     let keys_to_remove: Vec<String> = all_keys
         .into_iter()
         .filter(|k| k.starts_with(&storage_prefix))
         .collect();
 
-    let count = keys_to_remove.len();
+    let mut removed = Vec::with_capacity(keys_to_remove.len());
     for key in keys_to_remove {
-        state
+        if let Some(value) = state
             .store
             .remove_plugin_data(&key)
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| e.to_string())?
+        {
+            removed.push((key, value));
+        }
     }
 
+    let count = removed.len();
+    emit_changes(
+        &app,
+        &window,
+        None,
+        removed_changes(strip_storage_prefix(removed)),
+    );
     Ok(count)
 }
+
+/// 여러 키를 한 번에 조회 (없는 키는 `null`)
+#[tauri::command]
+pub async fn plugin_storage_get_many(
+    state: State<'_, AppState>,
+    keys: Vec<String>,
+    plugin_id: Option<String>,
+) -> Result<Map<String, Value>, String> {
+    let entries = match plugin_id.as_deref() {
+        Some(plugin_id) => state
+            .plugin_storage
+            .get_many(plugin_id, &keys)
+            .map_err(|e| e.to_string())?,
+        None => {
+            let storage_keys: Vec<String> = keys.iter().map(|k| make_storage_key(k)).collect();
+            strip_storage_prefix(
+                state
+                    .store
+                    .get_plugin_data_many(&storage_keys)
+                    .map_err(|e| e.to_string())?,
+            )
+        }
+    };
+    Ok(entries
+        .into_iter()
+        .map(|(key, value)| (key, value.unwrap_or(Value::Null)))
+        .collect())
+}
+
+/// 여러 값을 한 번에 저장 (한 번의 기록과 한 번의 변경 이벤트)
+#[tauri::command]
+pub async fn plugin_storage_set_many(
+    app: AppHandle,
+    window: WebviewWindow,
+    state: State<'_, AppState>,
+    entries: Map<String, Value>,
+    plugin_id: Option<String>,
+) -> Result<(), String> {
+    let ops = entries
+        .into_iter()
+        .map(|(key, value)| StorageOp::Set {
+            key,
+            value,
+            expected: None,
+        })
+        .collect();
+    apply_ops(&app, &window, &state, plugin_id.as_deref(), ops).map(|_| ())
+}
+
+/// 작업 묶음을 원자적으로 적용
+///
+/// 각 작업의 `expected`로 비교 후 저장(compare-and-set)을 지정할 수 있으며,
+/// 하나라도 맞지 않으면 아무것도 적용하지 않습니다.
+#[tauri::command]
+pub async fn plugin_storage_transaction(
+    app: AppHandle,
+    window: WebviewWindow,
+    state: State<'_, AppState>,
+    ops: Vec<StorageOp>,
+    plugin_id: Option<String>,
+) -> Result<PluginStorageTransactionResult, String> {
+    match apply_ops(&app, &window, &state, plugin_id.as_deref(), ops)? {
+        BatchOutcome::Applied(_) => Ok(PluginStorageTransactionResult {
+            success: true,
+            ..Default::default()
+        }),
+        BatchOutcome::Conflict(conflicts) => Ok(PluginStorageTransactionResult {
+            success: false,
+            error: Some("conflict".to_string()),
+            conflicts,
+        }),
+    }
+}
+
+fn apply_ops(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    state: &AppState,
+    plugin_id: Option<&str>,
+    ops: Vec<StorageOp>,
+) -> Result<BatchOutcome, String> {
+    let outcome = match plugin_id {
+        Some(plugin_id) => state
+            .plugin_storage
+            .apply(plugin_id, ops)
+            .map_err(|e| e.to_string())?,
+        None => {
+            let ops = ops
+                .into_iter()
+                .map(|op| op.map_key(|key| make_storage_key(&key)))
+                .collect();
+            match state
+                .store
+                .apply_plugin_data_batch(ops)
+                .map_err(|e| e.to_string())?
+            {
+                BatchOutcome::Applied(changes) => {
+                    BatchOutcome::Applied(strip_change_prefix(changes))
+                }
+                BatchOutcome::Conflict(conflicts) => BatchOutcome::Conflict(
+                    conflicts
+                        .into_iter()
+                        .map(|conflict| StorageConflict {
+                            key: conflict
+                                .key
+                                .strip_prefix(STORE_KEY_PREFIX)
+                                .unwrap_or(&conflict.key)
+                                .to_string(),
+                            current: conflict.current,
+                        })
+                        .collect(),
+                ),
+            }
+        }
+    };
+    if let BatchOutcome::Applied(changes) = &outcome {
+        emit_changes(app, window, plugin_id, changes.clone());
+    }
+    Ok(outcome)
+}
+
+/// 플러그인 저장소 사용량과 용량 제한 조회
+#[tauri::command]
+pub async fn plugin_storage_usage(
+    state: State<'_, AppState>,
+    plugin_id: String,
+) -> Result<PluginStorageUsage, String> {
+    let (used_bytes, quota_bytes) = state
+        .plugin_storage
+        .usage(&plugin_id)
+        .map_err(|e| e.to_string())?;
+    Ok(PluginStorageUsage {
+        used_bytes,
+        quota_bytes,
+    })
+}
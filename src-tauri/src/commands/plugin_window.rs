@@ -0,0 +1,141 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+
+use crate::{
+    app_state::AppState,
+    services::plugin_windows::{
+        plugin_window_label, BridgeWindowInfo, PluginWindowOptions, PluginWindows,
+    },
+};
+
+const DEFAULT_PLUGIN_WINDOW_WIDTH: f64 = 400.0;
+const DEFAULT_PLUGIN_WINDOW_HEIGHT: f64 = 300.0;
+
+/// 플러그인 윈도우 페이지에 주입되는 정보
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginWindowInfo {
+    pub label: String,
+    pub plugin_id: String,
+    pub name: String,
+}
+
+/// 플러그인 전용 윈도우 열기 (이미 열려 있으면 앞으로 가져옴)
+#[tauri::command]
+pub fn plugin_window_open(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    windows: State<'_, PluginWindows>,
+    plugin_id: String,
+    name: String,
+    options: Option<PluginWindowOptions>,
+) -> Result<PluginWindowInfo, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("invalid-name".to_string());
+    }
+    // 로드 중인(활성화된) 플러그인만 윈도우를 열 수 있음
+    let owner = state.store.read(|data| {
+        if !data.use_custom_js {
+            return None;
+        }
+        data.custom_js
+            .plugins
+            .iter()
+            .find(|plugin| plugin.enabled && plugin.dependency_key() == plugin_id)
+            .map(|plugin| plugin.id.clone())
+    });
+    let Some(owner) = owner else {
+        return Err("plugin-not-active".to_string());
+    };
+
+    let label = plugin_window_label(&plugin_id, &name);
+    let info = PluginWindowInfo {
+        label: label.clone(),
+        plugin_id: plugin_id.clone(),
+        name: name.clone(),
+    };
+
+    // 같은 레이블의 윈도우가 다른 플러그인 소유이거나 앱 윈도우면 건드리지 않음
+    if let Some(existing) = windows.plugin_id_of(&label) {
+        if existing != plugin_id {
+            return Err("window-not-owned".to_string());
+        }
+    } else if app.get_webview_window(&label).is_some() {
+        return Err("window-not-owned".to_string());
+    }
+
+    if let Some(window) = app.get_webview_window(&label) {
+        window.show().map_err(|err| err.to_string())?;
+        window.set_focus().map_err(|err| err.to_string())?;
+        return Ok(info);
+    }
+
+    let options = options.unwrap_or_default();
+    let init_script = format!(
+        "window.__dmn_plugin_window = {};",
+        serde_json::to_string(&info).map_err(|err| err.to_string())?
+    );
+    let mut builder =
+        WebviewWindowBuilder::new(&app, &label, WebviewUrl::App("plugin/index.html".into()))
+            .title(options.title.as_deref().unwrap_or(&name))
+            .inner_size(
+                options.width.unwrap_or(DEFAULT_PLUGIN_WINDOW_WIDTH),
+                options.height.unwrap_or(DEFAULT_PLUGIN_WINDOW_HEIGHT),
+            )
+            .resizable(options.resizable.unwrap_or(true))
+            .decorations(options.decorations.unwrap_or(true))
+            .transparent(options.transparent.unwrap_or(false))
+            .always_on_top(options.always_on_top.unwrap_or(false))
+            .initialization_script(&init_script)
+            .devtools(true);
+    if let (Some(x), Some(y)) = (options.x, options.y) {
+        builder = builder.position(x, y);
+    }
+    let window = builder.build().map_err(|err| err.to_string())?;
+
+    windows.register(&label, &plugin_id, &owner, &name);
+    let handle = app.clone();
+    let closed_label = label.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Some(windows) = handle.try_state::<PluginWindows>() {
+                windows.unregister(&closed_label);
+            }
+        }
+    });
+
+    log::info!("[PluginWindows] Opened {} for plugin {}", label, plugin_id);
+    Ok(info)
+}
+
+/// 플러그인 윈도우 닫기
+#[tauri::command]
+pub fn plugin_window_close(
+    app: AppHandle,
+    windows: State<'_, PluginWindows>,
+    plugin_id: String,
+    name: String,
+) -> Result<bool, String> {
+    let label = plugin_window_label(&plugin_id, name.trim());
+    // 요청한 플러그인이 연 윈도우만 닫을 수 있음
+    match windows.plugin_id_of(&label) {
+        Some(owner) if owner == plugin_id => {}
+        Some(_) => return Err("window-not-owned".to_string()),
+        None => return Ok(false),
+    }
+    windows.unregister(&label);
+    if let Some(window) = app.get_webview_window(&label) {
+        window.close().map_err(|err| err.to_string())?;
+    }
+    Ok(true)
+}
+
+/// 브릿지 메시지를 보낼 수 있는 윈도우 목록
+#[tauri::command]
+pub fn plugin_window_list(
+    app: AppHandle,
+    windows: State<'_, PluginWindows>,
+) -> Result<Vec<BridgeWindowInfo>, String> {
+    Ok(windows.list(&app))
+}
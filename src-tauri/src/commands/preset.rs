@@ -1,44 +1,88 @@
-use std::fs;
+use std::{fs, path::Path};
 
 use rfd::FileDialog;
-use serde::{Deserialize, Serialize};
-use tauri::{AppHandle, Emitter, State};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, State};
 
 use crate::{
     app_state::AppState,
     defaults::{default_keys, default_positions},
     models::{
-        CustomCss, CustomCssPatch, CustomJs, CustomJsPatch, CustomTab, KeyMappings, KeyPositions,
-        NoteSettings, NoteSettingsPatch, SettingsPatchInput,
+        CustomCssPatch, CustomJsPatch, CustomTab, JsPlugin, KeyMappings, NoteSettings,
+        NoteSettingsPatch, SettingsPatchInput,
+    },
+    services::{
+        preset::{
+            parse_json, preset_from_value, read_preset_json, write_preset, PresetFile, PresetIssue,
+        },
+        preset_bundle::{self, PresetBundle},
+        preset_merge::{self, PresetMergeReport, PresetPreview, PresetSelection},
     },
 };
 
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 pub struct PresetOperationResult {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Vec<PresetIssue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<PresetMergeReport>,
 }
 
-#[derive(Serialize, Deserialize, Default)]
+impl PresetOperationResult {
+    fn ok() -> Self {
+        Self {
+            success: true,
+            ..Self::default()
+        }
+    }
+
+    fn cancelled() -> Self {
+        Self::default()
+    }
+
+    fn invalid(issues: Vec<PresetIssue>) -> Self {
+        Self {
+            success: false,
+            error: Some("invalid-preset".to_string()),
+            issues: Some(issues),
+            report: None,
+        }
+    }
+
+    fn invalid_arguments() -> Self {
+        Self {
+            success: false,
+            error: Some("invalid-arguments".to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct PresetPreviewResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Vec<PresetIssue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<PresetPreview>,
+}
+
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-struct PresetFile {
-    keys: Option<KeyMappings>,
-    key_positions: Option<KeyPositions>,
-    background_color: Option<String>,
-    note_settings: Option<NoteSettings>,
-    note_effect: Option<bool>,
-    laboratory_enabled: Option<bool>,
-    custom_tabs: Option<Vec<CustomTab>>,
-    selected_key_type: Option<String>,
-    #[serde(rename = "useCustomCSS")]
-    use_custom_css: Option<bool>,
-    #[serde(rename = "customCSS")]
-    custom_css: Option<CustomCss>,
-    #[serde(rename = "useCustomJS")]
-    use_custom_js: Option<bool>,
-    #[serde(rename = "customJS")]
-    custom_js: Option<CustomJs>,
+pub struct PresetExportResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<PresetFile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<PresetBundle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
 }
 
 #[tauri::command(permission = "dmnote-allow-all")]
@@ -49,35 +93,16 @@ pub fn preset_save(state: State<'_, AppState>) -> Result<PresetOperationResult,
         .save_file();
 
     let Some(path) = preset_path else {
-        return Ok(PresetOperationResult {
-            success: false,
-            error: None,
-        });
-    };
-
-    let snapshot = state.store.snapshot();
-    let preset = PresetFile {
-        keys: Some(snapshot.keys),
-        key_positions: Some(snapshot.key_positions),
-        background_color: Some(snapshot.background_color),
-        note_settings: Some(snapshot.note_settings),
-        note_effect: Some(snapshot.note_effect),
-        laboratory_enabled: Some(snapshot.laboratory_enabled),
-        custom_tabs: Some(snapshot.custom_tabs),
-        selected_key_type: Some(snapshot.selected_key_type),
-        use_custom_css: Some(snapshot.use_custom_css),
-        custom_css: Some(snapshot.custom_css),
-        use_custom_js: Some(snapshot.use_custom_js),
-        custom_js: Some(snapshot.custom_js),
+        return Ok(PresetOperationResult::cancelled());
     };
 
-    let json = serde_json::to_string_pretty(&preset).map_err(|err| err.to_string())?;
-    fs::write(&path, json).map_err(|err| err.to_string())?;
+    state
+        .persist_key_counters()
+        .map_err(|err| err.to_string())?;
+    let preset = PresetFile::from_store(&state.store.snapshot(), &state.plugin_storage);
+    write_preset(&path, &preset).map_err(|err| err.to_string())?;
 
-    Ok(PresetOperationResult {
-        success: true,
-        error: None,
-    })
+    Ok(PresetOperationResult::ok())
 }
 
 #[tauri::command(permission = "dmnote-allow-all")]
@@ -90,24 +115,229 @@ pub fn preset_load(
         .pick_file();
 
     let Some(path) = picked else {
-        return Ok(PresetOperationResult {
+        return Ok(PresetOperationResult::cancelled());
+    };
+
+    let parsed = read_preset_json(&path).map_err(|err| err.to_string())?;
+    match resolve_preset(&state, parsed)? {
+        Ok(preset) => apply_preset(&state, &app, preset).map(|_| PresetOperationResult::ok()),
+        Err(issues) => Ok(PresetOperationResult::invalid(issues)),
+    }
+}
+
+/// 현재 상태를 프리셋으로 내보내기 (경로가 없으면 JSON으로 반환)
+///
+/// `bundle`이면 참조 중인 이미지/CSS/JS 파일을 포함한 번들로 내보냅니다.
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn preset_export(
+    state: State<'_, AppState>,
+    path: Option<String>,
+    bundle: Option<bool>,
+) -> Result<PresetExportResult, String> {
+    state
+        .persist_key_counters()
+        .map_err(|err| err.to_string())?;
+    let preset = PresetFile::from_store(&state.store.snapshot(), &state.plugin_storage);
+    let (preset, bundle) = if bundle.unwrap_or(false) {
+        (None, Some(preset_bundle::pack(&preset)))
+    } else {
+        (Some(preset), None)
+    };
+
+    let Some(path) = path else {
+        return Ok(PresetExportResult {
+            success: true,
+            preset,
+            bundle,
+            path: None,
+        });
+    };
+    let written = match (&preset, &bundle) {
+        (_, Some(bundle)) => write_preset(Path::new(&path), bundle),
+        (Some(preset), None) => write_preset(Path::new(&path), preset),
+        (None, None) => Ok(()),
+    };
+    written.map_err(|err| err.to_string())?;
+    Ok(PresetExportResult {
+        success: true,
+        preset: None,
+        bundle: None,
+        path: Some(path),
+    })
+}
+
+/// 경로 또는 JSON 페이로드(객체 혹은 문자열)로 프리셋/번들 적용
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn preset_import(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    path: Option<String>,
+    payload: Option<Value>,
+) -> Result<PresetOperationResult, String> {
+    let Some(parsed) = read_source(path, payload)? else {
+        return Ok(PresetOperationResult::invalid_arguments());
+    };
+
+    match resolve_preset(&state, parsed)? {
+        Ok(preset) => apply_preset(&state, &app, preset).map(|_| PresetOperationResult::ok()),
+        Err(issues) => Ok(PresetOperationResult::invalid(issues)),
+    }
+}
+
+/// 프리셋을 적용하지 않고 포함된 탭/모드/설정/CSS/JS 요약 반환
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn preset_preview(
+    state: State<'_, AppState>,
+    path: Option<String>,
+    payload: Option<Value>,
+) -> Result<PresetPreviewResult, String> {
+    let Some(parsed) = read_source(path, payload)? else {
+        return Ok(PresetPreviewResult {
             success: false,
-            error: None,
+            error: Some("invalid-arguments".to_string()),
+            ..PresetPreviewResult::default()
         });
     };
 
-    let content = fs::read_to_string(&path).map_err(|err| err.to_string())?;
-    let preset: PresetFile =
-        serde_json::from_str(&content).map_err(|_| "invalid-preset".to_string())?;
+    // 미리보기 단계에서는 번들 에셋을 메모리에서만 검사하고 파일로 풀지 않음
+    let validated = parsed.and_then(|value| {
+        if preset_bundle::is_bundle(&value) {
+            preset_bundle::bundle_from_value(value).and_then(preset_bundle::verify)
+        } else {
+            preset_from_value(value).map(|preset| (preset, 0))
+        }
+    });
+
+    match validated {
+        Ok((preset, asset_count)) => {
+            let current = PresetFile::from_store(&state.store.snapshot(), &state.plugin_storage);
+            Ok(PresetPreviewResult {
+                success: true,
+                preview: Some(preset_merge::preview(&preset, &current, asset_count)),
+                ..PresetPreviewResult::default()
+            })
+        }
+        Err(issues) => Ok(PresetPreviewResult {
+            success: false,
+            error: Some("invalid-preset".to_string()),
+            issues: Some(issues),
+            preview: None,
+        }),
+    }
+}
+
+/// 선택한 섹션/모드만 현재 상태에 병합
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn preset_import_selective(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    path: Option<String>,
+    payload: Option<Value>,
+    selection: PresetSelection,
+) -> Result<PresetOperationResult, String> {
+    let Some(parsed) = read_source(path, payload)? else {
+        return Ok(PresetOperationResult::invalid_arguments());
+    };
+    let value = match parsed {
+        Ok(value) => value,
+        Err(issues) => return Ok(PresetOperationResult::invalid(issues)),
+    };
+    // 번들은 병합 뒤에 풀어 선택한 섹션이 참조하는 에셋만 기록
+    let mut bundle = None;
+    let incoming = if preset_bundle::is_bundle(&value) {
+        match preset_bundle::bundle_from_value(value) {
+            Ok(mut parsed) => {
+                let preset = std::mem::take(&mut parsed.preset);
+                bundle = Some(parsed);
+                preset
+            }
+            Err(issues) => return Ok(PresetOperationResult::invalid(issues)),
+        }
+    } else {
+        match preset_from_value(value) {
+            Ok(preset) => preset,
+            Err(issues) => return Ok(PresetOperationResult::invalid(issues)),
+        }
+    };
+
+    state
+        .persist_key_counters()
+        .map_err(|err| err.to_string())?;
+    let base = PresetFile::from_store(&state.store.snapshot(), &state.plugin_storage);
+    let (mut merged, report) = preset_merge::merge(base, incoming, &selection);
+    if let Some(mut bundle) = bundle {
+        bundle.preset = merged;
+        merged = match preset_bundle::unpack(
+            bundle,
+            &preset_bundle::assets_root(state.store.data_dir()),
+        )
+        .map_err(|err| err.to_string())?
+        {
+            Ok(preset) => preset,
+            Err(issues) => return Ok(PresetOperationResult::invalid(issues)),
+        };
+    }
+    apply_preset(&state, &app, merged)?;
+
+    Ok(PresetOperationResult {
+        success: true,
+        report: Some(report),
+        ..PresetOperationResult::default()
+    })
+}
+
+/// 경로/페이로드 인자를 JSON으로 읽기 (둘 중 정확히 하나만 있어야 하며, 아니면 `None`)
+pub(crate) fn read_source(
+    path: Option<String>,
+    payload: Option<Value>,
+) -> Result<Option<Result<Value, Vec<PresetIssue>>>, String> {
+    Ok(match (path, payload) {
+        (Some(path), None) => {
+            Some(read_preset_json(Path::new(&path)).map_err(|err| err.to_string())?)
+        }
+        (None, Some(Value::String(content))) => Some(parse_json(&content)),
+        (None, Some(value)) => Some(Ok(value)),
+        _ => None,
+    })
+}
+
+/// 일반 프리셋/번들을 구분해 검증하고, 번들이면 에셋을 관리 폴더에 풀기
+fn resolve_preset(
+    state: &AppState,
+    parsed: Result<Value, Vec<PresetIssue>>,
+) -> Result<Result<PresetFile, Vec<PresetIssue>>, String> {
+    let value = match parsed {
+        Ok(value) => value,
+        Err(issues) => return Ok(Err(issues)),
+    };
+    if !preset_bundle::is_bundle(&value) {
+        return Ok(preset_from_value(value));
+    }
+    let bundle = match preset_bundle::bundle_from_value(value) {
+        Ok(bundle) => bundle,
+        Err(issues) => return Ok(Err(issues)),
+    };
+    preset_bundle::unpack(bundle, &preset_bundle::assets_root(state.store.data_dir()))
+        .map_err(|err| err.to_string())
+}
 
-    let keys = preset.keys.unwrap_or_else(default_keys);
-    let positions = preset.key_positions.unwrap_or_else(default_positions);
+/// 검증된 프리셋을 스토어에 적용하고 변경 이벤트 전파
+pub fn apply_preset(state: &AppState, app: &AppHandle, preset: PresetFile) -> Result<(), String> {
+    let previous = state.store.snapshot();
+    let keys = preset.keys.clone().unwrap_or_else(default_keys);
+    let positions = preset
+        .key_positions
+        .clone()
+        .unwrap_or_else(default_positions);
     let custom_tabs = preset
         .custom_tabs
+        .clone()
         .unwrap_or_else(|| synthesize_custom_tabs(&keys));
-    let snapshot = state.store.snapshot();
-    let selected_key_type =
-        choose_selected_key_type(preset.selected_key_type, &keys, snapshot.selected_key_type);
+    let selected_key_type = choose_selected_key_type(
+        preset.selected_key_type.clone(),
+        &keys,
+        previous.selected_key_type.clone(),
+    );
 
     state
         .store
@@ -116,11 +346,10 @@ pub fn preset_load(
             store.key_positions = positions.clone();
             store.custom_tabs = custom_tabs.clone();
             store.selected_key_type = selected_key_type.clone();
+            preset.apply_sections_to(store);
         })
         .map_err(|err| err.to_string())?;
-
-    state.keyboard.update_mappings(keys.clone());
-    state.keyboard.set_mode(selected_key_type.clone());
+    preset.apply_plugin_storage(&state.plugin_storage);
 
     let desired_settings = preset.note_settings.unwrap_or_else(NoteSettings::default);
     let mut note_patch = NoteSettingsPatch::default();
@@ -133,12 +362,11 @@ pub fn preset_load(
     note_patch.short_note_threshold_ms = Some(desired_settings.short_note_threshold_ms);
     note_patch.short_note_min_length_px = Some(desired_settings.short_note_min_length_px);
 
-    let css_use = preset.use_custom_css.unwrap_or(false);
     let custom_css = preset.custom_css.unwrap_or_default();
-    let js_use = preset.use_custom_js.unwrap_or(false);
-    let custom_js = preset.custom_js.unwrap_or_default();
+    let mut custom_js = preset.custom_js.unwrap_or_default();
+    carry_plugin_grants(&mut custom_js.plugins, &previous.custom_js.plugins);
 
-    let diff = state
+    state
         .settings
         .apply_patch(SettingsPatchInput {
             background_color: Some(
@@ -149,55 +377,60 @@ pub fn preset_load(
             note_settings: Some(note_patch),
             note_effect: Some(preset.note_effect.unwrap_or(false)),
             laboratory_enabled: Some(preset.laboratory_enabled.unwrap_or(false)),
-            use_custom_css: Some(css_use),
+            use_custom_css: Some(preset.use_custom_css.unwrap_or(false)),
             custom_css: Some(CustomCssPatch {
-                path: Some(custom_css.path.clone()),
-                content: Some(custom_css.content.clone()),
+                path: Some(custom_css.path),
+                content: Some(custom_css.content),
             }),
-            use_custom_js: Some(js_use),
+            use_custom_js: Some(preset.use_custom_js.unwrap_or(false)),
             custom_js: Some(CustomJsPatch {
-                path: Some(custom_js.path.clone()),
-                content: Some(custom_js.content.clone()),
-                plugins: Some(custom_js.plugins.clone()),
+                path: Some(custom_js.path),
+                content: Some(custom_js.content),
+                plugins: Some(custom_js.plugins),
             }),
+            // 이전 형식 프리셋에는 없는 항목이므로 값이 있을 때만 적용
+            key_counter_enabled: preset.key_counter_enabled,
+            overlay_resize_anchor: preset.overlay_resize_anchor,
             ..SettingsPatchInput::default()
         })
         .map_err(|err| err.to_string())?;
 
     state
-        .emit_settings_changed(&diff, &app)
-        .map_err(|err| err.to_string())?;
+        .sync_with_store(app, &previous)
+        .map_err(|err| err.to_string())
+}
 
-    app.emit("keys:changed", &keys)
-        .map_err(|err| err.to_string())?;
-    app.emit("positions:changed", &positions)
-        .map_err(|err| err.to_string())?;
-    app.emit(
-        "customTabs:changed",
-        &crate::commands::keys::CustomTabChangePayload {
-            custom_tabs: custom_tabs.clone(),
-            selected_key_type: selected_key_type.clone(),
-        },
-    )
-    .map_err(|err| err.to_string())?;
-    app.emit(
-        "keys:mode-changed",
-        &serde_json::json!({ "mode": &selected_key_type }),
-    )
-    .map_err(|err| err.to_string())?;
-    app.emit("css:use", &serde_json::json!({ "enabled": css_use }))
-        .map_err(|err| err.to_string())?;
-    app.emit("css:content", &custom_css)
-        .map_err(|err| err.to_string())?;
-    app.emit("js:use", &serde_json::json!({ "enabled": js_use }))
-        .map_err(|err| err.to_string())?;
-    app.emit("js:content", &custom_js)
-        .map_err(|err| err.to_string())?;
+/// 프리셋에 담긴 권한 허용 목록은 신뢰하지 않고, 이미 설치된 같은 플러그인의 허용 목록만 유지
+///
+/// ID만 같고 스크립트가 다르면 다른 플러그인으로 보고 허용 목록을 비웁니다.
+/// 경로가 같으면 그 파일의 현재 내용이 프리셋의 스크립트와 같을 때만 같은 플러그인으로 봅니다.
+fn carry_plugin_grants(plugins: &mut [JsPlugin], installed: &[JsPlugin]) {
+    for plugin in plugins.iter_mut() {
+        plugin.granted_permissions = Some(
+            installed
+                .iter()
+                .find(|current| current.id == plugin.id && is_same_script(current, plugin))
+                .map(|current| {
+                    current
+                        .granted_permissions
+                        .clone()
+                        .unwrap_or_else(|| current.requestable_permissions().to_vec())
+                })
+                .unwrap_or_default(),
+        );
+    }
+}
 
-    Ok(PresetOperationResult {
-        success: true,
-        error: None,
-    })
+fn is_same_script(installed: &JsPlugin, plugin: &JsPlugin) -> bool {
+    if installed.content == plugin.content {
+        return true;
+    }
+    match (installed.path.as_deref(), plugin.path.as_deref()) {
+        (Some(installed_path), Some(path)) if installed_path == path => {
+            fs::read_to_string(path).is_ok_and(|content| content == plugin.content)
+        }
+        _ => false,
+    }
 }
 
 fn synthesize_custom_tabs(keys: &KeyMappings) -> Vec<CustomTab> {
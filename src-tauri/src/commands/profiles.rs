@@ -0,0 +1,125 @@
+use serde::Serialize;
+use tauri::{AppHandle, State};
+
+use crate::{app_state::AppState, models::ProfileSummary, services::profiles::ProfileError};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileListResponse {
+    pub profiles: Vec<ProfileSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ProfileOperationResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<ProfileSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 서비스 결과를 응답으로 변환 (입력 오류는 error 코드로, I/O 오류는 커맨드 실패로 처리)
+fn into_result(
+    state: &AppState,
+    app: &AppHandle,
+    result: Result<Option<ProfileSummary>, ProfileError>,
+) -> Result<ProfileOperationResult, String> {
+    match result {
+        Ok(profile) => {
+            if let Err(err) = state.emit_profiles_changed(app) {
+                log::warn!("failed to emit profiles:changed: {err}");
+            }
+            Ok(ProfileOperationResult {
+                success: true,
+                profile,
+                error: None,
+            })
+        }
+        Err(ProfileError::Io(err)) => Err(err.to_string()),
+        Err(err) => Ok(ProfileOperationResult {
+            success: false,
+            profile: None,
+            error: Some(err.to_string()),
+        }),
+    }
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_list(state: State<'_, AppState>) -> Result<ProfileListResponse, String> {
+    let profiles = state.profiles.list().map_err(|err| err.to_string())?;
+    Ok(ProfileListResponse {
+        profiles,
+        active_id: state.profiles.active_id(),
+    })
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_create(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    name: String,
+) -> Result<ProfileOperationResult, String> {
+    state
+        .persist_key_counters()
+        .map_err(|err| err.to_string())?;
+    let result = state.profiles.create(&name).map(Some);
+    into_result(&state, &app, result)
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_clone(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    id: String,
+    name: String,
+) -> Result<ProfileOperationResult, String> {
+    state
+        .persist_key_counters()
+        .map_err(|err| err.to_string())?;
+    let result = state.profiles.clone_profile(&id, &name).map(Some);
+    into_result(&state, &app, result)
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_rename(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    id: String,
+    name: String,
+) -> Result<ProfileOperationResult, String> {
+    let result = state.profiles.rename(&id, &name).map(Some);
+    into_result(&state, &app, result)
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_delete(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    id: String,
+) -> Result<ProfileOperationResult, String> {
+    let result = state.profiles.delete(&id).map(|_| None);
+    into_result(&state, &app, result)
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn profiles_switch(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    id: String,
+) -> Result<ProfileOperationResult, String> {
+    match state.switch_profile(&app, &id) {
+        Ok(profile) => Ok(ProfileOperationResult {
+            success: true,
+            profile: Some(profile),
+            error: None,
+        }),
+        Err(ProfileError::Io(err)) => Err(err.to_string()),
+        Err(err) => Ok(ProfileOperationResult {
+            success: false,
+            profile: None,
+            error: Some(err.to_string()),
+        }),
+    }
+}
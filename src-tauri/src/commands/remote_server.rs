@@ -0,0 +1,57 @@
+use tauri::{AppHandle, State};
+use uuid::Uuid;
+
+use crate::{
+    app_state::AppState, models::RemoteServerPatch, services::remote_server::RemoteServerStatus,
+};
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn remote_server_get(state: State<'_, AppState>) -> Result<RemoteServerStatus, String> {
+    let settings = state.store.read(|data| data.remote_server.clone());
+    Ok(state.remote_server.status(settings))
+}
+
+/// 로컬 서버 설정 변경 후 서버 재시작
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn remote_server_update(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    patch: RemoteServerPatch,
+) -> Result<RemoteServerStatus, String> {
+    if patch.port == Some(0) {
+        return Err("invalid-port".to_string());
+    }
+    let updated = state
+        .store
+        .update(|data| {
+            let settings = &mut data.remote_server;
+            if let Some(enabled) = patch.enabled {
+                settings.enabled = enabled;
+            }
+            if let Some(port) = patch.port {
+                settings.port = port;
+            }
+            if let Some(token) = patch.token.as_deref() {
+                settings.token = token.trim().to_string();
+            }
+            if let Some(control_enabled) = patch.control_enabled {
+                settings.control_enabled = control_enabled;
+            }
+            if let Some(control_token) = patch.control_token.as_deref() {
+                settings.control_token = control_token.trim().to_string();
+            }
+            // 토큰 없이 서버를 열지 않도록 자동 생성
+            if settings.token.is_empty() {
+                settings.token = Uuid::new_v4().simple().to_string();
+            }
+            if settings.control_enabled && settings.control_token.is_empty() {
+                settings.control_token = Uuid::new_v4().simple().to_string();
+            }
+        })
+        .map_err(|err| err.to_string())?;
+
+    state
+        .restart_remote_server(&app)
+        .map_err(|err| format!("{err:#}"))?;
+    Ok(state.remote_server.status(updated.remote_server))
+}
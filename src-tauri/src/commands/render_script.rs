@@ -0,0 +1,108 @@
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::State;
+
+use crate::{
+    app_state::AppState,
+    commands::preset::read_source,
+    services::{
+        preset::{write_preset, PresetIssue},
+        render_script::{script_from_value, RecordingStatus, RenderScript},
+    },
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderScriptExportResult {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script: Option<RenderScript>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub events: usize,
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Default)]
+pub struct RenderScriptValidation {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues: Option<Vec<PresetIssue>>,
+}
+
+/// 입력 녹화 시작 (진행 중인 녹화는 버리고 새로 시작)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn render_record_start(state: State<'_, AppState>) -> Result<RecordingStatus, String> {
+    state.store.read(|data| state.recorder.start(data));
+    log::info!("[RenderScript] Recording started");
+    Ok(state.recorder.status())
+}
+
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn render_record_status(state: State<'_, AppState>) -> Result<RecordingStatus, String> {
+    Ok(state.recorder.status())
+}
+
+/// 녹화를 마치고 렌더 스크립트로 내보내기 (경로가 없으면 JSON으로 반환)
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn render_record_stop(
+    state: State<'_, AppState>,
+    path: Option<String>,
+) -> Result<RenderScriptExportResult, String> {
+    let truncated = state.recorder.status().truncated;
+    let recording = state
+        .recorder
+        .stop()
+        .ok_or_else(|| "not-recording".to_string())?;
+    let script = RenderScript::from_recording(recording);
+    let events = script.events.len();
+    log::info!("[RenderScript] Recording stopped ({} events)", events);
+
+    let Some(path) = path else {
+        return Ok(RenderScriptExportResult {
+            success: true,
+            script: Some(script),
+            path: None,
+            events,
+            truncated,
+        });
+    };
+    write_preset(Path::new(&path), &script).map_err(|err| err.to_string())?;
+    Ok(RenderScriptExportResult {
+        success: true,
+        script: None,
+        path: Some(path),
+        events,
+        truncated,
+    })
+}
+
+/// 경로 또는 JSON 페이로드의 렌더 스크립트 검증
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn render_script_validate(
+    path: Option<String>,
+    payload: Option<Value>,
+) -> Result<RenderScriptValidation, String> {
+    let Some(parsed) = read_source(path, payload)? else {
+        return Ok(RenderScriptValidation {
+            error: Some("invalid-arguments".to_string()),
+            ..RenderScriptValidation::default()
+        });
+    };
+    let result = parsed.and_then(script_from_value);
+    Ok(match result {
+        Ok(_) => RenderScriptValidation {
+            valid: true,
+            ..RenderScriptValidation::default()
+        },
+        Err(issues) => RenderScriptValidation {
+            valid: false,
+            error: Some("invalid-render-script".to_string()),
+            issues: Some(issues),
+        },
+    })
+}
@@ -0,0 +1,10 @@
+use tauri::State;
+
+use crate::app_state::AppState;
+
+/// 대기 중인 store.json / 플러그인 저장소 변경을 즉시 디스크에 기록
+#[tauri::command(permission = "dmnote-allow-all")]
+pub fn store_flush(state: State<'_, AppState>) -> Result<(), String> {
+    state.store.flush().map_err(|err| err.to_string())?;
+    state.plugin_storage.flush().map_err(|err| err.to_string())
+}
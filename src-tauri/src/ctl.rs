@@ -0,0 +1,188 @@
+//! `dm-note ctl <명령>`: 실행 중인 DM Note를 제어하는 CLI
+//!
+//! store.json의 로컬 서버 설정(포트/토큰)을 읽어 `POST /control`로 명령을 보냅니다.
+//! 로컬 서버가 켜져 있어야 합니다.
+
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, TcpStream};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::models::RemoteServerSettings;
+use crate::services::preset::write_preset;
+use crate::services::remote_control::{ControlCommand, ControlResponse};
+
+const CTL_TIMEOUT: Duration = Duration::from_secs(10);
+
+const USAGE: &str = "\
+Usage: dm-note ctl <command> [args]
+
+Commands:
+  mode <mode>                 Switch key mode (e.g. 4key, or a custom tab id)
+  overlay [show|hide|toggle]  Show or hide the overlay
+  lock [on|off|toggle]        Lock or unlock the overlay
+  reset-counters [mode]       Reset key counters (all modes when omitted)
+  preset <path>               Load a preset file
+  record start                Start recording input for a render script
+  record stop [path]          Stop recording and save the render script
+  status                      Print the current mode and overlay state
+  send <json>                 Send a raw control command
+
+The local server and external control must be enabled in Settings.";
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct StoreServerSection {
+    #[serde(default)]
+    remote_server: RemoteServerSettings,
+}
+
+pub fn run(identifier: &str, args: &[String]) -> Result<()> {
+    let (command, save_path) = match parse_command(args) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{USAGE}");
+            return Err(err);
+        }
+    };
+    let settings = read_server_settings(identifier)?;
+    if !settings.enabled {
+        bail!("the local server is disabled; enable it in DM Note settings first");
+    }
+    if !settings.control_enabled || settings.control_token.is_empty() {
+        bail!("external control is disabled; enable it in DM Note settings first");
+    }
+
+    let response = send_command(&settings, &command)?;
+    if !response.ok {
+        bail!(response
+            .error
+            .unwrap_or_else(|| "command failed".to_string()));
+    }
+    // 앱은 원격 요청으로 파일을 쓰지 않으므로 받은 스크립트를 여기서 저장
+    let result = match (save_path, response.result) {
+        (Some(path), Some(result)) => Some(save_script(&path, result)?),
+        (_, result) => result,
+    };
+    if let Some(result) = result {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    }
+    Ok(())
+}
+
+/// 명령과 (녹화 종료 시) 스크립트를 저장할 경로
+fn parse_command(args: &[String]) -> Result<(ControlCommand, Option<PathBuf>)> {
+    let name = args.first().map(String::as_str).unwrap_or_default();
+    let arg = args.get(1).map(String::as_str);
+    let toggle = |on: &str, off: &str| -> Result<Option<bool>> {
+        match arg {
+            None | Some("toggle") => Ok(None),
+            Some(value) if value == on => Ok(Some(true)),
+            Some(value) if value == off => Ok(Some(false)),
+            Some(value) => Err(anyhow!("unknown argument: {value}")),
+        }
+    };
+
+    if name == "record" && arg == Some("stop") {
+        return Ok((ControlCommand::RecordStop, args.get(2).map(PathBuf::from)));
+    }
+
+    let command = match name {
+        "mode" => ControlCommand::SetMode {
+            mode: arg.context("missing mode")?.to_string(),
+        },
+        "overlay" => ControlCommand::Overlay {
+            visible: toggle("show", "hide")?,
+        },
+        "lock" => ControlCommand::Lock {
+            locked: toggle("on", "off")?,
+        },
+        "reset-counters" => ControlCommand::ResetCounters {
+            mode: arg.map(str::to_string),
+        },
+        "preset" => {
+            let path = PathBuf::from(arg.context("missing preset path")?);
+            // 실행 중인 앱의 작업 폴더와 다를 수 있으므로 절대 경로로 전달
+            let path = std::fs::canonicalize(&path)
+                .with_context(|| format!("preset not found: {}", path.display()))?;
+            ControlCommand::LoadPreset {
+                path: path.to_string_lossy().to_string(),
+            }
+        }
+        "record" => match arg {
+            Some("start") => ControlCommand::RecordStart,
+            _ => bail!("usage: record start | record stop [path]"),
+        },
+        "status" => ControlCommand::Status,
+        "send" => serde_json::from_str(arg.context("missing command json")?)
+            .context("invalid command json")?,
+        "" => bail!("missing command"),
+        other => bail!("unknown command: {other}"),
+    };
+    Ok((command, None))
+}
+
+/// `record stop` 응답의 렌더 스크립트를 파일로 저장하고, 출력용 결과에서는 스크립트를 뺌
+fn save_script(path: &Path, mut result: Value) -> Result<Value> {
+    let script = result
+        .as_object_mut()
+        .and_then(|object| object.remove("script"))
+        .context("response did not include a render script")?;
+    write_preset(path, &script)?;
+    if let Some(object) = result.as_object_mut() {
+        object.insert(
+            "path".to_string(),
+            Value::String(path.to_string_lossy().to_string()),
+        );
+    }
+    Ok(result)
+}
+
+fn read_server_settings(identifier: &str) -> Result<RemoteServerSettings> {
+    let path = dirs_next::data_dir()
+        .context("failed to resolve app data directory")?
+        .join(identifier)
+        .join("store.json");
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let section: StoreServerSection =
+        serde_json::from_str(&content).context("failed to parse store.json")?;
+    Ok(section.remote_server)
+}
+
+fn send_command(
+    settings: &RemoteServerSettings,
+    command: &ControlCommand,
+) -> Result<ControlResponse> {
+    let body = serde_json::to_string(command)?;
+    let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, settings.port))
+        .with_context(|| format!("DM Note is not reachable on port {}", settings.port))?;
+    stream.set_read_timeout(Some(CTL_TIMEOUT))?;
+    let request = format!(
+        "POST /control HTTP/1.1\r\n\
+         Host: 127.0.0.1:{}\r\n\
+         Authorization: Bearer {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        settings.port,
+        settings.control_token,
+        body.len()
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .context("invalid response from DM Note")?;
+    let status = head.lines().next().unwrap_or_default();
+    if body.trim().is_empty() {
+        bail!("request failed: {status}");
+    }
+    serde_json::from_str(body).with_context(|| format!("invalid response ({status})"))
+}
@@ -0,0 +1,168 @@
+//! 실행 인자 처리
+//!
+//! 이미 실행 중인 인스턴스가 있으면 새 프로세스는 바로 종료되고,
+//! 그 명령줄이 기존 인스턴스로 전달되어 여기서 처리됩니다.
+//! - `dm-note path/to/preset.json`: 프리셋 불러오기 (`preset_load`와 같은 검증/적용)
+//!   실제로 있는 `.json` 파일만 프리셋으로 보므로 OS가 붙이는 인자(macOS `-psn_…`)나
+//!   알 수 없는 옵션의 값은 무시됨 (번들의 파일 연결로 더블클릭한 파일도 이 경로로 전달)
+//! - `--profile <이름>`: 프로필 전환
+//! - 적용 결과는 알림 목록에 쌓고 `app:launchNotice` 이벤트로 UI에 알림
+
+use std::path::{Path, PathBuf};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::app_state::AppState;
+use crate::commands::preset::preset_import;
+
+/// DM Note 실행 인자
+#[derive(Debug, Default, Clone)]
+pub struct LaunchArgs {
+    /// `--show`: 오버레이 표시
+    pub show: bool,
+    /// 불러올 프리셋 파일 (절대 경로)
+    pub preset: Option<PathBuf>,
+    /// `--profile`: 전환할 프로필 이름 또는 ID
+    pub profile: Option<String>,
+}
+
+impl LaunchArgs {
+    /// 실행 파일 경로를 제외한 인자 해석 (상대 경로는 `cwd` 기준, 알 수 없는 인자는 무시)
+    pub fn parse(args: &[String], cwd: &Path) -> Self {
+        let mut parsed = Self::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--show" => parsed.show = true,
+                "--profile" => parsed.profile = iter.next().cloned(),
+                other => {
+                    if let Some(profile) = other.strip_prefix("--profile=") {
+                        parsed.profile = Some(profile.to_string());
+                    } else if parsed.preset.is_none() && !other.starts_with('-') {
+                        let path = cwd.join(other);
+                        if is_preset_file(&path) {
+                            parsed.preset = Some(path);
+                        } else {
+                            log::debug!("[LaunchArgs] Ignoring argument: {}", other);
+                        }
+                    } else {
+                        log::debug!("[LaunchArgs] Ignoring argument: {}", other);
+                    }
+                }
+            }
+        }
+        parsed
+    }
+}
+
+/// 프리셋으로 불러올 수 있는 파일인지 (존재하는 `.json` 파일)
+fn is_preset_file(path: &Path) -> bool {
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    is_json && path.is_file()
+}
+
+/// 실행 인자 적용 결과 (UI 확인용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchNotice {
+    /// `preset` 또는 `profile`
+    pub kind: &'static str,
+    /// 프리셋 경로 또는 프로필 이름
+    pub target: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// UI가 아직 가져가지 않은 실행 인자 적용 결과
+///
+/// 첫 실행 시에는 UI가 이벤트를 구독하기 전에 적용되므로 목록으로 보관합니다.
+#[derive(Default)]
+pub struct LaunchNotices {
+    pending: Mutex<Vec<LaunchNotice>>,
+}
+
+impl LaunchNotices {
+    pub fn take(&self) -> Vec<LaunchNotice> {
+        std::mem::take(&mut *self.pending.lock())
+    }
+}
+
+/// 두 번째 실행에서 전달된 명령줄 처리: 메인 창을 앞으로 가져온 뒤 인자 적용
+pub fn handle_second_instance(app: &AppHandle, argv: Vec<String>, cwd: String) {
+    log::info!("[LaunchArgs] Second instance launched: {:?}", argv);
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let args = LaunchArgs::parse(argv.get(1..).unwrap_or_default(), Path::new(&cwd));
+    apply(app, &args);
+}
+
+pub fn apply(app: &AppHandle, args: &LaunchArgs) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    // 프로필을 먼저 전환해 프리셋이 그 위에 적용되도록 함
+    if let Some(name) = args.profile.as_deref() {
+        let result = switch_profile(app, &state, name);
+        notify(app, "profile", name.to_string(), result);
+    }
+    if let Some(path) = args.preset.as_deref() {
+        let result = load_preset(app, &state, path);
+        notify(app, "preset", path.to_string_lossy().to_string(), result);
+    }
+    if args.show {
+        if let Err(err) = state.set_overlay_visibility(app, true) {
+            log::warn!("[LaunchArgs] Failed to show overlay: {err}");
+        }
+    }
+}
+
+fn switch_profile(app: &AppHandle, state: &AppState, name: &str) -> Result<(), String> {
+    let id = state
+        .profiles
+        .find_by_name(name)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| "not-found".to_string())?;
+    state
+        .switch_profile(app, &id)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+fn load_preset(app: &AppHandle, state: &State<'_, AppState>, path: &Path) -> Result<(), String> {
+    let result = preset_import(
+        state.clone(),
+        app.clone(),
+        Some(path.to_string_lossy().to_string()),
+        None,
+    )?;
+    if result.success {
+        Ok(())
+    } else {
+        Err(result.error.unwrap_or_else(|| "invalid-preset".to_string()))
+    }
+}
+
+fn notify(app: &AppHandle, kind: &'static str, target: String, result: Result<(), String>) {
+    match &result {
+        Ok(()) => log::info!("[LaunchArgs] Applied {} {}", kind, target),
+        Err(err) => log::warn!("[LaunchArgs] Failed to apply {} {}: {}", kind, target, err),
+    }
+    let notice = LaunchNotice {
+        kind,
+        target,
+        success: result.is_ok(),
+        error: result.err(),
+    };
+    if let Some(notices) = app.try_state::<LaunchNotices>() {
+        notices.pending.lock().push(notice);
+    }
+    let _ = app.emit("app:launchNotice", ());
+}
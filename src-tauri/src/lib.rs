@@ -1,9 +1,11 @@
 pub mod app_state;
 pub mod commands;
+pub mod ctl;
 pub mod defaults;
 pub mod keyboard;
 pub mod keyboard_daemon;
 pub mod keyboard_labels;
+pub mod launch_args;
 pub mod ipc;
 pub mod models;
 pub mod services;
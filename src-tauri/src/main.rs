@@ -2,10 +2,12 @@
 
 mod app_state;
 mod commands;
+mod ctl;
 mod defaults;
 mod keyboard;
 mod keyboard_daemon;
 mod keyboard_labels;
+mod launch_args;
 mod ipc;
 mod models;
 mod services;
@@ -15,9 +17,18 @@ use anyhow::Result;
 use log::LevelFilter;
 use std::{thread, time::Duration};
 
-use tauri::{ipc::CapabilityBuilder, LogicalSize, Manager, PhysicalPosition, Position};
+use tauri::{
+    ipc::CapabilityBuilder, webview::PageLoadEvent, LogicalSize, Manager, PhysicalPosition,
+    Position,
+};
 
 use app_state::AppState;
+use launch_args::{LaunchArgs, LaunchNotices};
+use services::{
+    bridge_rpc::BridgeRpc,
+    plugin_permissions::{self, PluginSessions},
+    plugin_windows::PluginWindows,
+};
 use store::AppStore;
 
 fn main() {
@@ -34,14 +45,31 @@ fn main() {
         return;
     }
 
+    let context = tauri::generate_context!();
+
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("ctl") {
+        #[cfg(target_os = "windows")]
+        attach_parent_console();
+        if let Err(err) = ctl::run(&context.config().identifier, &args[2..]) {
+            eprintln!("dm-note ctl: {err:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     if let Err(err) = setup_logging() {
         eprintln!("Failed to initialize logging: {err}");
     }
 
-    let context = tauri::generate_context!();
-
     tauri::Builder::default()
-        .setup(|app| {
+        // 이미 실행 중이면 명령줄을 기존 인스턴스로 넘기고 종료
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            launch_args::handle_second_instance(app, argv, cwd);
+        }))
+        // 첫 페이지 로드 전에 있어야 웹뷰가 앱 세션 토큰을 받을 수 있음
+        .manage(PluginSessions::default())
+        .setup(move |app| {
             register_dev_capability(app)?;
             let resolver = app.path();
             let store = AppStore::initialize(&resolver)
@@ -49,6 +77,9 @@ fn main() {
             let app_state = AppState::initialize(store)
                 .map_err(|e| -> Box<dyn std::error::Error> { e.into() })?;
             app.manage(app_state);
+            app.manage(BridgeRpc::default());
+            app.manage(PluginWindows::default());
+            app.manage(LaunchNotices::default());
             let handle = app.handle();
             {
                 let state = app.state::<AppState>();
@@ -57,12 +88,27 @@ fn main() {
                     .map_err(|e| -> Box<dyn std::error::Error> { e.into() })?;
             }
             configure_main_window(&app.handle());
+            let cwd = std::env::current_dir().unwrap_or_default();
+            launch_args::apply(app.handle(), &LaunchArgs::parse(&args[1..], &cwd));
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![
+        // 새 페이지는 호출자 토큰을 처음부터 다시 발급받음
+        .on_page_load(|webview, payload| {
+            if payload.event() == PageLoadEvent::Started {
+                if let Some(sessions) = webview.try_state::<PluginSessions>() {
+                    sessions.reset(webview.label());
+                }
+            }
+        })
+        // 플러그인에서 온 호출은 권한을 확인한 뒤 실행
+        .invoke_handler(plugin_permissions::guarded(tauri::generate_handler![
             commands::app::app_bootstrap,
+            commands::app::app_take_launch_notices,
             commands::settings::settings_get,
             commands::settings::settings_update,
+            commands::store::store_flush,
+            commands::history::history_undo,
+            commands::history::history_redo,
             commands::keys::keys_get,
             commands::keys::positions_get,
             commands::keys::keys_update,
@@ -97,17 +143,49 @@ fn main() {
             commands::js::js_set_content,
             commands::js::js_load,
             commands::js::js_reload,
+            commands::js::js_open_plugin_dir,
             commands::js::js_remove_plugin,
             commands::js::js_set_plugin_enabled,
+            commands::js::js_set_plugin_permissions,
+            commands::js::js_reorder_plugins,
             commands::preset::preset_save,
             commands::preset::preset_load,
+            commands::preset::preset_export,
+            commands::preset::preset_import,
+            commands::preset::preset_preview,
+            commands::preset::preset_import_selective,
+            commands::profiles::profiles_list,
+            commands::profiles::profiles_create,
+            commands::profiles::profiles_clone,
+            commands::profiles::profiles_rename,
+            commands::profiles::profiles_delete,
+            commands::profiles::profiles_switch,
             commands::overlay::overlay_get,
             commands::overlay::overlay_set_visible,
             commands::overlay::overlay_set_lock,
             commands::overlay::overlay_set_anchor,
             commands::overlay::overlay_resize,
+            commands::midi_output::midi_output_get,
+            commands::midi_output::midi_output_update,
+            commands::midi_output::midi_output_set_mapping,
+            commands::midi_output::midi_output_ports,
+            commands::osc_output::osc_output_get,
+            commands::osc_output::osc_output_update,
+            commands::remote_server::remote_server_get,
+            commands::remote_server::remote_server_update,
+            commands::render_script::render_record_start,
+            commands::render_script::render_record_status,
+            commands::render_script::render_record_stop,
+            commands::render_script::render_script_validate,
             commands::bridge::plugin_bridge_send,
             commands::bridge::plugin_bridge_send_to,
+            commands::bridge::plugin_bridge_call,
+            commands::bridge::plugin_bridge_reply,
+            commands::plugin_window::plugin_window_open,
+            commands::plugin_window::plugin_window_close,
+            commands::plugin_window::plugin_window_list,
+            commands::plugin_session::plugin_session_open,
+            commands::plugin_session::plugin_session_token,
             commands::plugin_storage::plugin_storage_get,
             commands::plugin_storage::plugin_storage_set,
             commands::plugin_storage::plugin_storage_remove,
@@ -115,12 +193,16 @@ fn main() {
             commands::plugin_storage::plugin_storage_keys,
             commands::plugin_storage::plugin_storage_has_data,
             commands::plugin_storage::plugin_storage_clear_by_prefix,
+            commands::plugin_storage::plugin_storage_get_many,
+            commands::plugin_storage::plugin_storage_set_many,
+            commands::plugin_storage::plugin_storage_transaction,
+            commands::plugin_storage::plugin_storage_usage,
             commands::system::window_minimize,
             commands::system::window_close,
             commands::system::app_open_external,
             commands::system::app_restart,
             commands::system::window_open_devtools_all,
-        ])
+        ]))
         .run(context)
         .expect("error while running tauri application");
 }
@@ -145,6 +227,15 @@ fn apply_webview2_additional_args(arg: &str) {
     env::set_var(KEY, new_value);
 }
 
+/// 릴리즈 빌드는 windows 서브시스템이라 CLI 출력을 위해 부모 콘솔에 연결
+#[cfg(target_os = "windows")]
+fn attach_parent_console() {
+    use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
 fn setup_logging() -> Result<()> {
     // 개발 모드에서는 Debug, 릴리즈에서는 Info
     let level = if cfg!(debug_assertions) {
@@ -281,8 +372,8 @@ fn register_dev_capability(app: &tauri::App) -> Result<(), Box<dyn std::error::E
     let builder = DEV_URLS.iter().fold(
         CapabilityBuilder::new("dmnote-dev")
             .local(true)
-            .windows(["main", "overlay"])
-            .webviews(["main", "overlay"])
+            .windows(["main", "overlay", "plugin-*"])
+            .webviews(["main", "overlay", "plugin-*"])
             .permission("dmnote-allow-all"),
         |acc, url| acc.remote((*url).to_string()),
     );
@@ -268,6 +268,84 @@ impl Default for TabCss {
 /// 탭별 CSS 오버라이드 맵 (키: 탭 ID, 값: TabCss)
 pub type TabCssOverrides = HashMap<String, TabCss>;
 
+/// 탭 CSS 조회/변경 응답 (`tabCss:changed` 이벤트 페이로드)
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TabCssResponse {
+    pub tab_id: String,
+    pub css: Option<TabCss>,
+}
+
+/// 플러그인이 실행될 윈도우
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PluginWindowTarget {
+    Main,
+    Overlay,
+    All,
+}
+
+/// 플러그인이 요청할 수 있는 권한
+///
+/// 자신의 저장소 네임스페이스와 읽기 전용 명령은 권한 없이 사용할 수 있습니다.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum PluginPermission {
+    /// 다른 플러그인과 공유하는 저장소 접근
+    Storage,
+    Bridge,
+    RawInput,
+    /// 외부 URL 열기
+    External,
+    /// 설정/CSS/오버레이 변경
+    Settings,
+    /// 키 매핑/카운터/탭 변경
+    Keys,
+    /// 프리셋/프로필 불러오기 및 변경
+    Presets,
+    /// 플러그인 전용 윈도우 열기
+    Windows,
+}
+
+impl PluginPermission {
+    pub const ALL: [PluginPermission; 8] = [
+        PluginPermission::Storage,
+        PluginPermission::Bridge,
+        PluginPermission::RawInput,
+        PluginPermission::External,
+        PluginPermission::Settings,
+        PluginPermission::Keys,
+        PluginPermission::Presets,
+        PluginPermission::Windows,
+    ];
+}
+
+/// 플러그인 헤더 주석에 선언된 메타데이터
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginManifest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 필요한 최소 DmNote 버전
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_app_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<PluginWindowTarget>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<PluginPermission>,
+    /// 먼저 로드되어야 하는 플러그인 ID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct JsPlugin {
@@ -276,6 +354,102 @@ pub struct JsPlugin {
     pub path: Option<String>,
     pub content: String,
     pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<PluginManifest>,
+    /// 사용자가 허용한 권한 (`None`은 권한 모델 도입 전 설치된 플러그인으로, 요청할 수 있는 권한을 모두 허용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granted_permissions: Option<Vec<PluginPermission>>,
+    /// 로드 순서 (`None`은 새로 추가된 플러그인으로, 정규화 시 맨 뒤에 배치)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<u32>,
+}
+
+impl JsPlugin {
+    /// 헤더에 선언된 ID
+    pub fn declared_id(&self) -> Option<&str> {
+        self.manifest.as_ref()?.id.as_deref()
+    }
+
+    /// 플러그인 저장소 네임스페이스 (선언된 ID, 없으면 파일명으로 만든 ID)
+    ///
+    /// 프런트엔드 런타임의 `extractPluginId`와 같은 규칙입니다.
+    pub fn storage_namespace(&self) -> String {
+        if let Some(id) = self.declared_id() {
+            return id.to_string();
+        }
+        let lower = self.name.to_lowercase();
+        let stem = [".js", ".mjs", ".ts"]
+            .iter()
+            .find_map(|ext| lower.strip_suffix(ext))
+            .unwrap_or(&lower);
+        let mut namespace = String::with_capacity(stem.len());
+        for c in stem.chars() {
+            let c = if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' {
+                c
+            } else {
+                '-'
+            };
+            if c == '-' && namespace.ends_with('-') {
+                continue;
+            }
+            namespace.push(c);
+        }
+        namespace.trim_matches('-').to_string()
+    }
+
+    /// 의존성 비교에 쓰는 ID (선언된 ID가 없으면 내부 ID)
+    pub fn dependency_key(&self) -> &str {
+        self.declared_id().unwrap_or(&self.id)
+    }
+
+    pub fn declared_dependencies(&self) -> &[String] {
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.dependencies.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn declared_permissions(&self) -> &[PluginPermission] {
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.permissions.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 사용자에게 허용받을 수 있는 권한
+    ///
+    /// `@permissions`를 선언하지 않은 플러그인(권한 모델 도입 전에 만든 플러그인)은 모든 권한을 요청할 수 있습니다.
+    pub fn requestable_permissions(&self) -> &[PluginPermission] {
+        match self.declared_permissions() {
+            [] => &PluginPermission::ALL,
+            declared => declared,
+        }
+    }
+
+    /// 실제로 허용된 권한 (요청할 수 없는 권한은 허용 목록에 있어도 제외)
+    pub fn has_permission(&self, permission: PluginPermission) -> bool {
+        let requestable = self.requestable_permissions().contains(&permission);
+        match &self.granted_permissions {
+            Some(granted) => requestable && granted.contains(&permission),
+            None => requestable,
+        }
+    }
+}
+
+/// 플러그인 파일을 읽거나 적용하지 못한 경우
+#[derive(Debug, Clone, Serialize)]
+pub struct JsPluginError {
+    pub path: String,
+    pub error: String,
+}
+
+impl JsPluginError {
+    pub fn new(path: String, error: impl Into<String>) -> Self {
+        Self {
+            path,
+            error: error.into(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -334,6 +508,9 @@ impl CustomJs {
                 path: self.path.clone(),
                 content: self.content.clone(),
                 enabled: true,
+                manifest: None,
+                granted_permissions: None,
+                order: None,
             };
             self.plugins.push(plugin);
             self.path = None;
@@ -341,6 +518,17 @@ impl CustomJs {
             mutated = true;
         }
 
+        // 순서 값 기준으로 정렬한 뒤 0부터 다시 매김
+        self.plugins
+            .sort_by_key(|plugin| plugin.order.unwrap_or(u32::MAX));
+        for (index, plugin) in self.plugins.iter_mut().enumerate() {
+            let order = Some(index as u32);
+            if plugin.order != order {
+                plugin.order = order;
+                mutated = true;
+            }
+        }
+
         mutated
     }
 }
@@ -391,6 +579,10 @@ pub fn overlay_resize_anchor_from_str(value: &str) -> Option<OverlayResizeAnchor
         _ => None,
     }
 }
+
+/// 커스텀 탭 최대 개수
+pub const MAX_CUSTOM_TABS: usize = 5;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomTab {
@@ -398,6 +590,13 @@ pub struct CustomTab {
     pub name: String,
 }
 
+/// `customTabs:changed` 이벤트 페이로드
+#[derive(Serialize)]
+pub struct CustomTabChangePayload {
+    pub custom_tabs: Vec<CustomTab>,
+    pub selected_key_type: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AppStoreData {
@@ -439,6 +638,18 @@ pub struct AppStoreData {
     pub overlay_bounds_are_logical: bool,
     #[serde(default)]
     pub key_counter_enabled: bool,
+    /// 현재 활성화된 프로필 ID
+    #[serde(default)]
+    pub active_profile_id: Option<String>,
+    /// 외부 프로그램용 로컬 서버 설정
+    #[serde(default)]
+    pub remote_server: RemoteServerSettings,
+    /// OSC 출력 설정
+    #[serde(default)]
+    pub osc_output: OscOutputSettings,
+    /// MIDI 출력 설정
+    #[serde(default)]
+    pub midi_output: MidiOutputSettings,
     /// 플러그인 데이터 저장소 (plugin_data_* 키로 저장)
     #[serde(default, flatten)]
     pub plugin_data: HashMap<String, serde_json::Value>,
@@ -472,11 +683,277 @@ impl Default for AppStoreData {
             overlay_last_content_top_offset: None,
             overlay_bounds_are_logical: false,
             key_counter_enabled: false,
+            active_profile_id: None,
+            remote_server: RemoteServerSettings::default(),
+            osc_output: OscOutputSettings::default(),
+            midi_output: MidiOutputSettings::default(),
             plugin_data: HashMap::new(),
         }
     }
 }
 
+/// 외부 프로그램(OBS 브라우저 소스 등)용 로컬 WebSocket/HTTP 서버 설정
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteServerSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_remote_server_port")]
+    pub port: u16,
+    /// 접속 토큰 (`?token=` 쿼리 또는 `Authorization: Bearer` 헤더)
+    #[serde(default)]
+    pub token: String,
+    /// `POST /control` 허용 여부 (기본값 꺼짐)
+    #[serde(default)]
+    pub control_enabled: bool,
+    /// `POST /control` 전용 토큰 (읽기용 `token`과 별개)
+    #[serde(default)]
+    pub control_token: String,
+}
+
+fn default_remote_server_port() -> u16 {
+    3939
+}
+
+impl Default for RemoteServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_remote_server_port(),
+            token: String::new(),
+            control_enabled: false,
+            control_token: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteServerPatch {
+    pub enabled: Option<bool>,
+    pub port: Option<u16>,
+    /// 빈 문자열이면 새 토큰 생성
+    pub token: Option<String>,
+    pub control_enabled: Option<bool>,
+    /// 빈 문자열이면 새 제어 토큰 생성
+    pub control_token: Option<String>,
+}
+
+/// 키 입력을 OSC(UDP)로 보내는 출력 설정
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OscOutputSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_osc_host")]
+    pub host: String,
+    #[serde(default = "default_osc_port")]
+    pub port: u16,
+    /// 초당 최대 눌림/kps 메시지 수 (초과분은 버림, 뗌은 항상 전송)
+    #[serde(default = "default_osc_max_rate")]
+    pub max_messages_per_second: u32,
+}
+
+fn default_osc_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_osc_port() -> u16 {
+    9000
+}
+
+fn default_osc_max_rate() -> u32 {
+    200
+}
+
+impl Default for OscOutputSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: default_osc_host(),
+            port: default_osc_port(),
+            max_messages_per_second: default_osc_max_rate(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OscOutputPatch {
+    pub enabled: Option<bool>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub max_messages_per_second: Option<u32>,
+}
+
+/// MIDI 출력 대상
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MidiOutputBackend {
+    /// 시스템 MIDI 출력 장치 (loopMIDI 등 가상 포트 포함, Windows 전용)
+    #[default]
+    Port,
+    /// 메시지를 텍스트 파일에 기록 (테스트용)
+    File,
+}
+
+/// 키 하나에 대응하는 MIDI 메시지
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MidiMapping {
+    /// 누르면 노트 온, 떼면 노트 오프
+    Note { note: u8 },
+    /// 누르면 벨로시티 값, 떼면 0
+    Cc { controller: u8 },
+}
+
+/// 모드 → 키 이름 → MIDI 매핑
+pub type MidiMappings = HashMap<String, HashMap<String, MidiMapping>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MidiOutputSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub backend: MidiOutputBackend,
+    /// 출력 장치 이름 (비어 있으면 첫 번째 장치)
+    #[serde(default)]
+    pub port_name: String,
+    /// 파일 출력 경로
+    #[serde(default)]
+    pub file_path: String,
+    /// MIDI 채널 (1~16)
+    #[serde(default = "default_midi_channel")]
+    pub channel: u8,
+    /// 노트 온 벨로시티 및 CC 눌림 값 (1~127)
+    #[serde(default = "default_midi_velocity")]
+    pub velocity: u8,
+    #[serde(default)]
+    pub mappings: MidiMappings,
+}
+
+fn default_midi_channel() -> u8 {
+    1
+}
+
+fn default_midi_velocity() -> u8 {
+    100
+}
+
+impl Default for MidiOutputSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: MidiOutputBackend::default(),
+            port_name: String::new(),
+            file_path: String::new(),
+            channel: default_midi_channel(),
+            velocity: default_midi_velocity(),
+            mappings: MidiMappings::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MidiOutputPatch {
+    pub enabled: Option<bool>,
+    pub backend: Option<MidiOutputBackend>,
+    pub port_name: Option<String>,
+    pub file_path: Option<String>,
+    pub channel: Option<u8>,
+    pub velocity: Option<u8>,
+}
+
+/// 프로필 단위로 전환되는 설정 묶음 (키/배치/CSS/JS/노트 설정)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileData {
+    #[serde(default)]
+    pub keys: KeyMappings,
+    #[serde(default)]
+    pub key_positions: KeyPositions,
+    #[serde(default)]
+    pub key_counters: KeyCounters,
+    #[serde(default)]
+    pub custom_tabs: Vec<CustomTab>,
+    pub selected_key_type: String,
+    pub note_effect: bool,
+    #[serde(default)]
+    pub note_settings: NoteSettings,
+    pub background_color: String,
+    #[serde(default)]
+    pub laboratory_enabled: bool,
+    #[serde(rename = "useCustomCSS")]
+    pub use_custom_css: bool,
+    #[serde(rename = "customCSS", default)]
+    pub custom_css: CustomCss,
+    #[serde(default)]
+    pub tab_css_overrides: TabCssOverrides,
+    #[serde(rename = "useCustomJS")]
+    pub use_custom_js: bool,
+    #[serde(rename = "customJS", default)]
+    pub custom_js: CustomJs,
+    #[serde(default)]
+    pub key_counter_enabled: bool,
+    #[serde(default)]
+    pub overlay_resize_anchor: OverlayResizeAnchor,
+}
+
+impl ProfileData {
+    pub fn from_store(store: &AppStoreData) -> Self {
+        Self {
+            keys: store.keys.clone(),
+            key_positions: store.key_positions.clone(),
+            key_counters: store.key_counters.clone(),
+            custom_tabs: store.custom_tabs.clone(),
+            selected_key_type: store.selected_key_type.clone(),
+            note_effect: store.note_effect,
+            note_settings: store.note_settings.clone(),
+            background_color: store.background_color.clone(),
+            laboratory_enabled: store.laboratory_enabled,
+            use_custom_css: store.use_custom_css,
+            custom_css: store.custom_css.clone(),
+            tab_css_overrides: store.tab_css_overrides.clone(),
+            use_custom_js: store.use_custom_js,
+            custom_js: store.custom_js.clone(),
+            key_counter_enabled: store.key_counter_enabled,
+            overlay_resize_anchor: store.overlay_resize_anchor.clone(),
+        }
+    }
+
+    pub fn apply_to(&self, store: &mut AppStoreData) {
+        store.keys = self.keys.clone();
+        store.key_positions = self.key_positions.clone();
+        store.key_counters = self.key_counters.clone();
+        store.custom_tabs = self.custom_tabs.clone();
+        store.selected_key_type = self.selected_key_type.clone();
+        store.note_effect = self.note_effect;
+        store.note_settings = self.note_settings.clone();
+        store.background_color = self.background_color.clone();
+        store.laboratory_enabled = self.laboratory_enabled;
+        store.use_custom_css = self.use_custom_css;
+        store.custom_css = self.custom_css.clone();
+        store.tab_css_overrides = self.tab_css_overrides.clone();
+        store.use_custom_js = self.use_custom_js;
+        store.custom_js = self.custom_js.clone();
+        store.key_counter_enabled = self.key_counter_enabled;
+        store.overlay_resize_anchor = self.overlay_resize_anchor.clone();
+    }
+}
+
+/// 프로필 목록 항목
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSummary {
+    pub id: String,
+    pub name: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub active: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BootstrapOverlayState {
@@ -0,0 +1,74 @@
+//! 플러그인 브릿지 요청/응답
+//!
+//! `plugin_bridge_call`로 보낸 요청을 ID로 기록해 두고,
+//! 대상 윈도우의 `plugin_bridge_reply` 응답을 기다리는 호출자에게 전달합니다.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// 타임아웃을 지정하지 않은 요청의 대기 시간
+const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_CALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub type BridgeReply = Result<Value, String>;
+
+struct PendingCall {
+    /// 응답할 수 있는 윈도우 레이블
+    target: String,
+    sender: Sender<BridgeReply>,
+}
+
+/// 응답을 기다리는 브릿지 요청 목록
+#[derive(Default)]
+pub struct BridgeRpc {
+    pending: Mutex<HashMap<String, PendingCall>>,
+}
+
+impl BridgeRpc {
+    /// 새 요청 등록 (요청 ID와 응답 수신기 반환)
+    pub fn register(&self, target: &str) -> (String, Receiver<BridgeReply>) {
+        let request_id = Uuid::new_v4().to_string();
+        let (sender, receiver) = mpsc::channel();
+        self.pending.lock().insert(
+            request_id.clone(),
+            PendingCall {
+                target: target.to_string(),
+                sender,
+            },
+        );
+        (request_id, receiver)
+    }
+
+    /// 대상 윈도우의 응답 전달 (요청을 받은 윈도우만 응답 가능)
+    pub fn resolve(&self, request_id: &str, from: &str, reply: BridgeReply) -> Result<(), String> {
+        let mut pending = self.pending.lock();
+        match pending.get(request_id) {
+            None => return Err("unknown-request".to_string()),
+            Some(call) if call.target != from => return Err("not-request-target".to_string()),
+            Some(_) => {}
+        }
+        if let Some(call) = pending.remove(request_id) {
+            // 호출자가 이미 타임아웃으로 끝난 경우 무시
+            let _ = call.sender.send(reply);
+        }
+        Ok(())
+    }
+
+    /// 타임아웃되거나 전송에 실패한 요청 제거
+    pub fn cancel(&self, request_id: &str) {
+        self.pending.lock().remove(request_id);
+    }
+}
+
+/// 요청한 타임아웃(ms)을 허용 범위로 맞춤
+pub fn call_timeout(timeout_ms: Option<u64>) -> Duration {
+    timeout_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_CALL_TIMEOUT)
+        .min(MAX_CALL_TIMEOUT)
+}
@@ -186,6 +186,12 @@ impl CssWatcher {
         }
     }
 
+    /// 스토어 내용이 통째로 바뀐 뒤 워칭 대상을 다시 구성
+    pub fn resync_from_store(&self) {
+        self.watchers.write().clear();
+        self.initialize_from_store();
+    }
+
     /// 모든 워칭 중지
     pub fn shutdown(&self) {
         let mut watchers = self.watchers.write();
@@ -277,7 +283,7 @@ fn reload_tab_css(store: &AppStore, app: &AppHandle, tab_id: &str, path: &str) -
 }
 
 /// 경로 비교 (플랫폼별 차이 무시)
-fn paths_match(path1: &str, path2: &str) -> bool {
+pub(crate) fn paths_match(path1: &str, path2: &str) -> bool {
     let p1 = PathBuf::from(path1);
     let p2 = PathBuf::from(path2);
 
@@ -0,0 +1,236 @@
+//! 스토어 변경 되돌리기/다시 실행 기록
+//!
+//! `AppStore`의 변경 직전 상태를 제한된 개수만큼 보관합니다.
+//! 오버레이 위치, 카운터, 플러그인 데이터처럼 사용자가 편집하지 않는 값은 기록/복원 대상에서 제외합니다.
+//! 선택된 키 모드도 기록하지 않으므로 되돌리기/다시 실행이 다른 모드로 전환하지 않습니다.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::models::AppStoreData;
+
+/// 보관할 최대 기록 수
+const HISTORY_LIMIT: usize = 100;
+/// 이 시간 안에 같은 종류(같은 필드)의 변경이 이어지면 하나의 기록으로 합침 (드래그, 슬라이더 등)
+const COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
+pub struct StoreHistory {
+    undo: VecDeque<AppStoreData>,
+    redo: Vec<AppStoreData>,
+    /// 마지막 기록 이후의 상태 (변경 비교 기준)
+    ///
+    /// 기록 대상 필드가 바뀔 때만 복제하므로 카운터/오버레이 이동처럼 잦은 변경은 복제하지 않음
+    baseline: AppStoreData,
+    /// 마지막 변경 시각과 바뀐 필드
+    last_change: Option<(Instant, Vec<&'static str>)>,
+}
+
+impl StoreHistory {
+    pub fn new(initial: &AppStoreData) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            baseline: initial.clone(),
+            last_change: None,
+        }
+    }
+
+    /// 변경 후 상태 기록 (기록 대상 필드가 바뀐 경우에만 이전 상태를 보관)
+    pub fn record(&mut self, after: &AppStoreData) {
+        let changed = changed_fields(&self.baseline, after);
+        if changed.is_empty() {
+            return;
+        }
+        self.redo.clear();
+        let now = Instant::now();
+        let coalesce = self.last_change.as_ref().is_some_and(|(last, fields)| {
+            now.duration_since(*last) < COALESCE_WINDOW && *fields == changed
+        });
+        self.last_change = Some((now, changed));
+        let before = std::mem::replace(&mut self.baseline, after.clone());
+        if coalesce && !self.undo.is_empty() {
+            return;
+        }
+        if self.undo.len() >= HISTORY_LIMIT {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+    }
+
+    /// 기록하지 않고 비교 기준만 갱신 (되돌리기/다시 실행 적용 후, 기록하지 않는 변경 등)
+    pub fn sync(&mut self, state: &AppStoreData) {
+        self.baseline = state.clone();
+        self.last_change = None;
+    }
+
+    /// 현재 상태를 다시 실행 기록에 넣고 되돌릴 상태 반환
+    pub fn undo(&mut self, current: &AppStoreData) -> Option<AppStoreData> {
+        let target = self.undo.pop_back()?;
+        self.redo.push(current.clone());
+        Some(restore(target, current))
+    }
+
+    /// 현재 상태를 되돌리기 기록에 넣고 다시 실행할 상태 반환
+    pub fn redo(&mut self, current: &AppStoreData) -> Option<AppStoreData> {
+        let target = self.redo.pop()?;
+        self.undo.push_back(current.clone());
+        Some(restore(target, current))
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self, current: &AppStoreData) {
+        self.undo.clear();
+        self.redo.clear();
+        self.sync(current);
+    }
+}
+
+/// 기록 대상 필드 중 바뀐 필드 이름 (복제 없이 필드별로 비교)
+fn changed_fields(before: &AppStoreData, after: &AppStoreData) -> Vec<&'static str> {
+    // 필드가 추가되면 기록 대상 여부를 정하도록 모든 필드를 나열
+    let AppStoreData {
+        hardware_acceleration,
+        always_on_top,
+        overlay_locked,
+        note_effect,
+        note_settings,
+        selected_key_type: _,
+        custom_tabs,
+        angle_mode,
+        language,
+        laboratory_enabled,
+        developer_mode_enabled,
+        keys,
+        key_positions,
+        background_color,
+        use_custom_css,
+        custom_css,
+        tab_css_overrides,
+        use_custom_js,
+        custom_js,
+        overlay_resize_anchor,
+        key_counter_enabled,
+        key_counters: _,
+        overlay_bounds: _,
+        overlay_last_content_top_offset: _,
+        overlay_bounds_are_logical: _,
+        active_profile_id: _,
+        remote_server: _,
+        osc_output: _,
+        midi_output: _,
+        plugin_data: _,
+    } = after;
+
+    let mut changed = Vec::new();
+    let mut check = |name: &'static str, differs: bool| {
+        if differs {
+            changed.push(name);
+        }
+    };
+    check(
+        "hardwareAcceleration",
+        before.hardware_acceleration != *hardware_acceleration,
+    );
+    check("alwaysOnTop", before.always_on_top != *always_on_top);
+    check("overlayLocked", before.overlay_locked != *overlay_locked);
+    check("noteEffect", before.note_effect != *note_effect);
+    check("noteSettings", before.note_settings != *note_settings);
+    check("customTabs", before.custom_tabs != *custom_tabs);
+    check("angleMode", before.angle_mode != *angle_mode);
+    check("language", before.language != *language);
+    check(
+        "laboratoryEnabled",
+        before.laboratory_enabled != *laboratory_enabled,
+    );
+    check(
+        "developerModeEnabled",
+        before.developer_mode_enabled != *developer_mode_enabled,
+    );
+    check("keys", before.keys != *keys);
+    check("keyPositions", before.key_positions != *key_positions);
+    check(
+        "backgroundColor",
+        before.background_color != *background_color,
+    );
+    check("useCustomCss", before.use_custom_css != *use_custom_css);
+    check("customCss", before.custom_css != *custom_css);
+    check(
+        "tabCssOverrides",
+        before.tab_css_overrides != *tab_css_overrides,
+    );
+    check("useCustomJs", before.use_custom_js != *use_custom_js);
+    check("customJs", before.custom_js != *custom_js);
+    check(
+        "overlayResizeAnchor",
+        before.overlay_resize_anchor != *overlay_resize_anchor,
+    );
+    check(
+        "keyCounterEnabled",
+        before.key_counter_enabled != *key_counter_enabled,
+    );
+    changed
+}
+
+/// 기록된 상태를 복원하되 기록 대상이 아닌 필드는 현재 값 유지
+fn restore(target: AppStoreData, current: &AppStoreData) -> AppStoreData {
+    AppStoreData {
+        overlay_bounds: current.overlay_bounds.clone(),
+        overlay_last_content_top_offset: current.overlay_last_content_top_offset,
+        overlay_bounds_are_logical: current.overlay_bounds_are_logical,
+        key_counters: current.key_counters.clone(),
+        plugin_data: current.plugin_data.clone(),
+        active_profile_id: current.active_profile_id.clone(),
+        remote_server: current.remote_server.clone(),
+        osc_output: current.osc_output.clone(),
+        midi_output: current.midi_output.clone(),
+        selected_key_type: current.selected_key_type.clone(),
+        ..target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_after_mode_switch_keeps_current_mode() {
+        let initial = AppStoreData::default();
+        let mut history = StoreHistory::new(&initial);
+
+        let mut edited = initial.clone();
+        edited.background_color = "#123456".to_string();
+        history.record(&edited);
+
+        // 모드 전환은 기록하지 않고 비교 기준만 갱신 (`AppStore::set_selected_key_type`과 같은 흐름)
+        let mut switched = edited.clone();
+        switched.selected_key_type = "8key".to_string();
+        history.sync(&switched);
+
+        let undone = history.undo(&switched).expect("undo entry");
+        assert_eq!(undone.background_color, initial.background_color);
+        assert_eq!(undone.selected_key_type, "8key");
+
+        let redone = history.redo(&undone).expect("redo entry");
+        assert_eq!(redone.background_color, "#123456");
+        assert_eq!(redone.selected_key_type, "8key");
+    }
+
+    #[test]
+    fn mode_change_alone_is_not_recorded() {
+        let initial = AppStoreData::default();
+        let mut history = StoreHistory::new(&initial);
+
+        let mut switched = initial.clone();
+        switched.selected_key_type = "8key".to_string();
+        history.record(&switched);
+
+        assert!(!history.can_undo());
+    }
+}
@@ -0,0 +1,240 @@
+//! JS 플러그인 핫리로딩 서비스
+//!
+//! 경로가 있는 플러그인 파일의 변경을 감지하여 해당 플러그인만 다시 불러옵니다.
+//! - 플러그인 파일이 있는 폴더 단위로 워칭
+//! - 디바운싱으로 연속 저장 시 한 번만 리로드
+//! - 전체 `js:content` 대신 플러그인별 `js:pluginReload` 이벤트 전송 (ID/의존성이 바뀐 경우 제외)
+//! - 관리 플러그인 폴더(`plugins/`)는 `PluginDirWatcher`가 맡으므로 워칭하지 않음
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify::RecommendedWatcher;
+use notify_debouncer_mini::{new_debouncer, DebouncedEventKind, Debouncer};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::models::{JsPlugin, JsPluginError};
+use crate::services::css_watcher::paths_match;
+use crate::services::plugin_dir::plugins_dir;
+use crate::services::plugin_manifest::{check_compatibility, parse_manifest};
+use crate::services::plugin_order::JsContentPayload;
+use crate::store::AppStore;
+
+const JS_WATCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct JsPluginReloadEvent {
+    plugin: JsPlugin,
+}
+
+/// JS 플러그인 파일 워처
+pub struct JsWatcher {
+    store: Arc<AppStore>,
+    inner: Mutex<WatchState>,
+}
+
+struct WatchState {
+    debouncer: Debouncer<RecommendedWatcher>,
+    /// 현재 워칭 중인 폴더
+    dirs: HashSet<PathBuf>,
+}
+
+impl JsWatcher {
+    pub fn new(store: Arc<AppStore>, app: AppHandle) -> Result<Self, String> {
+        let handler_store = store.clone();
+        let debouncer = new_debouncer(
+            JS_WATCH_DEBOUNCE,
+            move |res: Result<Vec<notify_debouncer_mini::DebouncedEvent>, notify::Error>| match res
+            {
+                Ok(events) => {
+                    for event in events {
+                        if event.kind == DebouncedEventKind::Any {
+                            if let Err(err) = handle_js_change(&handler_store, &app, &event.path) {
+                                log::error!("Failed to handle JS plugin change: {}", err);
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    log::error!("JS watcher error: {:?}", err);
+                }
+            },
+        )
+        .map_err(|e| format!("Failed to create debouncer: {}", e))?;
+
+        Ok(Self {
+            store,
+            inner: Mutex::new(WatchState {
+                debouncer,
+                dirs: HashSet::new(),
+            }),
+        })
+    }
+
+    /// 스토어의 플러그인 경로에 맞춰 워칭 폴더 갱신
+    pub fn resync_from_store(&self) {
+        let snapshot = self.store.snapshot();
+        // 같은 파일을 두 워처가 처리하면 전체 목록 전송이 플러그인별 리로드를 덮어쓰므로 제외
+        let managed_dir = plugins_dir(self.store.data_dir());
+        let dirs: HashSet<PathBuf> = snapshot
+            .custom_js
+            .plugins
+            .iter()
+            .filter_map(|plugin| plugin.path.as_deref())
+            .filter_map(|path| Path::new(path).parent())
+            .filter(|dir| *dir != managed_dir && dir.is_dir())
+            .map(Path::to_path_buf)
+            .collect();
+
+        let mut inner = self.inner.lock();
+        let WatchState {
+            debouncer,
+            dirs: watched,
+        } = &mut *inner;
+
+        for dir in watched.difference(&dirs) {
+            if let Err(err) = debouncer.watcher().unwatch(dir) {
+                log::warn!("[JsWatcher] Failed to unwatch {:?}: {}", dir, err);
+            }
+        }
+        watched.retain(|dir| dirs.contains(dir));
+
+        for dir in dirs {
+            if watched.contains(&dir) {
+                continue;
+            }
+            match debouncer
+                .watcher()
+                .watch(&dir, notify::RecursiveMode::NonRecursive)
+            {
+                Ok(()) => {
+                    log::info!("[JsWatcher] Started watching: {:?}", dir);
+                    watched.insert(dir);
+                }
+                Err(err) => log::warn!("[JsWatcher] Failed to watch {:?}: {}", dir, err),
+            }
+        }
+    }
+
+    /// 모든 워칭 중지
+    pub fn shutdown(&self) {
+        let mut inner = self.inner.lock();
+        let WatchState { debouncer, dirs } = &mut *inner;
+        for dir in dirs.drain() {
+            let _ = debouncer.watcher().unwatch(&dir);
+        }
+        log::info!("[JsWatcher] Shutdown complete");
+    }
+}
+
+/// JS 플러그인 파일 변경 처리
+fn handle_js_change(store: &AppStore, app: &AppHandle, changed_path: &Path) -> Result<(), String> {
+    let snapshot = store.snapshot();
+    let changed_path_str = changed_path.to_string_lossy().to_string();
+    let targets: Vec<&JsPlugin> = snapshot
+        .custom_js
+        .plugins
+        .iter()
+        .filter(|plugin| {
+            plugin
+                .path
+                .as_deref()
+                .is_some_and(|path| paths_match(path, &changed_path_str))
+        })
+        .collect();
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    log::debug!("[JsWatcher] File changed: {}", changed_path_str);
+
+    let emit_error = |plugin: &JsPlugin, error: String| {
+        let path = plugin.path.clone().unwrap_or_default();
+        log::warn!("[JsWatcher] Failed to reload {}: {}", path, error);
+        app.emit("js:pluginError", &JsPluginError::new(path, error))
+            .map_err(|e| e.to_string())
+    };
+
+    let content = match fs::read_to_string(changed_path) {
+        Ok(content) => content,
+        Err(err) => {
+            for plugin in targets {
+                emit_error(plugin, err.to_string())?;
+            }
+            return Ok(());
+        }
+    };
+
+    let manifest = parse_manifest(&content);
+    let app_version = app.package_info().version.to_string();
+    let mut reloaded = Vec::new();
+    let mut order_changed = false;
+    for plugin in targets {
+        if plugin.content == content {
+            continue;
+        }
+        let next = JsPlugin {
+            content: content.clone(),
+            manifest: manifest.clone(),
+            ..plugin.clone()
+        };
+        match check_compatibility(&next, &app_version) {
+            Ok(()) => {
+                order_changed |= next.dependency_key() != plugin.dependency_key()
+                    || next.declared_dependencies() != plugin.declared_dependencies();
+                reloaded.push(next);
+            }
+            Err(error) => emit_error(plugin, error)?,
+        }
+    }
+    if reloaded.is_empty() {
+        return Ok(());
+    }
+
+    let updated = store
+        .update(|s| {
+            for plugin in &reloaded {
+                if let Some(current) = s
+                    .custom_js
+                    .plugins
+                    .iter_mut()
+                    .find(|current| current.id == plugin.id)
+                {
+                    *current = plugin.clone();
+                }
+            }
+        })
+        .map_err(|e| e.to_string())?;
+
+    // ID나 의존성이 바뀌면 로드 순서를 다시 계산해야 하므로 전체 목록 전송
+    if order_changed {
+        log::info!("[JsWatcher] Dependencies changed in: {}", changed_path_str);
+        return app
+            .emit(
+                "js:content",
+                &JsContentPayload::new(updated.custom_js.clone()),
+            )
+            .map_err(|e| e.to_string());
+    }
+
+    for plugin in reloaded {
+        log::info!(
+            "[JsWatcher] Reloaded plugin {} from: {}",
+            plugin.id,
+            changed_path_str
+        );
+        emit_plugin_reload(app, plugin).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// 플러그인 하나만 다시 불러오도록 윈도우에 알림
+pub fn emit_plugin_reload(app: &AppHandle, plugin: JsPlugin) -> tauri::Result<()> {
+    app.emit("js:pluginReload", &JsPluginReloadEvent { plugin })
+}
@@ -0,0 +1,388 @@
+//! 키 입력 MIDI 출력
+//!
+//! 현재 모드의 키 매핑(`MidiOutputSettings::mappings`)에 따라 키를 누르고 뗄 때
+//! 노트 온/오프 또는 CC 메시지를 보냅니다. 실제 전송은 `MidiSink` 구현이 담당합니다.
+//! - `Port`: 시스템 MIDI 출력 장치 (Windows WinMM, loopMIDI 같은 가상 포트 포함)
+//! - `File`: `<경과 ms> <상태> <데이터1> <데이터2>` 형식(16진수)의 텍스트 기록
+
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+use anyhow::{bail, Context, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::models::{MidiMapping, MidiMappings, MidiOutputBackend, MidiOutputSettings};
+
+/// MIDI 메시지 출력 대상
+pub trait MidiSink: Send {
+    /// 3바이트 채널 메시지 전송
+    fn send(&mut self, message: [u8; 3]) -> Result<()>;
+
+    /// 버퍼에 쌓인 메시지 내보내기 (대기 중인 메시지를 모두 보낸 뒤 호출)
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// MIDI 출력 상태 (설정 화면 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MidiOutputStatus {
+    #[serde(flatten)]
+    pub settings: MidiOutputSettings,
+    pub running: bool,
+}
+
+enum MidiCommand {
+    Key {
+        mode: String,
+        key: String,
+        down: bool,
+    },
+    SetMappings(MidiMappings),
+}
+
+struct MidiRuntime {
+    sink: Box<dyn MidiSink>,
+    channel: u8,
+    velocity: u8,
+    mappings: MidiMappings,
+    /// 눌린 키 → 뗄 때 보낼 메시지 (누른 뒤 모드나 매핑이 바뀌어도 같은 음을 끔)
+    held: HashMap<String, [u8; 3]>,
+}
+
+impl MidiRuntime {
+    fn send(&mut self, message: [u8; 3]) {
+        if let Err(err) = self.sink.send(message) {
+            log::debug!("[MidiOutput] Send failed: {err:#}");
+        }
+    }
+
+    fn handle(&mut self, command: MidiCommand) {
+        match command {
+            MidiCommand::SetMappings(mappings) => self.mappings = mappings,
+            MidiCommand::Key {
+                key, down: false, ..
+            } => {
+                if let Some(message) = self.held.remove(&key) {
+                    self.send(message);
+                }
+            }
+            MidiCommand::Key {
+                mode,
+                key,
+                down: true,
+            } => {
+                let Some(mapping) = self
+                    .mappings
+                    .get(&mode)
+                    .and_then(|keys| keys.get(&key))
+                    .copied()
+                else {
+                    return;
+                };
+                let (on, off) = channel_messages(mapping, self.channel, self.velocity);
+                if let Some(previous) = self.held.insert(key, off) {
+                    self.send(previous);
+                }
+                self.send(on);
+            }
+        }
+    }
+
+    fn release_all(&mut self) {
+        let pending: Vec<[u8; 3]> = self.held.drain().map(|(_, message)| message).collect();
+        for message in pending {
+            self.send(message);
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Err(err) = self.sink.flush() {
+            log::debug!("[MidiOutput] Flush failed: {err:#}");
+        }
+    }
+}
+
+/// 워커 스레드: 대기 중인 명령을 모두 처리한 뒤 한 번에 flush
+fn run_worker(mut runtime: MidiRuntime, receiver: Receiver<MidiCommand>) {
+    while let Ok(command) = receiver.recv() {
+        runtime.handle(command);
+        while let Ok(command) = receiver.try_recv() {
+            runtime.handle(command);
+        }
+        runtime.flush();
+    }
+    // 송신자가 사라지면 눌린 음을 모두 끄고 종료
+    runtime.release_all();
+    runtime.flush();
+}
+
+struct MidiWorker {
+    sender: Option<Sender<MidiCommand>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for MidiWorker {
+    fn drop(&mut self) {
+        // 송신자를 버리면 워커가 채널 종료를 보고 끝남
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// 키 입력 MIDI 송신기
+///
+/// 장치/파일 I/O가 키 입력 스레드를 막지 않도록 전송은 워커 스레드에서 처리합니다.
+#[derive(Default)]
+pub struct MidiOutput {
+    worker: Mutex<Option<MidiWorker>>,
+}
+
+impl MidiOutput {
+    /// 설정에 맞춰 출력 (재)시작 (비활성화 상태면 중지만 함)
+    pub fn restart(&self, settings: &MidiOutputSettings) -> Result<()> {
+        self.stop();
+        if !settings.enabled {
+            return Ok(());
+        }
+
+        let runtime = MidiRuntime {
+            sink: open_sink(settings)?,
+            channel: settings.channel.clamp(1, 16) - 1,
+            velocity: settings.velocity.clamp(1, 127),
+            mappings: settings.mappings.clone(),
+            held: HashMap::new(),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::Builder::new()
+            .name("midi-output".into())
+            .spawn(move || run_worker(runtime, receiver))
+            .context("failed to spawn MIDI output thread")?;
+
+        *self.worker.lock() = Some(MidiWorker {
+            sender: Some(sender),
+            handle: Some(handle),
+        });
+        log::info!("[MidiOutput] Started ({:?})", settings.backend);
+        Ok(())
+    }
+
+    /// 눌린 음을 모두 끈 뒤 중지
+    pub fn stop(&self) {
+        if self.worker.lock().take().is_some() {
+            log::info!("[MidiOutput] Stopped");
+        }
+    }
+
+    /// 재시작 없이 키 매핑만 교체 (이미 눌린 키는 기존 메시지로 뗌)
+    pub fn set_mappings(&self, mappings: &MidiMappings) {
+        self.post(MidiCommand::SetMappings(mappings.clone()));
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.worker.lock().is_some()
+    }
+
+    pub fn status(&self, settings: MidiOutputSettings) -> MidiOutputStatus {
+        MidiOutputStatus {
+            settings,
+            running: self.is_running(),
+        }
+    }
+
+    /// 키 눌림/뗌 전송 요청 (매핑이 없는 키는 워커에서 무시)
+    pub fn send_key(&self, mode: &str, key: &str, down: bool) {
+        self.post(MidiCommand::Key {
+            mode: mode.to_string(),
+            key: key.to_string(),
+            down,
+        });
+    }
+
+    fn post(&self, command: MidiCommand) {
+        let worker = self.worker.lock();
+        if let Some(sender) = worker.as_ref().and_then(|worker| worker.sender.as_ref()) {
+            let _ = sender.send(command);
+        }
+    }
+}
+
+/// 매핑에 해당하는 (눌림, 뗌) 메시지
+fn channel_messages(mapping: MidiMapping, channel: u8, velocity: u8) -> ([u8; 3], [u8; 3]) {
+    match mapping {
+        MidiMapping::Note { note } => {
+            let note = note.min(127);
+            ([0x90 | channel, note, velocity], [0x80 | channel, note, 0])
+        }
+        MidiMapping::Cc { controller } => {
+            let controller = controller.min(127);
+            (
+                [0xB0 | channel, controller, velocity],
+                [0xB0 | channel, controller, 0],
+            )
+        }
+    }
+}
+
+fn open_sink(settings: &MidiOutputSettings) -> Result<Box<dyn MidiSink>> {
+    match settings.backend {
+        MidiOutputBackend::File => Ok(Box::new(FileSink::open(&settings.file_path)?)),
+        #[cfg(target_os = "windows")]
+        MidiOutputBackend::Port => Ok(Box::new(winmm::WinMmSink::open(&settings.port_name)?)),
+        #[cfg(not(target_os = "windows"))]
+        MidiOutputBackend::Port => bail!("MIDI port output is only supported on Windows"),
+    }
+}
+
+/// 사용 가능한 MIDI 출력 장치 이름 목록
+pub fn port_names() -> Vec<String> {
+    #[cfg(target_os = "windows")]
+    {
+        winmm::port_names()
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        Vec::new()
+    }
+}
+
+/// 메시지를 텍스트로 기록하는 출력 (테스트용)
+struct FileSink {
+    writer: BufWriter<File>,
+    started: Instant,
+}
+
+impl FileSink {
+    fn open(path: &str) -> Result<Self> {
+        if path.trim().is_empty() {
+            bail!("MIDI output file path is empty");
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("failed to open {path}"))?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+            started: Instant::now(),
+        })
+    }
+}
+
+impl MidiSink for FileSink {
+    fn send(&mut self, message: [u8; 3]) -> Result<()> {
+        writeln!(
+            self.writer,
+            "{} {:02X} {:02X} {:02X}",
+            self.started.elapsed().as_millis(),
+            message[0],
+            message[1],
+            message[2]
+        )?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod winmm {
+    use anyhow::{anyhow, bail, Result};
+    use windows::Win32::Media::Audio::{
+        midiOutClose, midiOutGetDevCapsW, midiOutGetNumDevs, midiOutOpen, midiOutReset,
+        midiOutShortMsg, CALLBACK_NULL, HMIDIOUT, MIDIOUTCAPSW,
+    };
+    use windows::Win32::Media::MMSYSERR_NOERROR;
+
+    use super::MidiSink;
+
+    pub fn port_names() -> Vec<String> {
+        let count = unsafe { midiOutGetNumDevs() };
+        (0..count)
+            .map(|id| {
+                let mut caps = MIDIOUTCAPSW::default();
+                let result = unsafe {
+                    midiOutGetDevCapsW(
+                        id as usize,
+                        &mut caps,
+                        std::mem::size_of::<MIDIOUTCAPSW>() as u32,
+                    )
+                };
+                if result != MMSYSERR_NOERROR {
+                    return String::new();
+                }
+                // packed 구조체라 필드를 복사해서 사용
+                let name = caps.szPname;
+                let len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+                String::from_utf16_lossy(&name[..len])
+            })
+            .collect()
+    }
+
+    pub struct WinMmSink {
+        handle: HMIDIOUT,
+    }
+
+    // WinMM 출력 핸들은 다른 스레드로 옮겨 써도 됨 (워커 스레드만 사용)
+    unsafe impl Send for WinMmSink {}
+
+    impl WinMmSink {
+        /// 이름이 같거나 포함하는 장치 열기 (이름이 비어 있으면 첫 번째 장치)
+        pub fn open(name: &str) -> Result<Self> {
+            let names = port_names();
+            let wanted = name.trim().to_lowercase();
+            let id = if wanted.is_empty() {
+                (!names.is_empty()).then_some(0)
+            } else {
+                names
+                    .iter()
+                    .position(|candidate| candidate.to_lowercase() == wanted)
+                    .or_else(|| {
+                        names
+                            .iter()
+                            .position(|candidate| candidate.to_lowercase().contains(&wanted))
+                    })
+            }
+            .ok_or_else(|| anyhow!("MIDI output port not found: {name}"))?;
+
+            let mut handle = HMIDIOUT::default();
+            let result = unsafe { midiOutOpen(&mut handle, id as u32, None, None, CALLBACK_NULL) };
+            if result != MMSYSERR_NOERROR {
+                bail!("failed to open MIDI output {} (error {result})", names[id]);
+            }
+            log::info!("[MidiOutput] Opened port {}", names[id]);
+            Ok(Self { handle })
+        }
+    }
+
+    impl MidiSink for WinMmSink {
+        fn send(&mut self, message: [u8; 3]) -> Result<()> {
+            let packed = u32::from_le_bytes([message[0], message[1], message[2], 0]);
+            let result = unsafe { midiOutShortMsg(self.handle, packed) };
+            if result != MMSYSERR_NOERROR {
+                bail!("midiOutShortMsg failed (error {result})");
+            }
+            Ok(())
+        }
+    }
+
+    impl Drop for WinMmSink {
+        fn drop(&mut self) {
+            unsafe {
+                midiOutReset(self.handle);
+                midiOutClose(self.handle);
+            }
+        }
+    }
+}
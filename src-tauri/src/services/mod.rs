@@ -1,2 +1,22 @@
+pub mod bridge_rpc;
 pub mod css_watcher;
+pub mod history;
+pub mod js_watcher;
+pub mod midi_output;
+pub mod osc_output;
+pub mod persistence;
+pub mod plugin_dir;
+pub mod plugin_manifest;
+pub mod plugin_order;
+pub mod plugin_permissions;
+pub mod plugin_storage;
+pub mod plugin_windows;
+pub mod preset;
+pub mod preset_bundle;
+pub mod preset_merge;
+pub mod profiles;
+pub mod remote_control;
+pub mod remote_server;
+pub mod render_script;
 pub mod settings;
+pub mod store_watcher;
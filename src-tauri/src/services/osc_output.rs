@@ -0,0 +1,260 @@
+//! 키 입력 OSC(Open Sound Control) 출력
+//!
+//! VJ/방송 프로그램에서 받을 수 있도록 키 입력을 OSC 메시지로 UDP 전송합니다.
+//! - `/dmnote/key/<키>` `,sis`: `"down"`/`"up"`, 현재 카운터 값, 모드
+//! - `/dmnote/kps` `,i`: 최근 1초간 누른 횟수 (바뀔 때만, 최대 100ms마다)
+//! - 전송은 별도 스레드에서 하며 초당 최대 메시지 수를 넘는 눌림/kps 메시지는 버림
+//!   (뗌은 받는 쪽에서 키가 눌린 채로 남지 않도록 항상 전송)
+
+use std::collections::VecDeque;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::models::OscOutputSettings;
+
+const KPS_WINDOW: Duration = Duration::from_secs(1);
+const KPS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// OSC 출력 상태 (설정 화면 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OscOutputStatus {
+    #[serde(flatten)]
+    pub settings: OscOutputSettings,
+    pub running: bool,
+}
+
+struct OscKeyEvent {
+    mode: String,
+    key: String,
+    down: bool,
+    count: u32,
+    at: Instant,
+}
+
+struct OscWorker {
+    sender: Option<Sender<OscKeyEvent>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for OscWorker {
+    fn drop(&mut self) {
+        // 송신자를 버리면 워커가 채널 종료를 보고 끝남
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// 키 입력 OSC 송신기
+#[derive(Default)]
+pub struct OscOutput {
+    worker: Mutex<Option<OscWorker>>,
+}
+
+impl OscOutput {
+    /// 설정에 맞춰 송신기 (재)시작 (비활성화 상태면 중지만 함)
+    pub fn restart(&self, settings: &OscOutputSettings) -> Result<()> {
+        self.stop();
+        if !settings.enabled {
+            return Ok(());
+        }
+
+        let target = (settings.host.as_str(), settings.port)
+            .to_socket_addrs()
+            .with_context(|| format!("failed to resolve {}:{}", settings.host, settings.port))?
+            .next()
+            .ok_or_else(|| anyhow!("no address for {}", settings.host))?;
+        let bind_addr: SocketAddr = if target.is_ipv4() {
+            "0.0.0.0:0".parse()?
+        } else {
+            "[::]:0".parse()?
+        };
+        let socket = UdpSocket::bind(bind_addr).context("failed to bind OSC socket")?;
+
+        let (sender, receiver) = mpsc::channel();
+        let max_rate = settings.max_messages_per_second.max(1);
+        let handle = thread::Builder::new()
+            .name("osc-output".into())
+            .spawn(move || run_worker(socket, target, receiver, max_rate))
+            .context("failed to spawn OSC output thread")?;
+
+        *self.worker.lock() = Some(OscWorker {
+            sender: Some(sender),
+            handle: Some(handle),
+        });
+        log::info!("[OscOutput] Sending to {}", target);
+        Ok(())
+    }
+
+    pub fn stop(&self) {
+        if self.worker.lock().take().is_some() {
+            log::info!("[OscOutput] Stopped");
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.worker.lock().is_some()
+    }
+
+    pub fn status(&self, settings: OscOutputSettings) -> OscOutputStatus {
+        OscOutputStatus {
+            settings,
+            running: self.is_running(),
+        }
+    }
+
+    /// 키 눌림/뗌 전송 요청 (실제 전송은 워커 스레드에서 처리)
+    pub fn send_key(&self, mode: &str, key: &str, down: bool, count: u32) {
+        let worker = self.worker.lock();
+        let Some(sender) = worker.as_ref().and_then(|worker| worker.sender.as_ref()) else {
+            return;
+        };
+        let _ = sender.send(OscKeyEvent {
+            mode: mode.to_string(),
+            key: key.to_string(),
+            down,
+            count,
+            at: Instant::now(),
+        });
+    }
+}
+
+fn run_worker(
+    socket: UdpSocket,
+    target: SocketAddr,
+    receiver: Receiver<OscKeyEvent>,
+    max_rate: u32,
+) {
+    let mut limiter = RateLimiter::new(max_rate);
+    let mut presses: VecDeque<Instant> = VecDeque::new();
+    let mut last_kps: Option<usize> = None;
+    let mut last_kps_at = Instant::now();
+    let send = |packet: Vec<u8>| {
+        if let Err(err) = socket.send_to(&packet, target) {
+            log::debug!("[OscOutput] Send failed: {}", err);
+        }
+    };
+
+    loop {
+        match receiver.recv_timeout(KPS_INTERVAL) {
+            Ok(event) => {
+                if event.down {
+                    presses.push_back(event.at);
+                }
+                let address = format!("/dmnote/key/{}", sanitize_address_part(&event.key));
+                let packet = encode_message(
+                    &address,
+                    &[
+                        OscArg::Str(if event.down { "down" } else { "up" }),
+                        OscArg::Int(event.count.min(i32::MAX as u32) as i32),
+                        OscArg::Str(&event.mode),
+                    ],
+                );
+                if !event.down || limiter.allow() {
+                    send(packet);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        let now = Instant::now();
+        while presses
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > KPS_WINDOW)
+        {
+            presses.pop_front();
+        }
+        let kps = presses.len();
+        if last_kps != Some(kps) && now.duration_since(last_kps_at) >= KPS_INTERVAL {
+            let packet = encode_message("/dmnote/kps", &[OscArg::Int(kps as i32)]);
+            if limiter.allow() {
+                send(packet);
+                last_kps = Some(kps);
+                last_kps_at = now;
+            }
+        }
+    }
+}
+
+/// 초당 메시지 수 제한 (토큰 버킷)
+struct RateLimiter {
+    rate: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl RateLimiter {
+    fn new(rate: u32) -> Self {
+        Self {
+            rate: rate as f64,
+            tokens: rate as f64,
+            last: Instant::now(),
+        }
+    }
+
+    fn allow(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+enum OscArg<'a> {
+    Int(i32),
+    Str(&'a str),
+}
+
+/// OSC 주소에 쓸 수 없는 문자를 `_`로 대체
+fn sanitize_address_part(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ' ' | '#' | '*' | ',' | '/' | '?' | '[' | ']' | '{' | '}' => '_',
+            c if c.is_ascii_graphic() => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// OSC 1.0 메시지 인코딩
+fn encode_message(address: &str, args: &[OscArg]) -> Vec<u8> {
+    let mut packet = Vec::new();
+    push_padded_str(&mut packet, address);
+    let tags: String = std::iter::once(',')
+        .chain(args.iter().map(|arg| match arg {
+            OscArg::Int(_) => 'i',
+            OscArg::Str(_) => 's',
+        }))
+        .collect();
+    push_padded_str(&mut packet, &tags);
+    for arg in args {
+        match arg {
+            OscArg::Int(value) => packet.extend_from_slice(&value.to_be_bytes()),
+            OscArg::Str(value) => push_padded_str(&mut packet, value),
+        }
+    }
+    packet
+}
+
+/// null 종료 후 4바이트 단위로 채운 문자열
+fn push_padded_str(packet: &mut Vec<u8>, value: &str) {
+    packet.extend_from_slice(value.as_bytes());
+    packet.push(0);
+    packet.resize(packet.len().next_multiple_of(4), 0);
+}
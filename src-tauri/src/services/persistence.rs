@@ -0,0 +1,162 @@
+//! 디바운스 기반 백그라운드 저장 워커
+//!
+//! 짧은 시간 안에 연속으로 들어온 저장 요청을 하나로 합쳐 디스크에 기록합니다.
+//! - 커맨드 핸들러/키 입력 경로에서 디스크 I/O 제거
+//! - `flush`로 대기 중인 변경을 즉시 기록
+//! - `shutdown` 시 남은 변경을 기록하고 스레드 종료
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::{self, RecvTimeoutError, Sender},
+    Arc,
+};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
+
+/// 연속 변경을 모으는 기본 대기 시간
+pub const DEFAULT_PERSIST_WINDOW: Duration = Duration::from_millis(300);
+/// 변경이 계속 들어와도 이 시간이 지나면 강제로 기록
+const MAX_PERSIST_DELAY: Duration = Duration::from_secs(2);
+
+type PersistFn = dyn Fn() -> Result<()> + Send + Sync;
+
+enum PersistSignal {
+    Dirty,
+    Shutdown,
+}
+
+struct PersistShared {
+    pending: AtomicBool,
+    /// 워커 스레드와 동기 flush가 동시에 파일을 쓰지 않도록 보호
+    write_lock: Mutex<()>,
+    persist: Box<PersistFn>,
+}
+
+impl PersistShared {
+    fn flush_pending(&self) -> Result<()> {
+        let _guard = self.write_lock.lock();
+        if !self.pending.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let result = (self.persist)();
+        if result.is_err() {
+            // 다음 신호에서 다시 시도할 수 있도록 dirty 상태 복원
+            self.pending.store(true, Ordering::SeqCst);
+        }
+        result
+    }
+}
+
+/// 저장 요청을 모아서 처리하는 백그라운드 워커
+pub struct PersistenceWorker {
+    name: String,
+    shared: Arc<PersistShared>,
+    sender: Mutex<Option<Sender<PersistSignal>>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PersistenceWorker {
+    pub fn spawn<F>(name: &str, window: Duration, persist: F) -> Result<Self>
+    where
+        F: Fn() -> Result<()> + Send + Sync + 'static,
+    {
+        let shared = Arc::new(PersistShared {
+            pending: AtomicBool::new(false),
+            write_lock: Mutex::new(()),
+            persist: Box::new(persist),
+        });
+        let (sender, receiver) = mpsc::channel::<PersistSignal>();
+        let worker_shared = shared.clone();
+        let worker_name = name.to_string();
+
+        let handle = thread::Builder::new()
+            .name(format!("{name}-persist"))
+            .spawn(move || {
+                while let Ok(signal) = receiver.recv() {
+                    let mut shutdown = matches!(signal, PersistSignal::Shutdown);
+                    if !shutdown {
+                        // 조용해질 때까지(또는 최대 지연까지) 추가 신호를 흡수
+                        let started = Instant::now();
+                        loop {
+                            let remaining = MAX_PERSIST_DELAY.saturating_sub(started.elapsed());
+                            if remaining.is_zero() {
+                                break;
+                            }
+                            match receiver.recv_timeout(window.min(remaining)) {
+                                Ok(PersistSignal::Dirty) => continue,
+                                Ok(PersistSignal::Shutdown)
+                                | Err(RecvTimeoutError::Disconnected) => {
+                                    shutdown = true;
+                                    break;
+                                }
+                                Err(RecvTimeoutError::Timeout) => break,
+                            }
+                        }
+                    }
+
+                    if let Err(err) = worker_shared.flush_pending() {
+                        log::error!("[Persistence:{}] failed to persist: {err}", worker_name);
+                    }
+                    if shutdown {
+                        break;
+                    }
+                }
+                log::debug!("[Persistence:{}] worker stopped", worker_name);
+            })
+            .map_err(|err| anyhow!("failed to spawn persistence worker: {err}"))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            shared,
+            sender: Mutex::new(Some(sender)),
+            handle: Mutex::new(Some(handle)),
+        })
+    }
+
+    /// 변경 사항을 표시하고 워커에게 저장을 예약
+    pub fn schedule(&self) {
+        self.shared.pending.store(true, Ordering::SeqCst);
+        let sent = self
+            .sender
+            .lock()
+            .as_ref()
+            .map(|sender| sender.send(PersistSignal::Dirty).is_ok())
+            .unwrap_or(false);
+        if !sent {
+            // 워커가 종료된 뒤에는 동기적으로 기록
+            if let Err(err) = self.shared.flush_pending() {
+                log::error!("[Persistence:{}] failed to persist: {err}", self.name);
+            }
+        }
+    }
+
+    /// 대기 중인 변경을 즉시 기록
+    pub fn flush(&self) -> Result<()> {
+        self.shared.flush_pending()
+    }
+
+    /// 남은 변경을 기록하고 워커 스레드 종료
+    pub fn shutdown(&self) -> Result<()> {
+        if let Some(sender) = self.sender.lock().take() {
+            let _ = sender.send(PersistSignal::Shutdown);
+        }
+        if let Some(handle) = self.handle.lock().take() {
+            let _ = handle.join();
+        }
+        self.shared.flush_pending()
+    }
+}
+
+impl Drop for PersistenceWorker {
+    fn drop(&mut self) {
+        if let Err(err) = self.shutdown() {
+            log::error!(
+                "[Persistence:{}] failed to persist on drop: {err}",
+                self.name
+            );
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! 관리 플러그인 폴더
+//!
+//! 앱 데이터 폴더의 `plugins/`에 있는 `.js`/`.mjs` 파일을 `CustomJs.plugins`와 동기화합니다.
+//! - 시작 시 전체 스캔, 이후 파일 추가/변경/삭제를 감지해 반영
+//! - 폴더에서 사라진 파일의 플러그인은 목록에서도 제거 (저장소 데이터는 유지)
+//! - 파일 내용만 바뀌면 전체 `js:content` 대신 플러그인별 `js:pluginReload` 전송
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use notify::RecommendedWatcher;
+use notify_debouncer_mini::{new_debouncer, Debouncer};
+use tauri::{AppHandle, Manager};
+
+use crate::app_state::AppState;
+use crate::models::{CustomJs, JsPlugin};
+use crate::services::js_watcher::emit_plugin_reload;
+use crate::services::plugin_manifest::{check_compatibility, install_plugin, plugin_from_file};
+
+const PLUGIN_DIR_NAME: &str = "plugins";
+/// 파일 복사 중 여러 이벤트가 발생하는 경우를 고려한 디바운스 시간
+const PLUGIN_DIR_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub fn plugins_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join(PLUGIN_DIR_NAME)
+}
+
+fn is_plugin_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("js") || ext.eq_ignore_ascii_case("mjs"))
+}
+
+/// 폴더의 플러그인 파일 목록과 내용 (이름순, 읽지 못한 파일은 `None`)
+fn scan_dir(dir: &Path) -> Result<Vec<(PathBuf, Option<String>)>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("failed to read plugin dir {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| is_plugin_file(path))
+        .collect();
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let content = fs::read_to_string(&path)
+                .map_err(|err| log::warn!("[PluginDir] Failed to read {:?}: {}", path, err))
+                .ok();
+            (path, content)
+        })
+        .collect())
+}
+
+/// 폴더 내용을 플러그인 목록에 반영
+fn apply_dir_files(
+    script: &mut CustomJs,
+    dir: &Path,
+    files: Vec<(PathBuf, Option<String>)>,
+    app_version: &str,
+) {
+    let file_paths: Vec<String> = files
+        .iter()
+        .map(|(path, _)| path.to_string_lossy().to_string())
+        .collect();
+    script.plugins.retain(|plugin| {
+        let Some(path) = plugin.path.as_deref() else {
+            return true;
+        };
+        Path::new(path).parent() != Some(dir) || file_paths.iter().any(|file| file == path)
+    });
+
+    // 복사 중이거나 잠긴 파일은 기존 항목을 유지하고 다음 이벤트에서 다시 시도
+    for (path, content) in files {
+        let Some(content) = content else {
+            continue;
+        };
+        let path_string = path.to_string_lossy();
+        let unchanged = script.plugins.iter().any(|plugin| {
+            plugin.path.as_deref() == Some(path_string.as_ref()) && plugin.content == content
+        });
+        if unchanged {
+            continue;
+        }
+        let plugin = plugin_from_file(&path, content);
+        if let Err(error) = check_compatibility(&plugin, app_version) {
+            log::warn!("[PluginDir] Skipping {:?}: {}", path, error);
+            continue;
+        }
+        install_plugin(&mut script.plugins, plugin);
+    }
+}
+
+/// 폴더와 플러그인 목록을 맞추고 바뀐 내용을 윈도우에 전파
+pub fn sync_plugin_dir(state: &AppState, app: &AppHandle) -> Result<()> {
+    let dir = plugins_dir(state.store.data_dir());
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create plugin dir {}", dir.display()))?;
+    let files = scan_dir(&dir)?;
+
+    let previous = state.store.snapshot();
+    let mut script = previous.custom_js.clone();
+    let _ = script.normalize();
+    let app_version = app.package_info().version.to_string();
+    apply_dir_files(&mut script, &dir, files, &app_version);
+    let _ = script.normalize();
+    if script == previous.custom_js {
+        return Ok(());
+    }
+
+    let reloaded = content_only_changes(&previous.custom_js, &script);
+    log::info!("[PluginDir] Syncing plugins from {:?}", dir);
+    state.store.update(|store| {
+        store.custom_js = script.clone();
+    })?;
+    let Some(reloaded) = reloaded else {
+        return state.sync_with_store(app, &previous);
+    };
+    for plugin in reloaded {
+        log::info!("[PluginDir] Reloaded plugin {}", plugin.id);
+        emit_plugin_reload(app, plugin)?;
+    }
+    Ok(())
+}
+
+/// 파일 내용(과 헤더)만 바뀐 플러그인
+///
+/// 목록, 순서, 권한, ID, 의존성 중 하나라도 바뀌면 로드 순서를 다시 계산해야 하므로 `None`
+fn content_only_changes(previous: &CustomJs, next: &CustomJs) -> Option<Vec<JsPlugin>> {
+    if previous.path != next.path
+        || previous.content != next.content
+        || previous.plugins.len() != next.plugins.len()
+    {
+        return None;
+    }
+    let mut changed = Vec::new();
+    for (before, after) in previous.plugins.iter().zip(&next.plugins) {
+        if before == after {
+            continue;
+        }
+        let same_entry = JsPlugin {
+            content: before.content.clone(),
+            manifest: before.manifest.clone(),
+            ..after.clone()
+        } == *before;
+        if !same_entry
+            || before.dependency_key() != after.dependency_key()
+            || before.declared_dependencies() != after.declared_dependencies()
+        {
+            return None;
+        }
+        changed.push(after.clone());
+    }
+    Some(changed)
+}
+
+pub struct PluginDirWatcher {
+    #[allow(dead_code)]
+    debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl PluginDirWatcher {
+    pub fn start(dir: PathBuf, app: AppHandle) -> Result<Self, String> {
+        let mut debouncer = new_debouncer(
+            PLUGIN_DIR_DEBOUNCE,
+            move |res: Result<Vec<notify_debouncer_mini::DebouncedEvent>, notify::Error>| {
+                if let Err(err) = res {
+                    log::error!("Plugin dir watcher error: {:?}", err);
+                    return;
+                }
+                let Some(state) = app.try_state::<AppState>() else {
+                    return;
+                };
+                if let Err(err) = sync_plugin_dir(&state, &app) {
+                    log::error!("[PluginDir] Failed to sync plugins: {:#}", err);
+                }
+            },
+        )
+        .map_err(|e| format!("Failed to create debouncer: {}", e))?;
+
+        debouncer
+            .watcher()
+            .watch(&dir, notify::RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to start watching: {}", e))?;
+
+        log::info!("[PluginDir] Started watching: {:?}", dir);
+        Ok(Self { debouncer })
+    }
+}
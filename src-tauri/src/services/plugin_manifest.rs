@@ -0,0 +1,289 @@
+//! 플러그인 헤더(매니페스트) 파싱
+//!
+//! 파일 맨 앞의 주석 블록에서 `@키 값` 또는 `@키: 값` 형식의 메타데이터를 읽습니다.
+//!
+//! ```js
+//! // @id kps-new
+//! // @name KPS 표시
+//! // @version 1.2.0
+//! // @author someone
+//! // @description 초당 입력 수 표시
+//! // @min-version 1.3.0
+//! // @window overlay
+//! // @permissions storage, raw-input
+//! // @requires ui-helper
+//! ```
+//!
+//! `/** ... */` 블록 주석 안의 ` * @id ...` 줄도 인식합니다.
+
+use std::path::Path;
+
+use uuid::Uuid;
+
+use crate::models::{JsPlugin, PluginManifest, PluginPermission, PluginWindowTarget};
+
+/// 플러그인 설치 결과
+pub enum PluginInstall {
+    Added(JsPlugin),
+    /// 같은 플러그인(선언된 ID 또는 파일 경로)의 새 버전으로 교체
+    Updated(JsPlugin),
+}
+
+/// 주석이 아닌 코드가 나오기 전까지의 헤더에서 매니페스트 추출 (메타데이터가 없으면 `None`)
+pub fn parse_manifest(content: &str) -> Option<PluginManifest> {
+    let mut manifest = PluginManifest::default();
+    let mut found = false;
+    let mut in_block = false;
+
+    for raw in content.lines() {
+        let line = raw.trim();
+        let body = if in_block {
+            if let Some(index) = line.find("*/") {
+                in_block = false;
+                &line[..index]
+            } else {
+                line
+            }
+        } else if line.is_empty() {
+            continue;
+        } else if let Some(rest) = line.strip_prefix("//") {
+            rest
+        } else if let Some(rest) = line.strip_prefix("/*") {
+            match rest.find("*/") {
+                Some(index) => &rest[..index],
+                None => {
+                    in_block = true;
+                    rest
+                }
+            }
+        } else {
+            // 헤더 주석이 끝나면 중단
+            break;
+        };
+
+        let body = body.trim().trim_start_matches('*').trim();
+        if let Some((key, value)) = parse_tag(body) {
+            found |= apply_tag(&mut manifest, &key, value);
+        }
+    }
+
+    found.then_some(manifest)
+}
+
+/// `@key value` / `@key: value` 분리
+fn parse_tag(line: &str) -> Option<(String, &str)> {
+    let rest = line.strip_prefix('@')?;
+    let key_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        .unwrap_or(rest.len());
+    if key_len == 0 {
+        return None;
+    }
+    let (key, value) = rest.split_at(key_len);
+    let value = value.trim_start();
+    let value = value.strip_prefix(':').unwrap_or(value).trim();
+    Some((key.to_ascii_lowercase().replace('_', "-"), value))
+}
+
+fn apply_tag(manifest: &mut PluginManifest, key: &str, value: &str) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+    let value_string = || Some(value.to_string());
+    match key {
+        "id" => {
+            let id = normalize_plugin_id(value);
+            if id.is_empty() {
+                return false;
+            }
+            manifest.id = Some(id);
+        }
+        "name" => manifest.name = value_string(),
+        "version" => manifest.version = value_string(),
+        "author" => manifest.author = value_string(),
+        "description" => manifest.description = value_string(),
+        "min-version" | "min-dmnote-version" | "mindmnoteversion" | "minversion" => {
+            manifest.min_app_version = value_string()
+        }
+        "window" | "target" => match value.to_ascii_lowercase().as_str() {
+            "main" => manifest.target = Some(PluginWindowTarget::Main),
+            "overlay" => manifest.target = Some(PluginWindowTarget::Overlay),
+            "all" | "both" => manifest.target = Some(PluginWindowTarget::All),
+            _ => return false,
+        },
+        "requires" | "require" | "depends" | "dependencies" => {
+            let mut any = false;
+            for id in value.split([',', ' ']).map(normalize_plugin_id) {
+                if !id.is_empty() && !manifest.dependencies.contains(&id) {
+                    manifest.dependencies.push(id);
+                    any = true;
+                }
+            }
+            return any;
+        }
+        "permissions" | "permission" => {
+            let mut any = false;
+            for name in value.split([',', ' ']).filter(|name| !name.is_empty()) {
+                let Some(permission) = parse_permission(name) else {
+                    log::warn!("[PluginManifest] Unknown permission: {}", name);
+                    continue;
+                };
+                if !manifest.permissions.contains(&permission) {
+                    manifest.permissions.push(permission);
+                }
+                any = true;
+            }
+            return any;
+        }
+        _ => return false,
+    }
+    true
+}
+
+fn parse_permission(name: &str) -> Option<PluginPermission> {
+    serde_json::from_value(serde_json::Value::String(
+        name.trim().to_ascii_lowercase().replace('_', "-"),
+    ))
+    .ok()
+}
+
+/// 선언된 ID를 소문자/하이픈 형식으로 정리 (프런트엔드 네임스페이스와 동일 규칙)
+fn normalize_plugin_id(value: &str) -> String {
+    let token = value.split_whitespace().next().unwrap_or_default();
+    token
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+/// 앱 버전이 최소 요구 버전 이상인지 (숫자 단위 비교, 해석할 수 없으면 허용)
+pub fn is_app_version_supported(min_version: &str, app_version: &str) -> bool {
+    let parse = |value: &str| -> Option<Vec<u64>> {
+        value
+            .trim()
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()?
+            .split('.')
+            .map(|part| part.parse().ok())
+            .collect()
+    };
+    let (Some(mut required), Some(mut current)) = (parse(min_version), parse(app_version)) else {
+        return true;
+    };
+    let len = required.len().max(current.len());
+    required.resize(len, 0);
+    current.resize(len, 0);
+    current >= required
+}
+
+/// 파일 내용으로 플러그인 생성 (헤더에 ID가 선언되어 있으면 그대로 사용)
+pub fn plugin_from_file(path: &Path, content: String) -> JsPlugin {
+    let name = path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "plugin.js".to_string());
+    let manifest = parse_manifest(&content);
+    let id = manifest
+        .as_ref()
+        .and_then(|manifest| manifest.id.clone())
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    JsPlugin {
+        id,
+        name,
+        path: Some(path.to_string_lossy().to_string()),
+        content,
+        enabled: true,
+        manifest,
+        // 권한은 사용자가 설치할 때나 플러그인 관리에서 허용한 뒤에만 적용
+        granted_permissions: Some(Vec::new()),
+        order: None,
+    }
+}
+
+/// 교체할 때 유지할 권한 허용 목록
+///
+/// 다른 파일이 같은 `@id`를 선언했으면 모두 비우고, 새 버전이 이전에 허용하지 않은 권한을
+/// 요청하면 이미 허용한 권한만 남겨 사용자에게 다시 묻게 합니다.
+fn carried_grants(current: &JsPlugin, plugin: &JsPlugin) -> Option<Vec<PluginPermission>> {
+    if current.path != plugin.path {
+        return Some(Vec::new());
+    }
+    let granted = match &current.granted_permissions {
+        Some(granted) => granted.clone(),
+        None => current.requestable_permissions().to_vec(),
+    };
+    let requestable = plugin.requestable_permissions();
+    if requestable.iter().all(|permission| granted.contains(permission)) {
+        return current.granted_permissions.clone();
+    }
+    Some(
+        granted
+            .into_iter()
+            .filter(|permission| requestable.contains(permission))
+            .collect(),
+    )
+}
+
+/// 최소 요구 버전 확인 (맞지 않으면 오류 코드 반환)
+pub fn check_compatibility(plugin: &JsPlugin, app_version: &str) -> Result<(), String> {
+    match plugin
+        .manifest
+        .as_ref()
+        .and_then(|manifest| manifest.min_app_version.as_deref())
+    {
+        Some(min_version) if !is_app_version_supported(min_version, app_version) => {
+            Err(format!("requires-dmnote-{}", min_version))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 새 플러그인을 추가하거나 같은 플러그인을 교체
+///
+/// 교체 시 내부 ID, 활성 상태는 유지하고, 허용한 권한은 `carried_grants` 규칙에 따라 유지합니다.
+pub fn install_plugin(plugins: &mut Vec<JsPlugin>, plugin: JsPlugin) -> PluginInstall {
+    let existing = match plugin.declared_id() {
+        // 헤더가 없는 기존 항목은 내부 ID로 비교
+        Some(declared_id) => plugins
+            .iter()
+            .position(|current| current.declared_id().unwrap_or(&current.id) == declared_id),
+        None => None,
+    }
+    .or_else(|| {
+        plugin.path.as_ref().and_then(|path| {
+            plugins
+                .iter()
+                .position(|current| current.path.as_ref() == Some(path))
+        })
+    });
+
+    match existing {
+        Some(index) => {
+            let current = &mut plugins[index];
+            *current = JsPlugin {
+                id: current.id.clone(),
+                enabled: current.enabled,
+                granted_permissions: carried_grants(current, &plugin),
+                order: current.order,
+                ..plugin
+            };
+            PluginInstall::Updated(current.clone())
+        }
+        None => {
+            plugins.push(plugin.clone());
+            PluginInstall::Added(plugin)
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! 플러그인 로드 순서 계산
+//!
+//! 헤더의 `@requires`로 선언된 의존성과 `order` 값으로 로드 순서를 정합니다.
+//! - 의존성이 먼저 로드되고, 그 밖에는 `order`가 작은 플러그인부터 로드
+//! - 의존성이 없거나 비활성화되었거나 순환하는 플러그인은 로드하지 않고 문제로 보고
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::models::{CustomJs, JsPlugin};
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DependencyIssueKind {
+    /// 설치되지 않은 플러그인에 의존
+    Missing,
+    /// 비활성화된 플러그인에 의존
+    Disabled,
+    /// 순환 의존 (`dependencies`는 순환에 포함된 플러그인)
+    Cycle,
+    /// 로드할 수 없는 플러그인에 의존
+    Blocked,
+}
+
+/// 로드하지 못한 플러그인과 그 원인
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginDependencyIssue {
+    /// 플러그인 내부 ID
+    pub plugin_id: String,
+    pub kind: DependencyIssueKind,
+    /// 문제가 된 의존성 ID
+    pub dependencies: Vec<String>,
+}
+
+/// `js:content` 이벤트와 `js_get` 응답 (플러그인 목록 + 계산된 로드 순서)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsContentPayload {
+    #[serde(flatten)]
+    pub script: CustomJs,
+    /// 로드할 플러그인의 내부 ID (로드 순서대로)
+    pub load_order: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependency_issues: Vec<PluginDependencyIssue>,
+}
+
+impl JsContentPayload {
+    pub fn new(script: CustomJs) -> Self {
+        let (load_order, dependency_issues) = resolve_load_order(&script.plugins);
+        for issue in &dependency_issues {
+            log::warn!(
+                "[PluginOrder] Skipping plugin {} ({:?}: {})",
+                issue.plugin_id,
+                issue.kind,
+                issue.dependencies.join(", ")
+            );
+        }
+        Self {
+            script,
+            load_order,
+            dependency_issues,
+        }
+    }
+}
+
+/// 활성화된 플러그인의 로드 순서와 의존성 문제 계산
+pub fn resolve_load_order(plugins: &[JsPlugin]) -> (Vec<String>, Vec<PluginDependencyIssue>) {
+    let mut sorted: Vec<&JsPlugin> = plugins.iter().collect();
+    sorted.sort_by_key(|plugin| plugin.order.unwrap_or(u32::MAX));
+
+    let mut by_key: HashMap<&str, &JsPlugin> = HashMap::new();
+    for plugin in &sorted {
+        by_key.entry(plugin.dependency_key()).or_insert(plugin);
+    }
+
+    // 로드 후보 (인덱스는 순서 우선순위)
+    let candidates: Vec<&JsPlugin> = sorted.into_iter().filter(|plugin| plugin.enabled).collect();
+    let index_of: HashMap<&str, usize> = candidates
+        .iter()
+        .enumerate()
+        .map(|(index, plugin)| (plugin.dependency_key(), index))
+        .collect();
+    let deps: Vec<Vec<usize>> = candidates
+        .iter()
+        .map(|plugin| {
+            let mut indices: Vec<usize> = plugin
+                .declared_dependencies()
+                .iter()
+                .filter_map(|id| index_of.get(id.as_str()).copied())
+                .collect();
+            indices.sort_unstable();
+            indices.dedup();
+            indices
+        })
+        .collect();
+
+    let mut issues = Vec::new();
+    let mut excluded = vec![false; candidates.len()];
+    let issue = |plugin: &JsPlugin, kind, dependencies: Vec<String>| PluginDependencyIssue {
+        plugin_id: plugin.id.clone(),
+        kind,
+        dependencies,
+    };
+
+    for (index, plugin) in candidates.iter().enumerate() {
+        let declared = plugin.declared_dependencies();
+        let missing: Vec<String> = declared
+            .iter()
+            .filter(|id| !by_key.contains_key(id.as_str()))
+            .cloned()
+            .collect();
+        let disabled: Vec<String> = declared
+            .iter()
+            .filter(|id| by_key.get(id.as_str()).is_some_and(|dep| !dep.enabled))
+            .cloned()
+            .collect();
+        if !missing.is_empty() {
+            issues.push(issue(plugin, DependencyIssueKind::Missing, missing));
+            excluded[index] = true;
+        } else if !disabled.is_empty() {
+            issues.push(issue(plugin, DependencyIssueKind::Disabled, disabled));
+            excluded[index] = true;
+        }
+    }
+
+    // 순환 판별: 남은 의존 그래프에서 자기 자신에게 돌아오는 플러그인
+    let reachable = |start: usize, excluded: &[bool]| -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<usize> = deps[start].clone();
+        while let Some(next) = stack.pop() {
+            if excluded[next] || !seen.insert(next) {
+                continue;
+            }
+            stack.extend(deps[next].iter().copied());
+        }
+        seen
+    };
+    let reach: Vec<HashSet<usize>> = (0..candidates.len())
+        .map(|index| reachable(index, &excluded))
+        .collect();
+    for index in 0..candidates.len() {
+        if excluded[index] || !reach[index].contains(&index) {
+            continue;
+        }
+        let mut members: Vec<usize> = reach[index]
+            .iter()
+            .copied()
+            .filter(|other| reach[*other].contains(&index))
+            .collect();
+        members.sort_unstable();
+        let dependencies = members
+            .into_iter()
+            .map(|member| candidates[member].dependency_key().to_string())
+            .collect();
+        issues.push(issue(
+            candidates[index],
+            DependencyIssueKind::Cycle,
+            dependencies,
+        ));
+    }
+    for plugin_issue in &issues {
+        if plugin_issue.kind == DependencyIssueKind::Cycle {
+            if let Some(index) = candidates
+                .iter()
+                .position(|plugin| plugin.id == plugin_issue.plugin_id)
+            {
+                excluded[index] = true;
+            }
+        }
+    }
+
+    // 제외된 플러그인에 의존하는 플러그인도 제외 (더 바뀌지 않을 때까지)
+    loop {
+        let mut changed = false;
+        for index in 0..candidates.len() {
+            if excluded[index] {
+                continue;
+            }
+            let blocked: Vec<String> = deps[index]
+                .iter()
+                .filter(|dep| excluded[**dep])
+                .map(|dep| candidates[*dep].dependency_key().to_string())
+                .collect();
+            if !blocked.is_empty() {
+                issues.push(issue(
+                    candidates[index],
+                    DependencyIssueKind::Blocked,
+                    blocked,
+                ));
+                excluded[index] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // 의존성이 모두 로드된 플러그인 중 순서가 가장 앞선 것부터 선택
+    let mut remaining: Vec<usize> = deps
+        .iter()
+        .enumerate()
+        .map(|(index, indices)| if excluded[index] { 0 } else { indices.len() })
+        .collect();
+    let mut ready: BTreeSet<usize> = (0..candidates.len())
+        .filter(|index| !excluded[*index] && remaining[*index] == 0)
+        .collect();
+    let mut load_order = Vec::new();
+    while let Some(index) = ready.pop_first() {
+        load_order.push(candidates[index].id.clone());
+        for (dependant, indices) in deps.iter().enumerate() {
+            if excluded[dependant] || !indices.contains(&index) {
+                continue;
+            }
+            remaining[dependant] -= 1;
+            if remaining[dependant] == 0 {
+                ready.insert(dependant);
+            }
+        }
+    }
+
+    (load_order, issues)
+}
@@ -0,0 +1,391 @@
+//! 플러그인 권한 검사
+//!
+//! 호출한 쪽은 Rust가 발급한 토큰으로 구분합니다.
+//! - 앱은 페이지가 로드될 때 플러그인보다 먼저 웹뷰별 앱 세션 토큰을 한 번만 받아 `dmnote-app-token` 헤더로 보냄
+//! - 플러그인은 실행 전에 앱이 발급받은 자신의 토큰을 `dmnote-plugin-token` 헤더로 보내고,
+//!   명령별 필요 권한을 확인해 허용되지 않으면 실행 전에 거부
+//! - 유효한 토큰이 없는 호출(`__TAURI_INTERNALS__.invoke` 직접 호출 등)은 모두 거부
+//! - 읽기 전용 명령과 자신의 저장소 네임스페이스는 권한 없이 허용
+//! - 플러그인 관리(js_*)처럼 권한 상승이 가능한 명령과 목록에 없는 명령은 항상 거부
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use serde_json::Value;
+use tauri::ipc::{Invoke, InvokeBody};
+use tauri::{Manager, Runtime};
+use uuid::Uuid;
+
+use crate::app_state::AppState;
+use crate::models::{JsPlugin, PluginPermission};
+
+/// 앱 세션 토큰을 담는 IPC 헤더
+pub const APP_TOKEN_HEADER: &str = "dmnote-app-token";
+/// 플러그인 토큰을 담는 IPC 헤더
+pub const PLUGIN_TOKEN_HEADER: &str = "dmnote-plugin-token";
+/// 토큰 없이 호출할 수 있는 유일한 명령 (웹뷰마다 페이지 로드 후 한 번만 성공)
+pub const SESSION_OPEN_COMMAND: &str = "plugin_session_open";
+
+/// 웹뷰 하나의 호출자 토큰
+#[derive(Default)]
+struct WebviewSession {
+    app_token: Option<String>,
+    /// 플러그인 토큰 -> 스토어의 내부 플러그인 ID
+    plugins: HashMap<String, String>,
+}
+
+/// 웹뷰별로 발급한 호출자 토큰
+///
+/// 페이지가 새로 로드되면 해당 웹뷰의 토큰을 모두 폐기하므로,
+/// 앱 번들이 플러그인보다 먼저 앱 세션 토큰을 가져갑니다.
+#[derive(Default)]
+pub struct PluginSessions {
+    sessions: Mutex<HashMap<String, WebviewSession>>,
+}
+
+enum Caller {
+    App,
+    /// 스토어의 내부 플러그인 ID
+    Plugin(String),
+    Unknown,
+}
+
+impl PluginSessions {
+    /// 페이지 로드 시 웹뷰의 토큰 폐기
+    pub fn reset(&self, label: &str) {
+        self.sessions.lock().remove(label);
+    }
+
+    /// 앱 세션 토큰 발급 (이미 발급했으면 `None`)
+    pub fn open(&self, label: &str) -> Option<String> {
+        let mut sessions = self.sessions.lock();
+        let session = sessions.entry(label.to_string()).or_default();
+        if session.app_token.is_some() {
+            return None;
+        }
+        let token = Uuid::new_v4().to_string();
+        session.app_token = Some(token.clone());
+        Some(token)
+    }
+
+    /// 플러그인 토큰 발급
+    pub fn issue(&self, label: &str, plugin_id: &str) -> String {
+        let token = Uuid::new_v4().to_string();
+        self.sessions
+            .lock()
+            .entry(label.to_string())
+            .or_default()
+            .plugins
+            .insert(token.clone(), plugin_id.to_string());
+        token
+    }
+
+    /// 헤더의 토큰으로 호출자 확인 (플러그인 토큰이 있으면 앱 토큰보다 우선)
+    fn caller(&self, label: &str, app_token: Option<&str>, plugin_token: Option<&str>) -> Caller {
+        let sessions = self.sessions.lock();
+        let Some(session) = sessions.get(label) else {
+            return Caller::Unknown;
+        };
+        if let Some(token) = plugin_token {
+            return match session.plugins.get(token) {
+                Some(plugin_id) => Caller::Plugin(plugin_id.clone()),
+                None => Caller::Unknown,
+            };
+        }
+        match (app_token, session.app_token.as_deref()) {
+            (Some(token), Some(expected)) if token == expected => Caller::App,
+            _ => Caller::Unknown,
+        }
+    }
+}
+
+enum Access {
+    Allowed,
+    Requires(PluginPermission),
+    /// 자신의 네임스페이스는 허용, 그 밖은 `storage` 권한 필요
+    Storage,
+    /// `windows` 권한이 있고 자신의 윈도우(`pluginId`)만 다룰 때 허용
+    PluginWindow,
+    Denied,
+}
+
+fn command_access(command: &str) -> Access {
+    use PluginPermission::*;
+    match command {
+        "app_bootstrap" | "settings_get" | "store_flush" | "keys_get" | "positions_get"
+        | "custom_tabs_list" | "css_get" | "css_get_use" | "css_tab_get_all" | "css_tab_get"
+        | "js_get" | "js_get_use" | "profiles_list" | "overlay_get" | "window_minimize"
+        | "plugin_window_list" => Access::Allowed,
+        "settings_update"
+        | "css_toggle"
+        | "css_reset"
+        | "css_set_content"
+        | "css_load"
+        | "css_tab_load"
+        | "css_tab_clear"
+        | "css_tab_toggle"
+        | "overlay_set_visible"
+        | "overlay_set_lock"
+        | "overlay_set_anchor"
+        | "overlay_resize" => Access::Requires(Settings),
+        "keys_update"
+        | "positions_update"
+        | "keys_set_mode"
+        | "keys_reset_all"
+        | "keys_reset_mode"
+        | "keys_reset_counters"
+        | "keys_reset_counters_mode"
+        | "keys_reset_single_counter"
+        | "custom_tabs_create"
+        | "custom_tabs_delete"
+        | "custom_tabs_select"
+        | "history_undo"
+        | "history_redo" => Access::Requires(Keys),
+        "raw_input_subscribe" | "raw_input_unsubscribe" => Access::Requires(RawInput),
+        "preset_save"
+        | "preset_load"
+        | "preset_export"
+        | "preset_import"
+        | "preset_preview"
+        | "preset_import_selective"
+        | "profiles_create"
+        | "profiles_clone"
+        | "profiles_rename"
+        | "profiles_delete"
+        | "profiles_switch" => Access::Requires(Presets),
+        "plugin_bridge_send"
+        | "plugin_bridge_send_to"
+        | "plugin_bridge_call"
+        | "plugin_bridge_reply" => Access::Requires(Bridge),
+        "app_open_external" => Access::Requires(External),
+        "plugin_window_open" | "plugin_window_close" => Access::PluginWindow,
+        command if command.starts_with("plugin_storage_") => Access::Storage,
+        _ => Access::Denied,
+    }
+}
+
+/// 명령 핸들러 앞에서 플러그인 호출 권한 확인
+pub fn guarded<R: Runtime>(
+    handler: impl Fn(Invoke<R>) -> bool + Send + Sync + 'static,
+) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    move |invoke| {
+        if let Err(error) = guard_invoke(&invoke) {
+            invoke.resolver.reject(error);
+            return true;
+        }
+        handler(invoke)
+    }
+}
+
+/// IPC 호출 검사 (앱 세션 토큰이 있는 앱 자체 호출은 그대로 통과)
+fn guard_invoke<R: Runtime>(invoke: &Invoke<R>) -> Result<(), String> {
+    let command = invoke.message.command();
+    if command == SESSION_OPEN_COMMAND {
+        return Ok(());
+    }
+    let webview = invoke.message.webview();
+    let Some(sessions) = webview.try_state::<PluginSessions>() else {
+        return Err("permission-denied:not-ready".to_string());
+    };
+    let header = |name: &str| {
+        invoke
+            .message
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    let plugin_id = match sessions.caller(
+        webview.label(),
+        header(APP_TOKEN_HEADER),
+        header(PLUGIN_TOKEN_HEADER),
+    ) {
+        Caller::App => return Ok(()),
+        Caller::Plugin(plugin_id) => plugin_id,
+        Caller::Unknown => {
+            log::warn!(
+                "[PluginPermissions] Denied '{}' without a valid token ({})",
+                command,
+                webview.label()
+            );
+            return Err("permission-denied:unauthenticated".to_string());
+        }
+    };
+    let args = match invoke.message.payload() {
+        InvokeBody::Json(value) => value,
+        _ => &Value::Null,
+    };
+    let Some(state) = webview.try_state::<AppState>() else {
+        return Err("permission-denied:not-ready".to_string());
+    };
+    state.store.read(|data| {
+        let plugin = data
+            .custom_js
+            .plugins
+            .iter()
+            .find(|plugin| plugin.id == plugin_id);
+        check_plugin_call(plugin, command, args)
+    })
+}
+
+/// 플러그인 호출 허용 여부 확인 (거부 시 오류 코드 반환)
+///
+/// 토큰을 받은 뒤 삭제된 플러그인(`None`)은 권한 없이 쓸 수 있는 명령만 허용합니다.
+pub fn check_plugin_call(
+    plugin: Option<&JsPlugin>,
+    command: &str,
+    args: &Value,
+) -> Result<(), String> {
+    // 플러그인 컨텍스트에서 쓰는 ID (저장소/윈도우의 `pluginId`와 같은 규칙)
+    let caller = plugin.map(JsPlugin::storage_namespace).unwrap_or_default();
+    let has = |permission| plugin.is_some_and(|plugin| plugin.has_permission(permission));
+    let denied = |reason: &str| {
+        log::warn!(
+            "[PluginPermissions] Denied '{}' for plugin '{}' ({})",
+            command,
+            caller,
+            reason
+        );
+        Err(format!("permission-denied:{}", reason))
+    };
+
+    match command_access(command) {
+        Access::Allowed => Ok(()),
+        Access::Requires(permission) if has(permission) => Ok(()),
+        Access::Requires(permission) => denied(permission_name(permission)),
+        Access::Storage => {
+            if has(PluginPermission::Storage)
+                || (plugin.is_some() && is_own_storage(&caller, command, args))
+            {
+                Ok(())
+            } else {
+                denied("storage")
+            }
+        }
+        Access::PluginWindow => {
+            if !has(PluginPermission::Windows) {
+                denied("windows")
+            } else if args.get("pluginId").and_then(Value::as_str) == Some(caller.as_str()) {
+                Ok(())
+            } else {
+                denied("plugin-id")
+            }
+        }
+        Access::Denied => denied("command"),
+    }
+}
+
+fn permission_name(permission: PluginPermission) -> &'static str {
+    match permission {
+        PluginPermission::Storage => "storage",
+        PluginPermission::Bridge => "bridge",
+        PluginPermission::RawInput => "raw-input",
+        PluginPermission::External => "external",
+        PluginPermission::Settings => "settings",
+        PluginPermission::Keys => "keys",
+        PluginPermission::Presets => "presets",
+        PluginPermission::Windows => "windows",
+    }
+}
+
+/// 호출이 자신의 네임스페이스 안에서만 동작하는지 확인
+///
+/// 플러그인별 저장소(`pluginId`)는 자신의 ID여야 하고,
+/// 공유 저장소는 `<id>/`로 시작하는 키만 다룰 수 있습니다.
+fn is_own_storage(caller: &str, command: &str, args: &Value) -> bool {
+    if let Some(plugin_id) = args.get("pluginId").and_then(Value::as_str) {
+        return plugin_id == caller;
+    }
+    let namespace = format!("{}/", caller);
+    let owned = |key: Option<&str>| key.is_some_and(|key| key.starts_with(&namespace));
+    let str_arg = |name: &str| args.get(name).and_then(Value::as_str);
+
+    match command {
+        "plugin_storage_get" | "plugin_storage_set" | "plugin_storage_remove" => {
+            owned(str_arg("key"))
+        }
+        "plugin_storage_has_data" | "plugin_storage_clear_by_prefix" => owned(str_arg("prefix")),
+        // 키 목록은 래퍼가 자신의 접두사로 걸러서 사용
+        "plugin_storage_keys" => true,
+        "plugin_storage_get_many" => args
+            .get("keys")
+            .and_then(Value::as_array)
+            .is_some_and(|keys| keys.iter().all(|key| owned(key.as_str()))),
+        "plugin_storage_set_many" => args
+            .get("entries")
+            .and_then(Value::as_object)
+            .is_some_and(|entries| entries.keys().all(|key| owned(Some(key)))),
+        "plugin_storage_transaction" => {
+            args.get("ops")
+                .and_then(Value::as_array)
+                .is_some_and(|ops| {
+                    ops.iter()
+                        .all(|op| owned(op.get("key").and_then(Value::as_str)))
+                })
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn plugin_without_grants() -> JsPlugin {
+        JsPlugin {
+            id: "js-plugin-1".to_string(),
+            name: "sample.js".to_string(),
+            path: None,
+            content: String::new(),
+            enabled: true,
+            manifest: None,
+            granted_permissions: Some(Vec::new()),
+            order: None,
+        }
+    }
+
+    /// 플러그인이 `globalThis.api`처럼 실제 전역을 거쳐 보낼 수 있는 호출은 모두 거부
+    #[test]
+    fn denied_command_through_global_api_is_rejected() {
+        let sessions = PluginSessions::default();
+        let plugin = plugin_without_grants();
+        let args = json!({ "patch": { "alwaysOnTop": true } });
+
+        // 앱 번들이 먼저 앱 세션 토큰을 가져가므로 플러그인은 다시 받을 수 없음
+        let app_token = sessions.open("main").expect("app token");
+        assert!(sessions.open("main").is_none());
+        let plugin_token = sessions.issue("main", &plugin.id);
+
+        // 전역에 앱 API가 없으므로 토큰 없이 직접 호출하거나 토큰을 추측하는 수밖에 없음
+        assert!(matches!(
+            sessions.caller("main", None, None),
+            Caller::Unknown
+        ));
+        assert!(matches!(
+            sessions.caller("main", Some("guessed"), None),
+            Caller::Unknown
+        ));
+        // 앱 토큰과 함께 보내도 플러그인 토큰이 있으면 플러그인으로 검사
+        let Caller::Plugin(plugin_id) =
+            sessions.caller("main", Some(&app_token), Some(&plugin_token))
+        else {
+            panic!("plugin token must identify the plugin");
+        };
+        assert_eq!(plugin_id, plugin.id);
+        assert_eq!(
+            check_plugin_call(Some(&plugin), "settings_update", &args),
+            Err("permission-denied:settings".to_string())
+        );
+
+        // 페이지를 다시 불러오면 이전 토큰은 모두 무효
+        sessions.reset("main");
+        assert!(matches!(
+            sessions.caller("main", Some(&app_token), None),
+            Caller::Unknown
+        ));
+        assert!(matches!(
+            sessions.caller("main", None, Some(&plugin_token)),
+            Caller::Unknown
+        ));
+    }
+}
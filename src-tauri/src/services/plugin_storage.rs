@@ -0,0 +1,739 @@
+//! 플러그인별 네임스페이스 저장소
+//!
+//! 플러그인 ID마다 별도의 키 공간과 파일(`plugin-data/<id>.json`)을 사용합니다.
+//! - store.json과 분리되어 설정 필드와 충돌하지 않음
+//! - 파일명은 ID를 손실 없이 인코딩하므로 서로 다른 ID가 같은 파일을 쓰지 않음
+//! - 예전에 store.json에 `plugin_data_<id>/<키>`로 저장된 값은 시작 시 옮겨 옴
+//! - 플러그인별 용량 제한 (직렬화된 JSON 크기 기준, 바뀐 항목의 크기만 다시 계산)
+//! - 변경은 백그라운드 워커가 모아서 기록
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::services::persistence::{PersistenceWorker, DEFAULT_PERSIST_WINDOW};
+use crate::store::AppStore;
+
+const PLUGIN_DATA_DIR: &str = "plugin-data";
+/// store.json 공유 키 공간의 키 접두사 (`plugin_data_<키>`)
+pub const STORE_KEY_PREFIX: &str = "plugin_data_";
+/// 플러그인 ID 최대 길이 (파일명으로 쓰이므로 제한)
+const MAX_PLUGIN_ID_LEN: usize = 128;
+/// 플러그인 하나가 사용할 수 있는 최대 용량
+pub const DEFAULT_PLUGIN_QUOTA_BYTES: usize = 5 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginStorageError {
+    #[error("invalid-plugin-id")]
+    InvalidPluginId,
+    #[error("quota-exceeded")]
+    QuotaExceeded,
+    #[error("{0}")]
+    Io(#[from] anyhow::Error),
+}
+
+type Entries = Map<String, Value>;
+
+/// 플러그인 하나의 값과 직렬화 크기
+struct Namespace {
+    entries: Entries,
+    /// 항목별 직렬화 크기(`"키":값`)의 합
+    entry_bytes: usize,
+}
+
+impl Namespace {
+    fn new(entries: Entries) -> Self {
+        let entry_bytes = entries
+            .iter()
+            .map(|(key, value)| entry_size(key, value))
+            .sum();
+        Self {
+            entries,
+            entry_bytes,
+        }
+    }
+
+    /// 압축 JSON으로 직렬화했을 때의 크기 (`{}`와 항목 사이의 `,` 포함)
+    fn size(&self) -> usize {
+        2 + self.entry_bytes + self.entries.len().saturating_sub(1)
+    }
+
+    fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        if let Some(old) = self.entries.get(&key) {
+            self.entry_bytes -= entry_size(&key, old);
+        }
+        self.entry_bytes += entry_size(&key, &value);
+        self.entries.insert(key, value)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Value> {
+        let old = self.entries.remove(key)?;
+        self.entry_bytes -= entry_size(key, &old);
+        Some(old)
+    }
+}
+
+/// 변경 중인 네임스페이스
+///
+/// 키마다 처음 바뀌기 전의 값을 보관해 두었다가, 용량을 넘으면 전체를 복제하지 않고 그 키만 되돌립니다.
+struct NamespaceEdit<'a> {
+    namespace: &'a mut Namespace,
+    original: HashMap<String, Option<Value>>,
+}
+
+impl<'a> NamespaceEdit<'a> {
+    fn new(namespace: &'a mut Namespace) -> Self {
+        Self {
+            namespace,
+            original: HashMap::new(),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Value> {
+        self.namespace.entries.get(key)
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &String> {
+        self.namespace.entries.keys()
+    }
+
+    fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        let old = self.namespace.insert(key.clone(), value);
+        self.original.entry(key).or_insert_with(|| old.clone());
+        old
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Value> {
+        let old = self.namespace.remove(key)?;
+        if !self.original.contains_key(key) {
+            self.original.insert(key.to_string(), Some(old.clone()));
+        }
+        Some(old)
+    }
+
+    /// 모든 값을 삭제하고 삭제된 항목 반환
+    fn clear(&mut self) -> Vec<(String, Value)> {
+        let keys: Vec<String> = self.keys().cloned().collect();
+        keys.into_iter()
+            .filter_map(|key| self.remove(&key).map(|value| (key, value)))
+            .collect()
+    }
+
+    fn changed(&self) -> bool {
+        self.original
+            .iter()
+            .any(|(key, original)| self.namespace.entries.get(key) != original.as_ref())
+    }
+
+    fn rollback(self) {
+        for (key, original) in self.original {
+            match original {
+                Some(value) => {
+                    self.namespace.insert(key, value);
+                }
+                None => {
+                    self.namespace.remove(&key);
+                }
+            }
+        }
+    }
+}
+
+/// 일괄 처리 작업
+///
+/// `expected`가 있으면 현재 값이 같을 때만 적용합니다 (`null`은 키가 없어야 함을 의미).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum StorageOp {
+    #[serde(rename_all = "camelCase")]
+    Set {
+        key: String,
+        value: Value,
+        #[serde(default, deserialize_with = "deserialize_expected")]
+        expected: Option<Value>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Remove {
+        key: String,
+        #[serde(default, deserialize_with = "deserialize_expected")]
+        expected: Option<Value>,
+    },
+}
+
+impl StorageOp {
+    pub fn key(&self) -> &str {
+        match self {
+            StorageOp::Set { key, .. } | StorageOp::Remove { key, .. } => key,
+        }
+    }
+
+    /// 키를 변환한 작업 (store.json 네임스페이스 적용 등)
+    pub fn map_key(self, map: impl FnOnce(String) -> String) -> Self {
+        match self {
+            StorageOp::Set {
+                key,
+                value,
+                expected,
+            } => StorageOp::Set {
+                key: map(key),
+                value,
+                expected,
+            },
+            StorageOp::Remove { key, expected } => StorageOp::Remove {
+                key: map(key),
+                expected,
+            },
+        }
+    }
+}
+
+/// 필드가 없으면 `None`, `null`이면 `Some(Null)`
+fn deserialize_expected<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageChange {
+    pub key: String,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageConflict {
+    pub key: String,
+    pub current: Option<Value>,
+}
+
+pub enum BatchOutcome {
+    Applied(Vec<StorageChange>),
+    /// 조건이 맞지 않아 아무것도 적용하지 않음
+    Conflict(Vec<StorageConflict>),
+}
+
+/// 키/값 저장 공간 (플러그인 네임스페이스와 store.json의 plugin_data 공용)
+pub trait EntryMap {
+    fn entry_value(&self, key: &str) -> Option<&Value>;
+    fn put(&mut self, key: String, value: Value) -> Option<Value>;
+    fn take(&mut self, key: &str) -> Option<Value>;
+}
+
+impl EntryMap for Map<String, Value> {
+    fn entry_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+    fn put(&mut self, key: String, value: Value) -> Option<Value> {
+        self.insert(key, value)
+    }
+    fn take(&mut self, key: &str) -> Option<Value> {
+        self.remove(key)
+    }
+}
+
+impl EntryMap for NamespaceEdit<'_> {
+    fn entry_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+    fn put(&mut self, key: String, value: Value) -> Option<Value> {
+        self.insert(key, value)
+    }
+    fn take(&mut self, key: &str) -> Option<Value> {
+        self.remove(key)
+    }
+}
+
+impl EntryMap for HashMap<String, Value> {
+    fn entry_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+    fn put(&mut self, key: String, value: Value) -> Option<Value> {
+        self.insert(key, value)
+    }
+    fn take(&mut self, key: &str) -> Option<Value> {
+        self.remove(key)
+    }
+}
+
+/// 모든 조건을 먼저 확인한 뒤 작업을 한 번에 적용
+pub fn apply_batch(data: &mut impl EntryMap, ops: Vec<StorageOp>) -> BatchOutcome {
+    let conflicts: Vec<StorageConflict> = ops
+        .iter()
+        .filter_map(|op| {
+            let expected = match op {
+                StorageOp::Set { expected, .. } | StorageOp::Remove { expected, .. } => {
+                    expected.as_ref()?
+                }
+            };
+            let current = data.entry_value(op.key());
+            let matches = match current {
+                Some(current) => current == expected,
+                None => expected.is_null(),
+            };
+            (!matches).then(|| StorageConflict {
+                key: op.key().to_string(),
+                current: current.cloned(),
+            })
+        })
+        .collect();
+    if !conflicts.is_empty() {
+        return BatchOutcome::Conflict(conflicts);
+    }
+
+    // 같은 키를 여러 번 바꾸면 처음 값과 마지막 값만 남김
+    let mut changes: Vec<StorageChange> = Vec::new();
+    for op in ops {
+        let (key, old_value, new_value) = match op {
+            StorageOp::Set { key, value, .. } => {
+                let old = data.put(key.clone(), value.clone());
+                (key, old, Some(value))
+            }
+            StorageOp::Remove { key, .. } => {
+                let old = data.take(&key);
+                (key, old, None)
+            }
+        };
+        match changes.iter_mut().find(|change| change.key == key) {
+            Some(change) => change.new_value = new_value,
+            None => changes.push(StorageChange {
+                key,
+                old_value,
+                new_value,
+            }),
+        }
+    }
+    changes.retain(|change| change.old_value != change.new_value);
+    BatchOutcome::Applied(changes)
+}
+
+struct StorageInner {
+    dir: PathBuf,
+    namespaces: RwLock<HashMap<String, Namespace>>,
+    /// 아직 파일에 기록되지 않은 플러그인 ID
+    dirty: Mutex<HashSet<String>>,
+}
+
+impl StorageInner {
+    fn namespace_path(&self, plugin_id: &str) -> PathBuf {
+        // 소문자/숫자/`-`/`_` 외의 바이트는 `.xx`(16진수)로 인코딩
+        // (대소문자를 구분하지 않는 파일 시스템에서도 서로 다른 ID가 겹치지 않음)
+        let mut safe = String::with_capacity(plugin_id.len());
+        for byte in plugin_id.bytes() {
+            if byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'-' | b'_') {
+                safe.push(byte as char);
+            } else {
+                safe.push_str(&format!(".{byte:02x}"));
+            }
+        }
+        self.dir.join(format!("{safe}.json"))
+    }
+
+    fn persist_dirty(&self) -> Result<()> {
+        let pending: Vec<String> = self.dirty.lock().drain().collect();
+        let mut failed = Vec::new();
+        let mut last_error = None;
+        for plugin_id in pending {
+            let result = self
+                .serialize(&plugin_id)
+                .and_then(|json| self.write_namespace(&plugin_id, json.as_deref()));
+            if let Err(err) = result {
+                last_error = Some(err);
+                failed.push(plugin_id);
+            }
+        }
+        if let Some(err) = last_error {
+            self.dirty.lock().extend(failed);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// 기록할 JSON (비어 있거나 삭제된 네임스페이스는 `None`)
+    ///
+    /// 복제하지 않고 읽기 잠금 안에서 바로 직렬화합니다.
+    fn serialize(&self, plugin_id: &str) -> Result<Option<Vec<u8>>> {
+        match self.namespaces.read().get(plugin_id) {
+            Some(namespace) if !namespace.entries.is_empty() => {
+                Ok(Some(serde_json::to_vec_pretty(&namespace.entries)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn write_namespace(&self, plugin_id: &str, json: Option<&[u8]>) -> Result<()> {
+        let path = self.namespace_path(plugin_id);
+        match json {
+            Some(json) => {
+                fs::create_dir_all(&self.dir).with_context(|| {
+                    format!("failed to create plugin data dir {}", self.dir.display())
+                })?;
+                fs::write(&path, json)
+                    .with_context(|| format!("failed to write plugin data at {}", path.display()))
+            }
+            // 비어 있거나 삭제된 네임스페이스는 파일도 제거
+            None => match fs::remove_file(&path) {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(anyhow!(
+                    "failed to remove plugin data at {}: {err}",
+                    path.display()
+                )),
+            },
+        }
+    }
+}
+
+pub struct PluginStorage {
+    inner: Arc<StorageInner>,
+    quota: usize,
+    persistence: PersistenceWorker,
+}
+
+impl PluginStorage {
+    pub fn new(data_dir: &Path) -> Result<Self> {
+        let inner = Arc::new(StorageInner {
+            dir: data_dir.join(PLUGIN_DATA_DIR),
+            namespaces: RwLock::new(HashMap::new()),
+            dirty: Mutex::new(HashSet::new()),
+        });
+        let persistence = {
+            let inner = inner.clone();
+            PersistenceWorker::spawn("plugin-storage", DEFAULT_PERSIST_WINDOW, move || {
+                inner.persist_dirty()
+            })?
+        };
+        Ok(Self {
+            inner,
+            quota: DEFAULT_PLUGIN_QUOTA_BYTES,
+            persistence,
+        })
+    }
+
+    pub fn get(&self, plugin_id: &str, key: &str) -> Result<Option<Value>, PluginStorageError> {
+        self.read(plugin_id, |data| data.entries.get(key).cloned())
+    }
+
+    /// 값을 저장하고 이전 값 반환
+    pub fn set(
+        &self,
+        plugin_id: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<Option<Value>, PluginStorageError> {
+        self.write(plugin_id, |data| data.insert(key.to_string(), value))
+    }
+
+    /// 값을 삭제하고 이전 값 반환
+    pub fn remove(&self, plugin_id: &str, key: &str) -> Result<Option<Value>, PluginStorageError> {
+        self.write(plugin_id, |data| data.remove(key))
+    }
+
+    /// 모든 값을 삭제하고 삭제된 항목 반환
+    pub fn clear(&self, plugin_id: &str) -> Result<Vec<(String, Value)>, PluginStorageError> {
+        self.write(plugin_id, |data| data.clear())
+    }
+
+    /// 여러 키를 한 번에 조회 (없는 키는 `None`)
+    pub fn get_many(
+        &self,
+        plugin_id: &str,
+        keys: &[String],
+    ) -> Result<Vec<(String, Option<Value>)>, PluginStorageError> {
+        self.read(plugin_id, |data| {
+            keys.iter()
+                .map(|key| (key.clone(), data.entries.get(key).cloned()))
+                .collect()
+        })
+    }
+
+    /// 작업 묶음을 원자적으로 적용 (기록은 한 번만 예약)
+    pub fn apply(
+        &self,
+        plugin_id: &str,
+        ops: Vec<StorageOp>,
+    ) -> Result<BatchOutcome, PluginStorageError> {
+        self.write(plugin_id, |data| apply_batch(data, ops))
+    }
+
+    /// 모든 항목 조회 (프리셋 내보내기 등)
+    pub fn entries(&self, plugin_id: &str) -> Result<Vec<(String, Value)>, PluginStorageError> {
+        self.read(plugin_id, |data| {
+            data.entries
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+    }
+
+    /// 모든 항목을 주어진 값으로 교체 (용량을 넘으면 기존 값 유지)
+    pub fn replace(
+        &self,
+        plugin_id: &str,
+        entries: Vec<(String, Value)>,
+    ) -> Result<(), PluginStorageError> {
+        self.write(plugin_id, |data| {
+            data.clear();
+            for (key, value) in entries {
+                data.insert(key, value);
+            }
+        })
+    }
+
+    pub fn keys(&self, plugin_id: &str) -> Result<Vec<String>, PluginStorageError> {
+        self.read(plugin_id, |data| data.entries.keys().cloned().collect())
+    }
+
+    pub fn has_prefix(&self, plugin_id: &str, prefix: &str) -> Result<bool, PluginStorageError> {
+        self.read(plugin_id, |data| {
+            data.entries.keys().any(|k| k.starts_with(prefix))
+        })
+    }
+
+    /// 접두사로 시작하는 키를 삭제하고 삭제된 항목 반환
+    pub fn clear_prefix(
+        &self,
+        plugin_id: &str,
+        prefix: &str,
+    ) -> Result<Vec<(String, Value)>, PluginStorageError> {
+        self.write(plugin_id, |data| {
+            let keys: Vec<String> = data
+                .keys()
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect();
+            keys.into_iter()
+                .filter_map(|key| data.remove(&key).map(|value| (key, value)))
+                .collect()
+        })
+    }
+
+    /// (사용 중인 바이트, 용량 제한)
+    pub fn usage(&self, plugin_id: &str) -> Result<(usize, usize), PluginStorageError> {
+        let used = self.read(plugin_id, Namespace::size)?;
+        Ok((used, self.quota))
+    }
+
+    /// 플러그인 데이터 전체와 파일 삭제 (플러그인 제거 시)
+    pub fn purge(&self, plugin_id: &str) -> Result<(), PluginStorageError> {
+        validate_plugin_id(plugin_id)?;
+        self.inner.namespaces.write().remove(plugin_id);
+        self.inner.dirty.lock().remove(plugin_id);
+        self.inner.write_namespace(plugin_id, None)?;
+        Ok(())
+    }
+
+    /// store.json의 `plugin_data_<ID>/<키>` 항목을 플러그인별 저장소로 옮기고 옮긴 키 수 반환
+    ///
+    /// 옮긴 키는 store.json에서 삭제합니다. 같은 키가 이미 있으면 플러그인별 저장소의 값을 유지하고,
+    /// 용량 제한 등으로 옮기지 못한 플러그인의 값은 그대로 둡니다.
+    pub fn migrate_legacy(&self, store: &AppStore) -> Result<usize> {
+        let mut grouped: HashMap<String, Vec<(String, String)>> = HashMap::new();
+        for store_key in store.get_all_plugin_keys()? {
+            let Some((plugin_id, key)) = store_key
+                .strip_prefix(STORE_KEY_PREFIX)
+                .and_then(|rest| rest.split_once('/'))
+                .map(|(plugin_id, key)| (plugin_id.to_string(), key.to_string()))
+            else {
+                continue;
+            };
+            if validate_plugin_id(&plugin_id).is_err() {
+                continue;
+            }
+            grouped.entry(plugin_id).or_default().push((store_key, key));
+        }
+
+        let mut migrated = 0;
+        for (plugin_id, entries) in grouped {
+            let store_keys: Vec<String> = entries
+                .iter()
+                .map(|(store_key, _)| store_key.clone())
+                .collect();
+            let values = store.get_plugin_data_many(&store_keys)?;
+            let result = self.write(&plugin_id, |data| {
+                for ((_, key), (_, value)) in entries.iter().zip(values) {
+                    if let Some(value) = value {
+                        if data.get(key).is_none() {
+                            data.insert(key.clone(), value);
+                        }
+                    }
+                }
+            });
+            if let Err(err) = result {
+                log::warn!(
+                    "[PluginStorage] Failed to migrate data of {}: {}",
+                    plugin_id,
+                    err
+                );
+                continue;
+            }
+            for store_key in &store_keys {
+                store.remove_plugin_data(store_key)?;
+            }
+            migrated += store_keys.len();
+        }
+        if migrated > 0 {
+            log::info!("[PluginStorage] Migrated {} legacy keys", migrated);
+        }
+        Ok(migrated)
+    }
+
+    pub fn flush(&self) -> Result<()> {
+        self.persistence.flush()
+    }
+
+    pub fn shutdown(&self) -> Result<()> {
+        self.persistence.shutdown()
+    }
+
+    fn read<T>(
+        &self,
+        plugin_id: &str,
+        reader: impl FnOnce(&Namespace) -> T,
+    ) -> Result<T, PluginStorageError> {
+        self.ensure_loaded(plugin_id)?;
+        let namespaces = self.inner.namespaces.read();
+        let data = namespaces
+            .get(plugin_id)
+            .ok_or(PluginStorageError::InvalidPluginId)?;
+        Ok(reader(data))
+    }
+
+    /// 변경을 적용한 뒤 용량을 확인하고, 넘으면 바뀐 키만 되돌림
+    fn write<T>(
+        &self,
+        plugin_id: &str,
+        writer: impl FnOnce(&mut NamespaceEdit) -> T,
+    ) -> Result<T, PluginStorageError> {
+        self.ensure_loaded(plugin_id)?;
+        let output = {
+            let mut namespaces = self.inner.namespaces.write();
+            let namespace = namespaces
+                .get_mut(plugin_id)
+                .ok_or(PluginStorageError::InvalidPluginId)?;
+            let previous_size = namespace.size();
+            let mut edit = NamespaceEdit::new(namespace);
+            let output = writer(&mut edit);
+            if !edit.changed() {
+                return Ok(output);
+            }
+            // 이미 제한을 넘은 상태라도 크기를 줄이는 변경은 허용
+            let size = edit.namespace.size();
+            if size > self.quota && size > previous_size {
+                edit.rollback();
+                return Err(PluginStorageError::QuotaExceeded);
+            }
+            output
+        };
+        self.inner.dirty.lock().insert(plugin_id.to_string());
+        self.persistence.schedule();
+        Ok(output)
+    }
+
+    fn ensure_loaded(&self, plugin_id: &str) -> Result<(), PluginStorageError> {
+        validate_plugin_id(plugin_id)?;
+        if self.inner.namespaces.read().contains_key(plugin_id) {
+            return Ok(());
+        }
+        let path = self.inner.namespace_path(plugin_id);
+        let data = if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read plugin data at {}", path.display()))?;
+            serde_json::from_str::<Entries>(&content)
+                .with_context(|| format!("invalid plugin data at {}", path.display()))?
+        } else {
+            Entries::new()
+        };
+        self.inner
+            .namespaces
+            .write()
+            .entry(plugin_id.to_string())
+            .or_insert_with(|| Namespace::new(data));
+        Ok(())
+    }
+}
+
+fn validate_plugin_id(plugin_id: &str) -> Result<(), PluginStorageError> {
+    if plugin_id.trim().is_empty() || plugin_id.len() > MAX_PLUGIN_ID_LEN {
+        return Err(PluginStorageError::InvalidPluginId);
+    }
+    Ok(())
+}
+
+/// 직렬화 결과를 보관하지 않고 크기만 세는 출력
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// 압축 JSON에서 항목 하나(`"키":값`)가 차지하는 크기
+fn entry_size(key: &str, value: &Value) -> usize {
+    let mut counter = ByteCounter(0);
+    // 크기만 세므로 실패하지 않음
+    let _ = serde_json::to_writer(&mut counter, key);
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn serialized_size(namespace: &Namespace) -> usize {
+        serde_json::to_vec(&namespace.entries).unwrap().len()
+    }
+
+    #[test]
+    fn tracked_size_matches_serialized_size() {
+        let mut namespace = Namespace::new(Entries::new());
+        assert_eq!(namespace.size(), serialized_size(&namespace));
+
+        let mut edit = NamespaceEdit::new(&mut namespace);
+        edit.insert("a".into(), json!(1));
+        edit.insert("키\"".into(), json!({ "nested": ["x", null, 2.5] }));
+        edit.insert("a".into(), json!("replaced"));
+        edit.remove("missing");
+        assert_eq!(edit.namespace.size(), serialized_size(edit.namespace));
+
+        edit.remove("a");
+        assert_eq!(edit.namespace.size(), serialized_size(edit.namespace));
+    }
+
+    #[test]
+    fn rollback_restores_values_and_size() {
+        let mut initial = Entries::new();
+        initial.insert("keep".into(), json!("value"));
+        initial.insert("drop".into(), json!([1, 2, 3]));
+        let mut namespace = Namespace::new(initial.clone());
+        let size = namespace.size();
+
+        let mut edit = NamespaceEdit::new(&mut namespace);
+        edit.insert("keep".into(), json!("x".repeat(64)));
+        edit.remove("drop");
+        edit.insert("new".into(), json!(true));
+        edit.clear();
+        assert!(edit.changed());
+        edit.rollback();
+
+        assert_eq!(namespace.entries, initial);
+        assert_eq!(namespace.size(), size);
+    }
+}
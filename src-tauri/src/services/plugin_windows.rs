@@ -0,0 +1,189 @@
+//! 플러그인이 연 윈도우 관리
+//!
+//! 플러그인 윈도우는 `plugin-<플러그인 ID>-<이름>` 레이블을 가지며,
+//! 브릿지 메시지의 대상이 될 수 있습니다.
+//! - ID와 이름은 영숫자 외 문자를 `_xx`(UTF-8 바이트 16진수)로 바꿔 넣으므로 서로 다른
+//!   (ID, 이름) 쌍이 같은 레이블이 되지 않음
+//! - 플러그인이 비활성화/삭제되거나 JS 플러그인이 꺼지면 해당 윈도우를 닫음
+
+use std::collections::{HashMap, HashSet};
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::app_state::AppState;
+use crate::services::plugin_order::resolve_load_order;
+
+pub const PLUGIN_WINDOW_PREFIX: &str = "plugin-";
+/// 앱 기본 윈도우 레이블
+const APP_WINDOW_LABELS: [&str; 2] = ["main", "overlay"];
+
+/// 플러그인 윈도우 생성 옵션
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginWindowOptions {
+    pub title: Option<String>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub resizable: Option<bool>,
+    pub decorations: Option<bool>,
+    pub transparent: Option<bool>,
+    pub always_on_top: Option<bool>,
+}
+
+/// 브릿지로 메시지를 보낼 수 있는 윈도우
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeWindowInfo {
+    pub label: String,
+    /// 플러그인 윈도우를 연 플러그인 ID (앱 윈도우는 `None`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PluginWindowEntry {
+    /// 요청한 플러그인 ID (선언된 ID)
+    plugin_id: String,
+    /// 스토어의 내부 플러그인 ID
+    owner: String,
+    name: String,
+}
+
+/// 열려 있는 플러그인 윈도우 목록
+#[derive(Default)]
+pub struct PluginWindows {
+    windows: RwLock<HashMap<String, PluginWindowEntry>>,
+}
+
+impl PluginWindows {
+    pub fn register(&self, label: &str, plugin_id: &str, owner: &str, name: &str) {
+        self.windows.write().insert(
+            label.to_string(),
+            PluginWindowEntry {
+                plugin_id: plugin_id.to_string(),
+                owner: owner.to_string(),
+                name: name.to_string(),
+            },
+        );
+    }
+
+    pub fn unregister(&self, label: &str) {
+        self.windows.write().remove(label);
+    }
+
+    pub fn contains(&self, label: &str) -> bool {
+        self.windows.read().contains_key(label)
+    }
+
+    /// 레이블의 윈도우를 연 플러그인 ID (등록되지 않은 레이블은 `None`)
+    pub fn plugin_id_of(&self, label: &str) -> Option<String> {
+        self.windows
+            .read()
+            .get(label)
+            .map(|entry| entry.plugin_id.clone())
+    }
+
+    /// 앱 윈도우와 플러그인 윈도우 목록 (실제로 열려 있는 윈도우만)
+    pub fn list(&self, app: &AppHandle) -> Vec<BridgeWindowInfo> {
+        let app_windows = APP_WINDOW_LABELS
+            .iter()
+            .filter(|label| app.get_webview_window(label).is_some())
+            .map(|label| BridgeWindowInfo {
+                label: label.to_string(),
+                plugin_id: None,
+                name: None,
+            });
+        let mut plugin_windows: Vec<BridgeWindowInfo> = self
+            .windows
+            .read()
+            .iter()
+            .filter(|(label, _)| app.get_webview_window(label).is_some())
+            .map(|(label, entry)| BridgeWindowInfo {
+                label: label.clone(),
+                plugin_id: Some(entry.plugin_id.clone()),
+                name: Some(entry.name.clone()),
+            })
+            .collect();
+        plugin_windows.sort_by(|a, b| a.label.cmp(&b.label));
+        app_windows.chain(plugin_windows).collect()
+    }
+
+    /// 브릿지 대상 이름을 윈도우 레이블로 변환
+    pub fn resolve_target(&self, target: &str) -> Result<String, String> {
+        if APP_WINDOW_LABELS.contains(&target) || self.contains(target) {
+            Ok(target.to_string())
+        } else {
+            Err(format!("Unknown target window: {}", target))
+        }
+    }
+
+    /// 로드되지 않는 플러그인의 윈도우 닫기
+    fn close_except(&self, app: &AppHandle, active: &HashSet<String>) {
+        let labels: Vec<String> = self
+            .windows
+            .read()
+            .iter()
+            .filter(|(_, entry)| !active.contains(&entry.owner))
+            .map(|(label, _)| label.clone())
+            .collect();
+        for label in labels {
+            self.unregister(&label);
+            if let Some(window) = app.get_webview_window(&label) {
+                log::info!("[PluginWindows] Closing {} (plugin inactive)", label);
+                if let Err(err) = window.close() {
+                    log::warn!("[PluginWindows] Failed to close {}: {}", label, err);
+                }
+            }
+        }
+    }
+}
+
+/// 플러그인 ID와 이름으로 윈도우 레이블 생성
+///
+/// 영숫자 외 문자는 `_xx`로 바꾸므로 구분자 `-`는 두 값 사이에만 나타남
+pub fn plugin_window_label(plugin_id: &str, name: &str) -> String {
+    let encode = |value: &str| -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("_{byte:02x}"));
+            }
+        }
+        encoded
+    };
+    format!(
+        "{}{}-{}",
+        PLUGIN_WINDOW_PREFIX,
+        encode(plugin_id),
+        encode(name)
+    )
+}
+
+/// 플러그인 목록이나 JS 사용 여부가 바뀐 뒤 로드되지 않는 플러그인의 윈도우 닫기
+pub fn close_inactive_plugin_windows(app: &AppHandle) {
+    let (Some(windows), Some(state)) = (
+        app.try_state::<PluginWindows>(),
+        app.try_state::<AppState>(),
+    ) else {
+        return;
+    };
+    let active: HashSet<String> = state.store.read(|data| {
+        if data.use_custom_js {
+            resolve_load_order(&data.custom_js.plugins)
+                .0
+                .into_iter()
+                .collect()
+        } else {
+            HashSet::new()
+        }
+    });
+    windows.close_except(app, &active);
+}
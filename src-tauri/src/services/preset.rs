@@ -0,0 +1,338 @@
+//! 프리셋 직렬화/검증 서비스
+//!
+//! 파일 다이얼로그 없이 경로나 JSON 페이로드로 프리셋을 주고받을 수 있도록
+//! 프리셋 생성과 검증 로직을 커맨드에서 분리합니다.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::{
+    AppStoreData, CustomCss, CustomJs, CustomTab, KeyCounters, KeyMappings, KeyPositions,
+    NoteSettings, OverlayBounds, OverlayResizeAnchor, TabCssOverrides,
+};
+use crate::services::plugin_storage::{PluginStorage, STORE_KEY_PREFIX};
+
+/// 현재 프리셋 형식 버전 (버전 필드가 없는 프리셋은 1로 취급)
+pub const PRESET_VERSION: u32 = 2;
+
+/// 프리셋 파일 형식
+///
+/// 버전 2에서 추가된 항목은 모두 선택 사항이며, 없으면 현재 값을 그대로 유지합니다.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    pub keys: Option<KeyMappings>,
+    /// 키별 카운터 스타일(`counter`)도 위치 정보에 함께 포함
+    pub key_positions: Option<KeyPositions>,
+    pub background_color: Option<String>,
+    pub note_settings: Option<NoteSettings>,
+    pub note_effect: Option<bool>,
+    pub laboratory_enabled: Option<bool>,
+    pub custom_tabs: Option<Vec<CustomTab>>,
+    pub selected_key_type: Option<String>,
+    #[serde(rename = "useCustomCSS")]
+    pub use_custom_css: Option<bool>,
+    #[serde(rename = "customCSS")]
+    pub custom_css: Option<CustomCss>,
+    #[serde(rename = "useCustomJS")]
+    pub use_custom_js: Option<bool>,
+    #[serde(rename = "customJS")]
+    pub custom_js: Option<CustomJs>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tab_css_overrides: Option<TabCssOverrides>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_counter_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_counters: Option<KeyCounters>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlay_resize_anchor: Option<OverlayResizeAnchor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlay_bounds: Option<OverlayBounds>,
+    /// 플러그인 데이터
+    ///
+    /// - `plugin_data_<ID>/<키>`: 플러그인별 저장소 (`PluginStorage`)
+    /// - 그 외 `plugin_data_<키>`: store.json 공유 키 공간
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_data: Option<HashMap<String, Value>>,
+}
+
+impl PresetFile {
+    /// 현재 스토어 상태와 설치된 플러그인의 저장소 데이터로 프리셋 생성
+    pub fn from_store(snapshot: &AppStoreData, storage: &PluginStorage) -> Self {
+        let mut plugin_data: HashMap<String, Value> = snapshot
+            .plugin_data
+            .iter()
+            .filter(|(key, _)| key.starts_with(STORE_KEY_PREFIX))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        let mut namespaces: Vec<String> = snapshot
+            .custom_js
+            .plugins
+            .iter()
+            .map(|plugin| plugin.storage_namespace())
+            .collect();
+        namespaces.sort();
+        namespaces.dedup();
+        for namespace in namespaces {
+            match storage.entries(&namespace) {
+                Ok(entries) => {
+                    plugin_data.extend(entries.into_iter().map(|(key, value)| {
+                        (format!("{STORE_KEY_PREFIX}{namespace}/{key}"), value)
+                    }))
+                }
+                Err(err) => log::warn!(
+                    "[Preset] Failed to export plugin data of {}: {}",
+                    namespace,
+                    err
+                ),
+            }
+        }
+
+        let snapshot = snapshot.clone();
+        Self {
+            version: Some(PRESET_VERSION),
+            keys: Some(snapshot.keys),
+            key_positions: Some(snapshot.key_positions),
+            background_color: Some(snapshot.background_color),
+            note_settings: Some(snapshot.note_settings),
+            note_effect: Some(snapshot.note_effect),
+            laboratory_enabled: Some(snapshot.laboratory_enabled),
+            custom_tabs: Some(snapshot.custom_tabs),
+            selected_key_type: Some(snapshot.selected_key_type),
+            use_custom_css: Some(snapshot.use_custom_css),
+            custom_css: Some(snapshot.custom_css),
+            use_custom_js: Some(snapshot.use_custom_js),
+            custom_js: Some(snapshot.custom_js),
+            tab_css_overrides: Some(snapshot.tab_css_overrides),
+            key_counter_enabled: Some(snapshot.key_counter_enabled),
+            key_counters: Some(snapshot.key_counters),
+            overlay_resize_anchor: Some(snapshot.overlay_resize_anchor),
+            overlay_bounds: snapshot.overlay_bounds,
+            plugin_data: Some(plugin_data),
+        }
+    }
+
+    /// 설정 패치를 거치지 않는 선택 항목을 스토어 상태에 반영 (없는 항목은 유지)
+    pub fn apply_sections_to(&self, state: &mut AppStoreData) {
+        if let Some(overrides) = &self.tab_css_overrides {
+            state.tab_css_overrides = overrides.clone();
+        }
+        if let Some(counters) = &self.key_counters {
+            state.key_counters = counters.clone();
+        }
+        if let Some(bounds) = &self.overlay_bounds {
+            state.overlay_bounds = Some(bounds.clone());
+            state.overlay_bounds_are_logical = true;
+        }
+        if let Some(data) = &self.plugin_data {
+            state
+                .plugin_data
+                .retain(|key, _| !key.starts_with(STORE_KEY_PREFIX));
+            state.plugin_data.extend(
+                data.iter()
+                    .filter(|(key, _)| {
+                        key.starts_with(STORE_KEY_PREFIX) && split_plugin_key(key).is_none()
+                    })
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            );
+        }
+    }
+
+    /// 플러그인별 데이터를 저장소에 반영
+    ///
+    /// 프리셋에 데이터가 있는 플러그인만 전체를 교체하고, 나머지 플러그인의 데이터는 유지합니다.
+    pub fn apply_plugin_storage(&self, storage: &PluginStorage) {
+        let Some(data) = &self.plugin_data else {
+            return;
+        };
+        let mut grouped: HashMap<&str, Vec<(String, Value)>> = HashMap::new();
+        for (store_key, value) in data {
+            if let Some((plugin_id, key)) = split_plugin_key(store_key) {
+                grouped
+                    .entry(plugin_id)
+                    .or_default()
+                    .push((key.to_string(), value.clone()));
+            }
+        }
+        for (plugin_id, entries) in grouped {
+            if let Err(err) = storage.replace(plugin_id, entries) {
+                log::warn!(
+                    "[Preset] Failed to import plugin data of {}: {}",
+                    plugin_id,
+                    err
+                );
+            }
+        }
+    }
+}
+
+/// `plugin_data_<ID>/<키>`를 (ID, 키)로 분리 (`PluginStorage::migrate_legacy`와 같은 규칙)
+fn split_plugin_key(store_key: &str) -> Option<(&str, &str)> {
+    store_key.strip_prefix(STORE_KEY_PREFIX)?.split_once('/')
+}
+
+/// 프리셋 검증 실패 항목
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetIssue {
+    /// 문제가 된 필드 (문서 전체 문제는 빈 문자열)
+    pub field: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl PresetIssue {
+    pub(crate) fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+/// JSON 문자열 파싱 (문법 오류는 줄/열 정보와 함께 반환)
+pub fn parse_json(content: &str) -> Result<Value, Vec<PresetIssue>> {
+    serde_json::from_str(content).map_err(|err| {
+        vec![PresetIssue {
+            line: Some(err.line()),
+            column: Some(err.column()),
+            ..PresetIssue::new("", err.to_string())
+        }]
+    })
+}
+
+/// JSON 값을 프리셋으로 변환 (필드별로 검증해 문제를 모두 수집)
+pub fn preset_from_value(value: Value) -> Result<PresetFile, Vec<PresetIssue>> {
+    let Value::Object(object) = value else {
+        return Err(vec![PresetIssue::new("", "preset must be a JSON object")]);
+    };
+
+    let mut issues = Vec::new();
+    check_field::<u32>(&object, "version", &mut issues);
+    if let Some(version) = object.get("version").and_then(Value::as_u64) {
+        if version > u64::from(PRESET_VERSION) {
+            issues.push(PresetIssue::new(
+                "version",
+                format!("unsupported preset version {version} (max {PRESET_VERSION})"),
+            ));
+        }
+    }
+    check_field::<KeyMappings>(&object, "keys", &mut issues);
+    check_field::<KeyPositions>(&object, "keyPositions", &mut issues);
+    check_field::<String>(&object, "backgroundColor", &mut issues);
+    check_field::<NoteSettings>(&object, "noteSettings", &mut issues);
+    check_field::<bool>(&object, "noteEffect", &mut issues);
+    check_field::<bool>(&object, "laboratoryEnabled", &mut issues);
+    check_field::<Vec<CustomTab>>(&object, "customTabs", &mut issues);
+    check_field::<String>(&object, "selectedKeyType", &mut issues);
+    check_field::<bool>(&object, "useCustomCSS", &mut issues);
+    check_field::<CustomCss>(&object, "customCSS", &mut issues);
+    check_field::<bool>(&object, "useCustomJS", &mut issues);
+    check_field::<CustomJs>(&object, "customJS", &mut issues);
+    check_field::<TabCssOverrides>(&object, "tabCssOverrides", &mut issues);
+    check_field::<bool>(&object, "keyCounterEnabled", &mut issues);
+    check_field::<KeyCounters>(&object, "keyCounters", &mut issues);
+    check_field::<OverlayResizeAnchor>(&object, "overlayResizeAnchor", &mut issues);
+    check_field::<OverlayBounds>(&object, "overlayBounds", &mut issues);
+    check_field::<HashMap<String, Value>>(&object, "pluginData", &mut issues);
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    serde_json::from_value(Value::Object(object))
+        .map_err(|err| vec![PresetIssue::new("", err.to_string())])
+}
+
+/// 경로에서 프리셋 JSON 읽기 (I/O 실패는 `Err`, 문법 오류는 `Ok(Err(issues))`)
+pub fn read_preset_json(path: &Path) -> anyhow::Result<Result<Value, Vec<PresetIssue>>> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| anyhow::anyhow!("failed to read preset at {}: {err}", path.display()))?;
+    Ok(parse_json(&content))
+}
+
+/// 프리셋을 경로에 기록
+pub fn write_preset<T: Serialize>(path: &Path, preset: &T) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(preset)?;
+    fs::write(path, json)
+        .map_err(|err| anyhow::anyhow!("failed to write preset at {}: {err}", path.display()))
+}
+
+fn check_field<T: DeserializeOwned>(
+    object: &Map<String, Value>,
+    field: &str,
+    issues: &mut Vec<PresetIssue>,
+) {
+    let Some(value) = object.get(field) else {
+        return;
+    };
+    if value.is_null() {
+        return;
+    }
+    if let Err(err) = serde_json::from_value::<T>(value.clone()) {
+        issues.push(PresetIssue::new(field, err.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::JsPlugin;
+    use serde_json::json;
+
+    #[test]
+    fn plugin_data_round_trips_through_plugin_storage() {
+        let dir = std::env::temp_dir().join(format!("dmnote-preset-test-{}", std::process::id()));
+        let source = PluginStorage::new(&dir.join("source")).unwrap();
+        let target = PluginStorage::new(&dir.join("target")).unwrap();
+
+        let mut snapshot = AppStoreData::default();
+        snapshot.custom_js.plugins.push(JsPlugin {
+            id: "js-plugin-1".to_string(),
+            name: "counter.js".to_string(),
+            path: None,
+            content: String::new(),
+            enabled: true,
+            manifest: None,
+            granted_permissions: None,
+            order: None,
+        });
+        let namespace = snapshot.custom_js.plugins[0].storage_namespace();
+        source.set(&namespace, "best", json!(42)).unwrap();
+        snapshot
+            .plugin_data
+            .insert(format!("{STORE_KEY_PREFIX}shared"), json!("value"));
+
+        // 내보내기 → JSON → 가져오기
+        let exported = PresetFile::from_store(&snapshot, &source);
+        let imported = preset_from_value(serde_json::to_value(&exported).unwrap()).unwrap();
+        target.set(&namespace, "stale", json!(true)).unwrap();
+        let mut state = AppStoreData::default();
+        imported.apply_sections_to(&mut state);
+        imported.apply_plugin_storage(&target);
+
+        // 플러그인은 자기 네임스페이스에서 값을 읽음
+        assert_eq!(target.get(&namespace, "best").unwrap(), Some(json!(42)));
+        assert_eq!(target.get(&namespace, "stale").unwrap(), None);
+        assert_eq!(
+            state.plugin_data.get(&format!("{STORE_KEY_PREFIX}shared")),
+            Some(&json!("value"))
+        );
+        assert!(state.plugin_data.keys().all(|key| !key.contains('/')));
+
+        source.shutdown().unwrap();
+        target.shutdown().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
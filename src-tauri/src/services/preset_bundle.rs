@@ -0,0 +1,304 @@
+//! 자체 포함 프리셋 번들
+//!
+//! 프리셋이 참조하는 이미지/CSS/JS 파일을 base64로 포함한 단일 JSON 형식입니다.
+//! - 내보내기: 참조 경로를 `dmnote-asset://<id>/<파일명>` 형태로 치환
+//! - 가져오기: 앱 데이터 디렉토리의 `assets/<uuid>/`에 풀고 경로를 다시 작성
+//!   (프리셋이 실제로 참조하는 에셋만 기록하므로 선택적 가져오기에서 빠진 에셋은 남지 않음)
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::services::preset::{preset_from_value, PresetFile, PresetIssue};
+
+pub const BUNDLE_FORMAT: &str = "dmnote-preset-bundle";
+const BUNDLE_VERSION: u32 = 1;
+const ASSET_SCHEME: &str = "dmnote-asset://";
+const ASSETS_DIR: &str = "assets";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetBundle {
+    pub format: String,
+    pub version: u32,
+    pub preset: PresetFile,
+    #[serde(default)]
+    pub assets: Vec<BundleAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleAsset {
+    pub id: String,
+    pub file_name: String,
+    /// base64 인코딩된 파일 내용
+    pub data: String,
+}
+
+/// 번들 형식의 JSON인지 확인
+pub fn is_bundle(value: &Value) -> bool {
+    value.get("format").and_then(Value::as_str) == Some(BUNDLE_FORMAT)
+}
+
+/// 앱 데이터 디렉토리 아래 관리되는 에셋 폴더
+pub fn assets_root(data_dir: &Path) -> PathBuf {
+    data_dir.join(ASSETS_DIR)
+}
+
+/// 프리셋이 참조하는 로컬 파일을 모두 포함한 번들 생성
+///
+/// 읽을 수 없는 파일은 경로를 그대로 두고 건너뜁니다.
+pub fn pack(preset: &PresetFile) -> PresetBundle {
+    let mut preset = preset.clone();
+    let mut assets = Vec::new();
+    let mut packed: HashMap<String, String> = HashMap::new();
+
+    for_each_reference(&mut preset, &mut |reference| {
+        if !is_local_path(reference) {
+            return;
+        }
+        if let Some(rewritten) = packed.get(reference.as_str()) {
+            *reference = rewritten.clone();
+            return;
+        }
+        let path = Path::new(reference.as_str());
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                log::warn!("[PresetBundle] skipping asset {}: {err}", path.display());
+                return;
+            }
+        };
+        let id = (assets.len() + 1).to_string();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("asset")
+            .to_string();
+        let rewritten = format!("{ASSET_SCHEME}{id}/{file_name}");
+        assets.push(BundleAsset {
+            id,
+            file_name,
+            data: STANDARD.encode(bytes),
+        });
+        packed.insert(reference.clone(), rewritten.clone());
+        *reference = rewritten;
+    });
+
+    PresetBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        preset,
+        assets,
+    }
+}
+
+/// JSON 값을 번들로 변환 (프리셋 본문은 일반 프리셋과 같은 규칙으로 검증)
+pub fn bundle_from_value(mut value: Value) -> Result<PresetBundle, Vec<PresetIssue>> {
+    let mut issues = Vec::new();
+    match value.get("version").and_then(Value::as_u64) {
+        Some(version) if version <= u64::from(BUNDLE_VERSION) => {}
+        Some(version) => issues.push(PresetIssue::new(
+            "version",
+            format!("unsupported bundle version {version} (max {BUNDLE_VERSION})"),
+        )),
+        None => issues.push(PresetIssue::new("version", "missing bundle version")),
+    }
+
+    let preset = match value.get_mut("preset").map(Value::take) {
+        Some(preset) => match preset_from_value(preset) {
+            Ok(preset) => Some(preset),
+            Err(preset_issues) => {
+                issues.extend(preset_issues.into_iter().map(|issue| PresetIssue {
+                    field: join_field("preset", &issue.field),
+                    ..issue
+                }));
+                None
+            }
+        },
+        None => {
+            issues.push(PresetIssue::new("preset", "missing preset"));
+            None
+        }
+    };
+
+    let assets = match value.get_mut("assets").map(Value::take) {
+        Some(assets) => serde_json::from_value::<Vec<BundleAsset>>(assets)
+            .map_err(|err| issues.push(PresetIssue::new("assets", err.to_string())))
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
+    match preset {
+        Some(preset) if issues.is_empty() => Ok(PresetBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            preset,
+            assets,
+        }),
+        _ => Err(issues),
+    }
+}
+
+/// 디코딩된 에셋 (에셋 ID → (파일명, 내용))
+type DecodedAssets = HashMap<String, (String, Vec<u8>)>;
+
+/// 파일을 쓰지 않고 메모리에서 에셋 디코딩/참조 검사 (미리보기용)
+///
+/// 검사를 통과하면 프리셋과 에셋 수를 반환합니다.
+pub fn verify(bundle: PresetBundle) -> Result<(PresetFile, usize), Vec<PresetIssue>> {
+    decode(bundle).map(|(preset, decoded)| (preset, decoded.len()))
+}
+
+/// 번들 에셋을 관리 폴더에 풀고 경로를 다시 작성한 프리셋 반환
+///
+/// 에셋 디코딩/참조 검사를 모두 통과한 뒤에만, 프리셋이 참조하는 에셋만 기록합니다.
+pub fn unpack(
+    bundle: PresetBundle,
+    assets_root: &Path,
+) -> anyhow::Result<Result<PresetFile, Vec<PresetIssue>>> {
+    let (mut preset, mut decoded) = match decode(bundle) {
+        Ok(decoded) => decoded,
+        Err(issues) => return Ok(Err(issues)),
+    };
+    let mut referenced: HashSet<String> = HashSet::new();
+    for_each_reference(&mut preset, &mut |reference| {
+        if let Some(id) = asset_id(reference) {
+            referenced.insert(id.to_string());
+        }
+    });
+    decoded.retain(|id, _| referenced.contains(id));
+    if decoded.is_empty() {
+        return Ok(Ok(preset));
+    }
+
+    let dir = assets_root.join(Uuid::new_v4().to_string());
+    fs::create_dir_all(&dir).map_err(|err| {
+        anyhow::anyhow!("failed to create assets directory {}: {err}", dir.display())
+    })?;
+    let mut written: HashMap<String, String> = HashMap::new();
+    for (index, (id, (file_name, bytes))) in decoded.into_iter().enumerate() {
+        // 같은 파일명이 여러 개일 수 있으므로 순번을 붙여 저장
+        let path = dir.join(format!("{index}-{file_name}"));
+        fs::write(&path, bytes)
+            .map_err(|err| anyhow::anyhow!("failed to write asset {}: {err}", path.display()))?;
+        written.insert(id, path.to_string_lossy().into_owned());
+    }
+
+    for_each_reference(&mut preset, &mut |reference| {
+        if let Some(path) = asset_id(reference).and_then(|id| written.get(id)) {
+            *reference = path.clone();
+        }
+    });
+    Ok(Ok(preset))
+}
+
+/// 모든 에셋을 디코딩하고 프리셋의 에셋 참조가 모두 번들에 있는지 검사
+fn decode(bundle: PresetBundle) -> Result<(PresetFile, DecodedAssets), Vec<PresetIssue>> {
+    let mut issues = Vec::new();
+    let mut decoded: DecodedAssets = HashMap::new();
+    for (index, asset) in bundle.assets.iter().enumerate() {
+        let field = format!("assets[{index}]");
+        let Some(file_name) = sanitize_file_name(&asset.file_name) else {
+            issues.push(PresetIssue::new(&field, "invalid asset file name"));
+            continue;
+        };
+        match STANDARD.decode(asset.data.as_bytes()) {
+            Ok(bytes) => {
+                decoded.insert(asset.id.clone(), (file_name, bytes));
+            }
+            Err(err) => issues.push(PresetIssue::new(&field, format!("invalid base64: {err}"))),
+        }
+    }
+
+    let mut preset = bundle.preset;
+    for_each_reference(&mut preset, &mut |reference| {
+        if let Some(id) = asset_id(reference) {
+            if !decoded.contains_key(id) {
+                issues.push(PresetIssue::new(
+                    "assets",
+                    format!("missing asset referenced by {reference}"),
+                ));
+            }
+        }
+    });
+    if issues.is_empty() {
+        Ok((preset, decoded))
+    } else {
+        Err(issues)
+    }
+}
+
+/// 프리셋 안의 모든 파일 참조(이미지, CSS, JS 경로) 순회
+fn for_each_reference(preset: &mut PresetFile, visit: &mut dyn FnMut(&mut String)) {
+    if let Some(positions) = preset.key_positions.as_mut() {
+        for position in positions.values_mut().flatten() {
+            if let Some(image) = position.active_image.as_mut() {
+                visit(image);
+            }
+            if let Some(image) = position.inactive_image.as_mut() {
+                visit(image);
+            }
+        }
+    }
+    if let Some(path) = preset.custom_css.as_mut().and_then(|css| css.path.as_mut()) {
+        visit(path);
+    }
+    if let Some(overrides) = preset.tab_css_overrides.as_mut() {
+        for path in overrides.values_mut().filter_map(|css| css.path.as_mut()) {
+            visit(path);
+        }
+    }
+    if let Some(js) = preset.custom_js.as_mut() {
+        if let Some(path) = js.path.as_mut() {
+            visit(path);
+        }
+        for path in js
+            .plugins
+            .iter_mut()
+            .filter_map(|plugin| plugin.path.as_mut())
+        {
+            visit(path);
+        }
+    }
+}
+
+/// 데이터 URL이나 원격 주소가 아닌 로컬 파일 경로인지 확인
+fn is_local_path(reference: &str) -> bool {
+    let lower = reference.to_ascii_lowercase();
+    !reference.trim().is_empty()
+        && !lower.starts_with("data:")
+        && !lower.starts_with("http://")
+        && !lower.starts_with("https://")
+        && !lower.starts_with("blob:")
+        && !lower.starts_with(ASSET_SCHEME)
+}
+
+fn asset_id(reference: &str) -> Option<&str> {
+    reference
+        .strip_prefix(ASSET_SCHEME)
+        .and_then(|rest| rest.split('/').next())
+}
+
+fn sanitize_file_name(name: &str) -> Option<String> {
+    let name = Path::new(name).file_name()?.to_str()?;
+    let safe: String = name
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, ':' | '*' | '?' | '"' | '<' | '>' | '|'))
+        .collect();
+    let safe = safe.trim_start_matches('.');
+    (!safe.is_empty()).then(|| safe.to_string())
+}
+
+fn join_field(parent: &str, field: &str) -> String {
+    if field.is_empty() {
+        parent.to_string()
+    } else {
+        format!("{parent}.{field}")
+    }
+}
@@ -0,0 +1,542 @@
+//! 선택적 프리셋 가져오기
+//!
+//! 프리셋 내용을 미리보기로 보여주고, 사용자가 고른 섹션/모드만 현재 상태에 병합합니다.
+//! 병합 결과는 전체 프리셋 형태로 만들어 일반 프리셋 적용 경로를 그대로 사용합니다.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::defaults::default_keys;
+use crate::models::{CustomTab, JsPlugin, KeyMappings, MAX_CUSTOM_TABS};
+use crate::services::preset::{PresetFile, PRESET_VERSION};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PresetSection {
+    /// 키 매핑, 키 위치, 커스텀 탭
+    Layout,
+    /// 배경색, 노트 설정 등 일반 설정
+    Settings,
+    Css,
+    Js,
+    Counters,
+    Overlay,
+    PluginData,
+}
+
+/// ID 충돌 시 처리 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictRule {
+    /// 기존 항목을 덮어쓰기
+    #[default]
+    Replace,
+    /// 새 ID로 함께 보관
+    Rename,
+    /// 가져오지 않음
+    Skip,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetSelection {
+    pub sections: Vec<PresetSection>,
+    /// 가져올 모드 ID (없으면 프리셋의 모든 모드)
+    #[serde(default)]
+    pub modes: Option<Vec<String>>,
+    #[serde(default)]
+    pub tab_conflict: ConflictRule,
+    #[serde(default)]
+    pub plugin_conflict: ConflictRule,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetPreview {
+    pub version: u32,
+    pub sections: Vec<PresetSection>,
+    pub modes: Vec<PresetModePreview>,
+    /// 프리셋에 포함된 설정 항목 이름
+    pub settings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub css: Option<PresetCssPreview>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub js: Option<PresetJsPreview>,
+    pub asset_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetModePreview {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub custom: bool,
+    pub key_count: usize,
+    /// 현재 상태에 같은 ID(또는 같은 이름의 탭)가 있는지
+    pub conflict: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetCssPreview {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    pub has_content: bool,
+    pub tab_overrides: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetJsPreview {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    pub has_content: bool,
+    pub plugins: Vec<PresetPluginPreview>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetPluginPreview {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub conflict: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeAction {
+    Added,
+    Replaced,
+    Renamed,
+    Skipped,
+    LimitReached,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergedItem {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    pub action: MergeAction,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetMergeReport {
+    pub modes: Vec<MergedItem>,
+    pub plugins: Vec<MergedItem>,
+}
+
+/// 프리셋 내용 요약 (현재 상태와의 충돌 여부 포함)
+pub fn preview(incoming: &PresetFile, current: &PresetFile, asset_count: usize) -> PresetPreview {
+    let mut sections = Vec::new();
+    if incoming.keys.is_some() || incoming.key_positions.is_some() {
+        sections.push(PresetSection::Layout);
+    }
+    let settings = present_settings(incoming);
+    if !settings.is_empty() {
+        sections.push(PresetSection::Settings);
+    }
+    if incoming.use_custom_css.is_some()
+        || incoming.custom_css.is_some()
+        || incoming.tab_css_overrides.is_some()
+    {
+        sections.push(PresetSection::Css);
+    }
+    if incoming.use_custom_js.is_some() || incoming.custom_js.is_some() {
+        sections.push(PresetSection::Js);
+    }
+    if incoming.key_counters.is_some() {
+        sections.push(PresetSection::Counters);
+    }
+    if incoming.overlay_bounds.is_some() {
+        sections.push(PresetSection::Overlay);
+    }
+    if incoming
+        .plugin_data
+        .as_ref()
+        .is_some_and(|data| !data.is_empty())
+    {
+        sections.push(PresetSection::PluginData);
+    }
+
+    let current_tabs = current.custom_tabs.clone().unwrap_or_default();
+    let modes = ordered_modes(incoming)
+        .into_iter()
+        .map(|id| {
+            let custom = !is_default_mode(&id);
+            let name = find_tab(incoming, &id).map(|tab| tab.name.clone());
+            let conflict =
+                !custom || find_existing_tab(&current_tabs, &id, name.as_deref()).is_some();
+            PresetModePreview {
+                key_count: incoming
+                    .keys
+                    .as_ref()
+                    .and_then(|keys| keys.get(&id))
+                    .map(Vec::len)
+                    .unwrap_or(0),
+                id,
+                name,
+                custom,
+                conflict,
+            }
+        })
+        .collect();
+
+    let css = sections.contains(&PresetSection::Css).then(|| {
+        let mut tab_overrides: Vec<String> = incoming
+            .tab_css_overrides
+            .as_ref()
+            .map(|overrides| overrides.keys().cloned().collect())
+            .unwrap_or_default();
+        tab_overrides.sort();
+        PresetCssPreview {
+            enabled: incoming.use_custom_css,
+            has_content: incoming
+                .custom_css
+                .as_ref()
+                .is_some_and(|css| !css.content.is_empty()),
+            tab_overrides,
+        }
+    });
+
+    let current_plugins: HashSet<&str> = current
+        .custom_js
+        .iter()
+        .flat_map(|js| js.plugins.iter().map(|plugin| plugin.id.as_str()))
+        .collect();
+    let js = sections
+        .contains(&PresetSection::Js)
+        .then(|| PresetJsPreview {
+            enabled: incoming.use_custom_js,
+            has_content: incoming
+                .custom_js
+                .as_ref()
+                .is_some_and(|js| !js.content.is_empty()),
+            plugins: incoming
+                .custom_js
+                .iter()
+                .flat_map(|js| js.plugins.iter())
+                .map(|plugin| PresetPluginPreview {
+                    id: plugin.id.clone(),
+                    name: plugin.name.clone(),
+                    enabled: plugin.enabled,
+                    conflict: current_plugins.contains(plugin.id.as_str()),
+                })
+                .collect(),
+        });
+
+    PresetPreview {
+        version: incoming.version.unwrap_or(1).min(PRESET_VERSION),
+        sections,
+        modes,
+        settings,
+        css,
+        js,
+        asset_count,
+    }
+}
+
+/// 선택한 섹션/모드를 현재 상태(`base`)에 병합한 전체 프리셋 생성
+pub fn merge(
+    base: PresetFile,
+    incoming: PresetFile,
+    selection: &PresetSelection,
+) -> (PresetFile, PresetMergeReport) {
+    let mut out = base;
+    let mut report = PresetMergeReport::default();
+    let sections: HashSet<PresetSection> = selection.sections.iter().copied().collect();
+
+    let wanted: Option<HashSet<&String>> = selection.modes.as_ref().map(|m| m.iter().collect());
+    let modes: Vec<String> = ordered_modes(&incoming)
+        .into_iter()
+        .filter(|mode| wanted.as_ref().is_none_or(|set| set.contains(mode)))
+        .collect();
+
+    // 프리셋 모드 ID -> 현재 상태에서 대상이 되는 모드 ID
+    let mut mode_map: HashMap<String, String> = HashMap::new();
+    let mut tabs = out.custom_tabs.take().unwrap_or_default();
+    let mut keys = out.keys.take().unwrap_or_default();
+    let mut positions = out.key_positions.take().unwrap_or_default();
+
+    for mode in modes {
+        let incoming_name = find_tab(&incoming, &mode).map(|tab| tab.name.clone());
+        let existing = if is_default_mode(&mode) {
+            Some(mode.clone())
+        } else {
+            find_existing_tab(&tabs, &mode, incoming_name.as_deref()).map(|tab| tab.id.clone())
+        };
+
+        if !sections.contains(&PresetSection::Layout) {
+            if let Some(target) = existing {
+                mode_map.insert(mode, target);
+            }
+            continue;
+        }
+
+        let clash = existing.is_some();
+        let (target, action) = match (existing, selection.tab_conflict) {
+            (Some(target), ConflictRule::Replace) => (Some(target), MergeAction::Replaced),
+            (Some(_), ConflictRule::Skip) => (None, MergeAction::Skipped),
+            (Some(_), ConflictRule::Rename) | (None, _) => {
+                if tabs.len() >= MAX_CUSTOM_TABS {
+                    (None, MergeAction::LimitReached)
+                } else {
+                    let id = if mode_id_taken(&tabs, &keys, &mode) {
+                        new_tab_id(&tabs, &keys)
+                    } else {
+                        mode.clone()
+                    };
+                    let base_name = incoming_name.clone().unwrap_or_else(|| {
+                        if is_default_mode(&mode) {
+                            mode.clone()
+                        } else {
+                            format!("Custom {}", tabs.len() + 1)
+                        }
+                    });
+                    tabs.push(CustomTab {
+                        id: id.clone(),
+                        name: unique_tab_name(&tabs, &base_name),
+                    });
+                    let action = if clash {
+                        MergeAction::Renamed
+                    } else {
+                        MergeAction::Added
+                    };
+                    (Some(id), action)
+                }
+            }
+        };
+
+        if let Some(target) = target.as_ref() {
+            if let Some(mapping) = incoming.keys.as_ref().and_then(|k| k.get(&mode)) {
+                keys.insert(target.clone(), mapping.clone());
+            }
+            let layout = incoming
+                .key_positions
+                .as_ref()
+                .and_then(|p| p.get(&mode))
+                .cloned()
+                .unwrap_or_default();
+            positions.insert(target.clone(), layout);
+            mode_map.insert(mode.clone(), target.clone());
+        }
+        report.modes.push(MergedItem {
+            source: mode,
+            target,
+            action,
+        });
+    }
+    out.custom_tabs = Some(tabs);
+    out.keys = Some(keys);
+    out.key_positions = Some(positions);
+
+    if sections.contains(&PresetSection::Settings) {
+        merge_settings(&mut out, &incoming);
+    }
+
+    if sections.contains(&PresetSection::Css) {
+        if incoming.use_custom_css.is_some() {
+            out.use_custom_css = incoming.use_custom_css;
+        }
+        if incoming.custom_css.is_some() {
+            out.custom_css = incoming.custom_css.clone();
+        }
+        if let Some(overrides) = incoming.tab_css_overrides.as_ref() {
+            let target = out.tab_css_overrides.get_or_insert_with(Default::default);
+            for (mode, css) in overrides {
+                if let Some(mapped) = mode_map.get(mode) {
+                    target.insert(mapped.clone(), css.clone());
+                }
+            }
+        }
+    }
+
+    if sections.contains(&PresetSection::Js) {
+        if incoming.use_custom_js.is_some() {
+            out.use_custom_js = incoming.use_custom_js;
+        }
+        if let Some(incoming_js) = incoming.custom_js.as_ref() {
+            let js = out.custom_js.get_or_insert_with(Default::default);
+            js.path = incoming_js.path.clone();
+            js.content = incoming_js.content.clone();
+            for plugin in &incoming_js.plugins {
+                report.plugins.push(merge_plugin(
+                    &mut js.plugins,
+                    plugin,
+                    selection.plugin_conflict,
+                ));
+            }
+        }
+    }
+
+    if sections.contains(&PresetSection::Counters) {
+        if let Some(counters) = incoming.key_counters.as_ref() {
+            let target = out.key_counters.get_or_insert_with(Default::default);
+            for (mode, values) in counters {
+                if let Some(mapped) = mode_map.get(mode) {
+                    target.insert(mapped.clone(), values.clone());
+                }
+            }
+        }
+    }
+
+    if sections.contains(&PresetSection::Overlay) && incoming.overlay_bounds.is_some() {
+        out.overlay_bounds = incoming.overlay_bounds.clone();
+    }
+
+    if sections.contains(&PresetSection::PluginData) {
+        if let Some(data) = incoming.plugin_data.as_ref() {
+            out.plugin_data
+                .get_or_insert_with(Default::default)
+                .extend(data.iter().map(|(key, value)| (key.clone(), value.clone())));
+        }
+    }
+
+    (out, report)
+}
+
+fn merge_settings(out: &mut PresetFile, incoming: &PresetFile) {
+    if incoming.background_color.is_some() {
+        out.background_color = incoming.background_color.clone();
+    }
+    if incoming.note_settings.is_some() {
+        out.note_settings = incoming.note_settings.clone();
+    }
+    if incoming.note_effect.is_some() {
+        out.note_effect = incoming.note_effect;
+    }
+    if incoming.laboratory_enabled.is_some() {
+        out.laboratory_enabled = incoming.laboratory_enabled;
+    }
+    if incoming.key_counter_enabled.is_some() {
+        out.key_counter_enabled = incoming.key_counter_enabled;
+    }
+    if incoming.overlay_resize_anchor.is_some() {
+        out.overlay_resize_anchor = incoming.overlay_resize_anchor.clone();
+    }
+}
+
+fn present_settings(preset: &PresetFile) -> Vec<String> {
+    [
+        ("backgroundColor", preset.background_color.is_some()),
+        ("noteSettings", preset.note_settings.is_some()),
+        ("noteEffect", preset.note_effect.is_some()),
+        ("laboratoryEnabled", preset.laboratory_enabled.is_some()),
+        ("keyCounterEnabled", preset.key_counter_enabled.is_some()),
+        (
+            "overlayResizeAnchor",
+            preset.overlay_resize_anchor.is_some(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, present)| *present)
+    .map(|(name, _)| name.to_string())
+    .collect()
+}
+
+fn merge_plugin(plugins: &mut Vec<JsPlugin>, plugin: &JsPlugin, rule: ConflictRule) -> MergedItem {
+    let existing = plugins.iter().position(|p| p.id == plugin.id);
+    let (target, action) = match (existing, rule) {
+        (None, _) => {
+            plugins.push(plugin.clone());
+            (Some(plugin.id.clone()), MergeAction::Added)
+        }
+        (Some(index), ConflictRule::Replace) => {
+            plugins[index] = plugin.clone();
+            (Some(plugin.id.clone()), MergeAction::Replaced)
+        }
+        (Some(_), ConflictRule::Rename) => {
+            let id = Uuid::new_v4().to_string();
+            plugins.push(JsPlugin {
+                id: id.clone(),
+                ..plugin.clone()
+            });
+            (Some(id), MergeAction::Renamed)
+        }
+        (Some(_), ConflictRule::Skip) => (None, MergeAction::Skipped),
+    };
+    MergedItem {
+        source: plugin.id.clone(),
+        target,
+        action,
+    }
+}
+
+/// 프리셋의 모드 ID 목록 (기본 모드 먼저, 이후 커스텀 탭 순서)
+fn ordered_modes(preset: &PresetFile) -> Vec<String> {
+    let Some(keys) = preset.keys.as_ref() else {
+        return Vec::new();
+    };
+    let mut defaults: Vec<String> = keys
+        .keys()
+        .filter(|mode| is_default_mode(mode))
+        .cloned()
+        .collect();
+    defaults.sort();
+    let mut modes = defaults;
+    for tab in preset.custom_tabs.iter().flatten() {
+        if keys.contains_key(&tab.id) && !modes.contains(&tab.id) {
+            modes.push(tab.id.clone());
+        }
+    }
+    let mut rest: Vec<String> = keys
+        .keys()
+        .filter(|mode| !modes.contains(mode))
+        .cloned()
+        .collect();
+    rest.sort();
+    modes.extend(rest);
+    modes
+}
+
+fn is_default_mode(mode: &str) -> bool {
+    default_keys().contains_key(mode)
+}
+
+fn find_tab<'a>(preset: &'a PresetFile, id: &str) -> Option<&'a CustomTab> {
+    preset.custom_tabs.iter().flatten().find(|tab| tab.id == id)
+}
+
+/// 같은 ID 또는 같은 이름의 기존 커스텀 탭 검색
+fn find_existing_tab<'a>(
+    tabs: &'a [CustomTab],
+    id: &str,
+    name: Option<&str>,
+) -> Option<&'a CustomTab> {
+    tabs.iter()
+        .find(|tab| tab.id == id)
+        .or_else(|| name.and_then(|name| tabs.iter().find(|tab| tab.name == name)))
+}
+
+fn mode_id_taken(tabs: &[CustomTab], keys: &KeyMappings, id: &str) -> bool {
+    is_default_mode(id) || keys.contains_key(id) || tabs.iter().any(|tab| tab.id == id)
+}
+
+fn new_tab_id(tabs: &[CustomTab], keys: &KeyMappings) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    (0..)
+        .map(|offset| format!("custom-{}", now + offset))
+        .find(|id| !mode_id_taken(tabs, keys, id))
+        .unwrap_or_default()
+}
+
+fn unique_tab_name(tabs: &[CustomTab], base: &str) -> String {
+    let taken = |candidate: &str| tabs.iter().any(|tab| tab.name == candidate);
+    if !taken(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|index| format!("{base} {index}"))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_default()
+}
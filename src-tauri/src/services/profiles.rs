@@ -0,0 +1,304 @@
+//! 이름 있는 설정 프로필 서비스
+//!
+//! 게임/방송 환경별로 키, 배치, CSS, 플러그인, 노트 설정을 묶어 저장하고 전환합니다.
+//! 각 프로필은 앱 데이터 디렉토리의 `profiles/<id>.json` 파일로 저장됩니다.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{AppStoreData, ProfileData, ProfileSummary};
+use crate::store::AppStore;
+
+const PROFILES_DIR: &str = "profiles";
+const DEFAULT_PROFILE_NAME: &str = "Default";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileError {
+    #[error("invalid-name")]
+    InvalidName,
+    #[error("duplicate-name")]
+    DuplicateName,
+    #[error("not-found")]
+    NotFound,
+    #[error("{0}")]
+    Io(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProfileFile {
+    id: String,
+    name: String,
+    created_at: u64,
+    updated_at: u64,
+    data: ProfileData,
+}
+
+impl ProfileFile {
+    fn summary(&self, active_id: Option<&str>) -> ProfileSummary {
+        ProfileSummary {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            active: active_id == Some(self.id.as_str()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ProfileService {
+    store: Arc<AppStore>,
+    dir: PathBuf,
+}
+
+impl ProfileService {
+    pub fn new(store: Arc<AppStore>) -> Self {
+        let dir = store.data_dir().join(PROFILES_DIR);
+        Self { store, dir }
+    }
+
+    pub fn active_id(&self) -> Option<String> {
+        self.store.snapshot().active_profile_id
+    }
+
+    /// 저장된 프로필 목록 (이름순)
+    pub fn list(&self) -> Result<Vec<ProfileSummary>, ProfileError> {
+        let active = self.active_id();
+        let mut profiles: Vec<ProfileSummary> = self
+            .read_all()?
+            .iter()
+            .map(|file| file.summary(active.as_deref()))
+            .collect();
+        profiles.sort_by_key(|profile| profile.name.to_lowercase());
+        Ok(profiles)
+    }
+
+    /// 현재 상태를 새 프로필로 저장하고 활성 프로필로 지정
+    pub fn create(&self, name: &str) -> Result<ProfileSummary, ProfileError> {
+        let name = self.validate_new_name(name, None)?;
+        // 기존 활성 프로필에는 지금까지의 변경을 남겨둔다
+        self.save_active()?;
+        let file = self.write_new(name, ProfileData::from_store(&self.store.snapshot()))?;
+        let id = file.id.clone();
+        self.store.update(|state| {
+            state.active_profile_id = Some(id.clone());
+        })?;
+        Ok(file.summary(Some(&file.id)))
+    }
+
+    /// 기존 프로필을 복제 (활성 상태는 바뀌지 않음)
+    pub fn clone_profile(&self, id: &str, name: &str) -> Result<ProfileSummary, ProfileError> {
+        let name = self.validate_new_name(name, None)?;
+        if self.active_id().as_deref() == Some(id) {
+            self.save_active()?;
+        }
+        let source = self.read(id)?;
+        let file = self.write_new(name, source.data)?;
+        Ok(file.summary(self.active_id().as_deref()))
+    }
+
+    pub fn rename(&self, id: &str, name: &str) -> Result<ProfileSummary, ProfileError> {
+        let name = self.validate_new_name(name, Some(id))?;
+        let mut file = self.read(id)?;
+        file.name = name;
+        file.updated_at = now_millis();
+        self.write(&file)?;
+        Ok(file.summary(self.active_id().as_deref()))
+    }
+
+    /// 프로필 삭제 (활성 프로필이면 활성 상태만 해제하고 현재 설정은 유지)
+    pub fn delete(&self, id: &str) -> Result<(), ProfileError> {
+        let path = self.profile_path(id);
+        if !path.exists() {
+            return Err(ProfileError::NotFound);
+        }
+        fs::remove_file(&path).map_err(|err| {
+            anyhow::anyhow!("failed to delete profile at {}: {err}", path.display())
+        })?;
+        if self.active_id().as_deref() == Some(id) {
+            self.store.update(|state| {
+                state.active_profile_id = None;
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 대상 프로필을 스토어에 적용하고 이전 스토어 상태를 반환
+    ///
+    /// 활성 프로필이 없으면 현재 설정을 잃지 않도록 기본 프로필로 먼저 저장합니다.
+    pub fn switch(&self, id: &str) -> Result<(ProfileSummary, AppStoreData), ProfileError> {
+        let target = self.read(id)?;
+        if self.active_id().is_none() {
+            let name = self.unique_name(DEFAULT_PROFILE_NAME)?;
+            self.write_new(name, ProfileData::from_store(&self.store.snapshot()))?;
+        } else {
+            self.save_active()?;
+        }
+
+        let previous = self.store.snapshot();
+        self.store.update(|state| {
+            target.data.apply_to(state);
+            state.active_profile_id = Some(target.id.clone());
+        })?;
+        Ok((target.summary(Some(&target.id)), previous))
+    }
+
+    /// 이름(대소문자 무시) 또는 ID로 프로필 ID 찾기
+    pub fn find_by_name(&self, name: &str) -> Result<Option<String>, ProfileError> {
+        let name = name.trim();
+        let files = self.read_all()?;
+        Ok(files
+            .iter()
+            .find(|file| file.name.eq_ignore_ascii_case(name))
+            .or_else(|| files.iter().find(|file| file.id == name))
+            .map(|file| file.id.clone()))
+    }
+
+    /// 현재 스토어 상태를 활성 프로필 파일에 기록
+    pub fn save_active(&self) -> Result<(), ProfileError> {
+        let Some(id) = self.active_id() else {
+            return Ok(());
+        };
+        let mut file = match self.read(&id) {
+            Ok(file) => file,
+            Err(ProfileError::NotFound) => {
+                log::warn!("[Profiles] active profile {id} missing on disk, clearing");
+                self.store.update(|state| {
+                    state.active_profile_id = None;
+                })?;
+                return Ok(());
+            }
+            Err(err) => return Err(err),
+        };
+        file.data = ProfileData::from_store(&self.store.snapshot());
+        file.updated_at = now_millis();
+        self.write(&file)
+    }
+
+    fn validate_new_name(
+        &self,
+        name: &str,
+        except_id: Option<&str>,
+    ) -> Result<String, ProfileError> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            return Err(ProfileError::InvalidName);
+        }
+        let duplicate = self.read_all()?.iter().any(|file| {
+            Some(file.id.as_str()) != except_id && file.name.eq_ignore_ascii_case(trimmed)
+        });
+        if duplicate {
+            return Err(ProfileError::DuplicateName);
+        }
+        Ok(trimmed.to_string())
+    }
+
+    fn unique_name(&self, base: &str) -> Result<String, ProfileError> {
+        let existing = self.read_all()?;
+        let taken = |candidate: &str| {
+            existing
+                .iter()
+                .any(|file| file.name.eq_ignore_ascii_case(candidate))
+        };
+        if !taken(base) {
+            return Ok(base.to_string());
+        }
+        let mut index = 2;
+        loop {
+            let candidate = format!("{base} {index}");
+            if !taken(&candidate) {
+                return Ok(candidate);
+            }
+            index += 1;
+        }
+    }
+
+    fn write_new(&self, name: String, data: ProfileData) -> Result<ProfileFile, ProfileError> {
+        let now = now_millis();
+        let file = ProfileFile {
+            id: Uuid::new_v4().to_string(),
+            name,
+            created_at: now,
+            updated_at: now,
+            data,
+        };
+        self.write(&file)?;
+        Ok(file)
+    }
+
+    fn profile_path(&self, id: &str) -> PathBuf {
+        // ID는 파일명으로 쓰이므로 경로 구분자가 섞이지 않도록 정리
+        let safe: String = id
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        self.dir.join(format!("{safe}.json"))
+    }
+
+    fn read(&self, id: &str) -> Result<ProfileFile, ProfileError> {
+        let path = self.profile_path(id);
+        if !path.exists() {
+            return Err(ProfileError::NotFound);
+        }
+        Ok(read_profile_file(&path)?)
+    }
+
+    fn read_all(&self) -> Result<Vec<ProfileFile>, ProfileError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let entries = fs::read_dir(&self.dir).map_err(|err| {
+            anyhow::anyhow!(
+                "failed to read profiles directory {}: {err}",
+                self.dir.display()
+            )
+        })?;
+        let mut files = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match read_profile_file(&path) {
+                Ok(file) => files.push(file),
+                Err(err) => log::warn!("[Profiles] skipping unreadable profile: {err}"),
+            }
+        }
+        Ok(files)
+    }
+
+    fn write(&self, file: &ProfileFile) -> Result<(), ProfileError> {
+        fs::create_dir_all(&self.dir).map_err(|err| {
+            anyhow::anyhow!(
+                "failed to create profiles directory {}: {err}",
+                self.dir.display()
+            )
+        })?;
+        let path = self.profile_path(&file.id);
+        let json = serde_json::to_string_pretty(file).map_err(anyhow::Error::from)?;
+        fs::write(&path, json).map_err(|err| {
+            anyhow::anyhow!("failed to write profile at {}: {err}", path.display())
+        })?;
+        Ok(())
+    }
+}
+
+fn read_profile_file(path: &Path) -> anyhow::Result<ProfileFile> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| anyhow::anyhow!("failed to read profile at {}: {err}", path.display()))?;
+    serde_json::from_str(&content)
+        .map_err(|err| anyhow::anyhow!("invalid profile at {}: {err}", path.display()))
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
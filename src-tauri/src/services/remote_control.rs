@@ -0,0 +1,111 @@
+//! 외부 제어 명령 (Stream Deck 스크립트, `dm-note ctl` 등)
+//!
+//! 로컬 서버의 `POST /control`로 받은 JSON 명령을 실행합니다.
+//! 명령 형식은 `{ "command": "<이름>", ...인자 }`이며 `docs/remote-control.md`에 정리되어 있습니다.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+
+use crate::app_state::AppState;
+use crate::commands::{keys, overlay, preset, render_script};
+
+/// 제어 명령
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "command", rename_all = "camelCase")]
+pub enum ControlCommand {
+    /// 키 모드(탭) 전환
+    SetMode { mode: String },
+    /// 오버레이 표시/숨김 (`visible`이 없으면 토글)
+    Overlay { visible: Option<bool> },
+    /// 오버레이 잠금 (`locked`가 없으면 토글)
+    Lock { locked: Option<bool> },
+    /// 키 카운터 초기화 (`mode`가 없으면 전체)
+    ResetCounters { mode: Option<String> },
+    /// 프리셋 파일 불러오기
+    LoadPreset { path: String },
+    /// 렌더 스크립트용 입력 녹화 시작
+    RecordStart,
+    /// 녹화를 마치고 렌더 스크립트를 응답으로 반환 (원격 요청으로는 파일을 쓰지 않음)
+    RecordStop,
+    /// 현재 상태 조회
+    Status,
+}
+
+/// 제어 명령 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ControlResponse {
+    pub fn from_result(result: Result<Value, String>) -> Self {
+        match result {
+            Ok(value) => Self {
+                ok: true,
+                result: Some(value),
+                error: None,
+            },
+            Err(error) => Self::error(error),
+        }
+    }
+
+    pub fn error(error: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            result: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// 제어 명령 실행
+pub fn execute(app: &AppHandle, command: ControlCommand) -> Result<Value, String> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "app-not-ready".to_string())?;
+    log::info!("[RemoteControl] {:?}", command);
+
+    match command {
+        ControlCommand::SetMode { mode } => {
+            to_value(keys::keys_set_mode(state, app.clone(), mode)?)
+        }
+        ControlCommand::Overlay { visible } => {
+            let visible = visible.unwrap_or(!state.overlay_status().visible);
+            overlay::overlay_set_visible(state.clone(), app.clone(), visible)?;
+            to_value(state.overlay_status())
+        }
+        ControlCommand::Lock { locked } => {
+            let locked = locked.unwrap_or(!state.overlay_status().locked);
+            overlay::overlay_set_lock(state.clone(), app.clone(), locked)?;
+            to_value(state.overlay_status())
+        }
+        ControlCommand::ResetCounters { mode: Some(mode) } => {
+            to_value(keys::keys_reset_counters_mode(state, app.clone(), mode)?)
+        }
+        ControlCommand::ResetCounters { mode: None } => {
+            to_value(keys::keys_reset_counters(state, app.clone())?)
+        }
+        ControlCommand::LoadPreset { path } => {
+            let result = preset::preset_import(state, app.clone(), Some(path), None)?;
+            if !result.success {
+                return Err(result.error.unwrap_or_else(|| "preset-load-failed".into()));
+            }
+            to_value(result)
+        }
+        ControlCommand::RecordStart => to_value(render_script::render_record_start(state)?),
+        ControlCommand::RecordStop => to_value(render_script::render_record_stop(state, None)?),
+        ControlCommand::Status => to_value(serde_json::json!({
+            "mode": state.keyboard.current_mode(),
+            "overlay": state.overlay_status(),
+        })),
+    }
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|err| err.to_string())
+}
@@ -0,0 +1,518 @@
+//! 외부 프로그램용 로컬 WebSocket/HTTP 서버
+//!
+//! 오버레이 창을 캡처하지 않고도 OBS 브라우저 소스 등에서 키 상태를 받을 수 있도록
+//! `127.0.0.1`에서만 접속 가능한 서버를 엽니다.
+//! - `GET /snapshot`: 현재 `BootstrapPayload` (읽기 전용)
+//! - `GET /ws` (WebSocket): `keys:state`, `keys:counter`, `input:raw`, `settings:changed`
+//!   이벤트를 `{ "event": ..., "payload": ... }` JSON 텍스트 메시지로 전송
+//! - `POST /control`: 외부 제어 명령 실행 (`remote_control` 참고)
+//! - 모든 요청에 토큰 필요 (`?token=` 쿼리 또는 `Authorization: Bearer` 헤더)
+//! - `/control`은 읽기용 토큰이 아닌 별도 제어 토큰만 받으며, 외부 제어를 켠 경우에만 열림
+//!   (읽기용 토큰은 브라우저 소스 URL에 포함되어 노출되기 쉬움)
+//! - 토큰은 고정 시간 비교로 확인하고, 동시 연결 수는 `MAX_CONNECTIONS`로 제한
+
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender, TryRecvError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::json;
+use tauri::{AppHandle, Manager};
+use tungstenite::{handshake::derive_accept_key, protocol::Role, Message, WebSocket};
+
+use crate::app_state::AppState;
+use crate::models::RemoteServerSettings;
+use crate::services::remote_control::{self, ControlCommand, ControlResponse};
+
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+const MAX_REQUEST_BODY: usize = 64 * 1024;
+/// 보낼 이벤트가 없을 때 클라이언트 메시지(닫기/핑)를 확인하는 주기
+const CLIENT_READ_INTERVAL: Duration = Duration::from_millis(250);
+const CLIENT_READ_TIMEOUT: Duration = Duration::from_millis(1);
+/// 클라이언트별 대기 메시지 상한 (가득 차면 느린 클라이언트로 보고 연결 종료)
+const CLIENT_QUEUE_CAPACITY: usize = 1024;
+/// 클라이언트가 데이터를 받지 않을 때 쓰기를 포기하는 시간
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+/// 동시에 처리하는 연결 상한 (넘으면 503으로 바로 닫음)
+const MAX_CONNECTIONS: usize = 32;
+
+/// 서버 상태 (설정 화면 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteServerStatus {
+    #[serde(flatten)]
+    pub settings: RemoteServerSettings,
+    pub running: bool,
+    pub clients: usize,
+}
+
+/// 연결된 WebSocket 클라이언트로 이벤트를 보내는 서버
+#[derive(Default)]
+pub struct RemoteServer {
+    clients: Arc<Mutex<Vec<SyncSender<String>>>>,
+    task: Mutex<Option<ServerTask>>,
+}
+
+struct ServerTask {
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for ServerTask {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl RemoteServer {
+    /// 설정에 맞춰 서버 (재)시작 (비활성화 상태면 중지만 함)
+    pub fn restart(&self, app: &AppHandle, settings: &RemoteServerSettings) -> Result<()> {
+        self.stop();
+        if !settings.enabled {
+            return Ok(());
+        }
+        if settings.token.is_empty() {
+            return Err(anyhow!("remote server token is empty"));
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, settings.port))
+            .with_context(|| format!("failed to bind 127.0.0.1:{}", settings.port))?;
+        listener
+            .set_nonblocking(true)
+            .context("failed to configure remote server listener")?;
+
+        let running = Arc::new(AtomicBool::new(true));
+        let running_loop = running.clone();
+        let clients = self.clients.clone();
+        let access = Arc::new(ServerAccess {
+            token: settings.token.clone(),
+            control_token: (settings.control_enabled && !settings.control_token.is_empty())
+                .then(|| settings.control_token.clone()),
+        });
+        let app = app.clone();
+        let handle = thread::Builder::new()
+            .name("remote-server".into())
+            .spawn(move || accept_loop(listener, running_loop, clients, access, app))
+            .context("failed to spawn remote server thread")?;
+
+        *self.task.lock() = Some(ServerTask {
+            running,
+            handle: Some(handle),
+        });
+        log::info!("[RemoteServer] Listening on 127.0.0.1:{}", settings.port);
+        Ok(())
+    }
+
+    /// 서버 중지 및 모든 클라이언트 연결 종료
+    pub fn stop(&self) {
+        if self.task.lock().take().is_some() {
+            log::info!("[RemoteServer] Stopped");
+        }
+        // 송신자를 버리면 클라이언트 스레드가 연결을 닫고 종료
+        self.clients.lock().clear();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.task.lock().is_some()
+    }
+
+    pub fn client_count(&self) -> usize {
+        self.clients.lock().len()
+    }
+
+    pub fn has_clients(&self) -> bool {
+        !self.clients.lock().is_empty()
+    }
+
+    pub fn status(&self, settings: RemoteServerSettings) -> RemoteServerStatus {
+        RemoteServerStatus {
+            settings,
+            running: self.is_running(),
+            clients: self.client_count(),
+        }
+    }
+
+    /// 연결된 모든 클라이언트에 이벤트 전송
+    ///
+    /// 끊겼거나 대기열이 가득 찬 클라이언트는 목록에서 제거합니다 (송신자가 사라지면 연결 종료).
+    pub fn broadcast<T: Serialize>(&self, event: &str, payload: &T) {
+        let mut clients = self.clients.lock();
+        if clients.is_empty() {
+            return;
+        }
+        let message = match serde_json::to_string(&json!({ "event": event, "payload": payload })) {
+            Ok(message) => message,
+            Err(err) => {
+                log::warn!("[RemoteServer] Failed to serialize {}: {}", event, err);
+                return;
+            }
+        };
+        clients.retain(|client| client.try_send(message.clone()).is_ok());
+    }
+}
+
+/// 요청 인증에 쓰는 토큰
+struct ServerAccess {
+    /// 읽기 전용 엔드포인트(`/snapshot`, `/ws`)용 토큰
+    token: String,
+    /// `/control` 전용 토큰 (외부 제어가 꺼져 있으면 `None`)
+    control_token: Option<String>,
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    running: Arc<AtomicBool>,
+    clients: Arc<Mutex<Vec<SyncSender<String>>>>,
+    access: Arc<ServerAccess>,
+    app: AppHandle,
+) {
+    let connections = Arc::new(AtomicUsize::new(0));
+    while running.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((mut stream, _)) => {
+                let Some(slot) = ConnectionSlot::acquire(&connections) else {
+                    log::warn!("[RemoteServer] Too many connections, rejecting client");
+                    let _ = stream.set_nonblocking(false);
+                    let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+                    let _ = write_response(&mut stream, "503 Service Unavailable", None);
+                    continue;
+                };
+                let clients = clients.clone();
+                let access = access.clone();
+                let app = app.clone();
+                let spawned = thread::Builder::new()
+                    .name("remote-server-client".into())
+                    .spawn(move || {
+                        let _slot = slot;
+                        if let Err(err) = handle_connection(stream, &clients, &access, &app) {
+                            log::debug!("[RemoteServer] Connection closed: {err:#}");
+                        }
+                    });
+                if let Err(err) = spawned {
+                    log::warn!("[RemoteServer] Failed to spawn client thread: {err}");
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+            Err(err) => {
+                log::warn!("[RemoteServer] Accept failed: {err}");
+                thread::sleep(ACCEPT_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// 처리 중인 연결 수에서 차지한 자리 (drop 시 반환)
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn acquire(connections: &Arc<AtomicUsize>) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                (count < MAX_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(connections.clone()))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 요청 줄과 헤더
+struct RequestHead {
+    method: String,
+    path: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl RequestHead {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn query_param(&self, name: &str) -> Option<&str> {
+        self.query.as_deref()?.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (key == name).then_some(value)
+        })
+    }
+
+    fn authorized(&self, token: &str) -> bool {
+        let bearer = self
+            .header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        let matches = |candidate: Option<&str>| {
+            candidate.is_some_and(|candidate| constant_time_eq(candidate, token))
+        };
+        // 둘 다 비교해 어느 쪽으로 보냈는지에 따라 시간이 달라지지 않게 함
+        matches(bearer) | matches(self.query_param("token"))
+    }
+
+    fn is_websocket_upgrade(&self) -> bool {
+        self.header("upgrade")
+            .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    clients: &Mutex<Vec<SyncSender<String>>>,
+    access: &ServerAccess,
+    app: &AppHandle,
+) -> Result<()> {
+    // 리스너의 논블로킹 설정이 상속되는 플랫폼이 있어 명시적으로 해제
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+    let (head, rest) = read_request_head(&mut stream)?;
+
+    if head.method == "OPTIONS" {
+        return write_response(&mut stream, "204 No Content", None);
+    }
+    if head.method != "GET" && head.method != "POST" {
+        return write_response(&mut stream, "405 Method Not Allowed", None);
+    }
+    if head.path == "/control" {
+        let Some(control_token) = access.control_token.as_deref() else {
+            return write_response(&mut stream, "403 Forbidden", None);
+        };
+        if !head.authorized(control_token) {
+            return write_response(&mut stream, "401 Unauthorized", None);
+        }
+    } else if !head.authorized(&access.token) {
+        return write_response(&mut stream, "401 Unauthorized", None);
+    }
+
+    match (head.method.as_str(), head.path.as_str()) {
+        ("POST", "/control") => {
+            let body = read_request_body(&mut stream, &head, rest)?;
+            let (status, response) = match serde_json::from_slice::<ControlCommand>(&body) {
+                Ok(command) => {
+                    let response =
+                        ControlResponse::from_result(remote_control::execute(app, command));
+                    let status = if response.ok {
+                        "200 OK"
+                    } else {
+                        "500 Internal Server Error"
+                    };
+                    (status, response)
+                }
+                Err(err) => (
+                    "400 Bad Request",
+                    ControlResponse::error(format!("invalid-command: {err}")),
+                ),
+            };
+            let body = serde_json::to_string(&response)?;
+            write_response(&mut stream, status, Some(&body))
+        }
+        (_, "/control") => write_response(&mut stream, "405 Method Not Allowed", None),
+        ("GET", "/ws") if head.is_websocket_upgrade() => {
+            serve_websocket(stream, &head, rest, clients, app)
+        }
+        ("GET", "/snapshot") => {
+            let Some(state) = app.try_state::<AppState>() else {
+                return write_response(&mut stream, "503 Service Unavailable", None);
+            };
+            let body = serde_json::to_string(&state.bootstrap_payload())?;
+            write_response(&mut stream, "200 OK", Some(&body))
+        }
+        _ => write_response(&mut stream, "404 Not Found", None),
+    }
+}
+
+/// 요청 헤더까지 읽고, 헤더 뒤에 이미 받은 바이트를 함께 반환
+fn read_request_head(stream: &mut TcpStream) -> Result<(RequestHead, Vec<u8>)> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let head_end = loop {
+        if let Some(pos) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break pos;
+        }
+        if buffer.len() > MAX_REQUEST_HEAD {
+            return Err(anyhow!("request head too large"));
+        }
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Err(anyhow!("connection closed before request head"));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let text = std::str::from_utf8(&buffer[..head_end]).context("request head is not UTF-8")?;
+    let mut lines = text.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default();
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let rest = buffer[head_end + 4..].to_vec();
+    Ok((
+        RequestHead {
+            method,
+            path,
+            query,
+            headers,
+        },
+        rest,
+    ))
+}
+
+/// `Content-Length`만큼 요청 본문 읽기
+fn read_request_body(
+    stream: &mut TcpStream,
+    head: &RequestHead,
+    mut body: Vec<u8>,
+) -> Result<Vec<u8>> {
+    let length: usize = head
+        .header("content-length")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0);
+    if length > MAX_REQUEST_BODY {
+        return Err(anyhow!("request body too large"));
+    }
+    let mut chunk = [0u8; 1024];
+    while body.len() < length {
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Err(anyhow!("connection closed before request body"));
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(length);
+    Ok(body)
+}
+
+/// 내용과 상관없이 같은 시간이 걸리는 문자열 비교 (토큰 추측 방지)
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut diff = a.len() ^ b.len();
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= usize::from(x ^ y);
+    }
+    diff == 0
+}
+
+fn write_response(stream: &mut TcpStream, status: &str, body: Option<&str>) -> Result<()> {
+    let body = body.unwrap_or_default();
+    let response = format!(
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: application/json; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Headers: Authorization, Content-Type\r\n\
+         Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+         Cache-Control: no-store\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn serve_websocket(
+    mut stream: TcpStream,
+    head: &RequestHead,
+    rest: Vec<u8>,
+    clients: &Mutex<Vec<SyncSender<String>>>,
+    app: &AppHandle,
+) -> Result<()> {
+    let Some(key) = head.header("sec-websocket-key") else {
+        return write_response(&mut stream, "400 Bad Request", None);
+    };
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        derive_accept_key(key.as_bytes())
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.set_read_timeout(Some(CLIENT_READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT))?;
+    stream.set_nodelay(true)?;
+    let mut socket = WebSocket::from_partially_read(stream, rest, Role::Server, None);
+
+    // 연결 직후 현재 상태를 먼저 전송
+    if let Some(state) = app.try_state::<AppState>() {
+        let snapshot = json!({ "event": "snapshot", "payload": state.bootstrap_payload() });
+        socket.send(Message::text(serde_json::to_string(&snapshot)?))?;
+    }
+
+    let (sender, receiver) = mpsc::sync_channel::<String>(CLIENT_QUEUE_CAPACITY);
+    clients.lock().push(sender);
+    log::info!("[RemoteServer] WebSocket client connected");
+
+    let mut last_read = Instant::now();
+    loop {
+        match receiver.recv_timeout(CLIENT_READ_INTERVAL) {
+            Ok(message) => {
+                socket.write(Message::text(message))?;
+                loop {
+                    match receiver.try_recv() {
+                        Ok(message) => socket.write(Message::text(message))?,
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => return close_socket(socket),
+                    }
+                }
+                socket.flush()?;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return close_socket(socket),
+        }
+
+        // 클라이언트가 보낸 닫기/핑 처리 (받은 메시지는 무시)
+        if last_read.elapsed() >= CLIENT_READ_INTERVAL {
+            last_read = Instant::now();
+            match socket.read() {
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(err))
+                    if matches!(
+                        err.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) => {}
+                Err(tungstenite::Error::ConnectionClosed) => {
+                    log::info!("[RemoteServer] WebSocket client disconnected");
+                    return Ok(());
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+fn close_socket(mut socket: WebSocket<TcpStream>) -> Result<()> {
+    let _ = socket.close(None);
+    let _ = socket.flush();
+    Ok(())
+}
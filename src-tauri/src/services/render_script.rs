@@ -0,0 +1,418 @@
+//! 입력 녹화와 렌더 스크립트
+//!
+//! 녹화 중 키 눌림/뗌을 시작 시점 기준 ms로 기록하고, 녹화를 마치면 키 배치와
+//! 노트 설정/색상을 함께 묶은 "렌더 스크립트"로 내보냅니다. 외부 렌더러는 화면 녹화 없이
+//! 이 파일만으로 오버레이를 다시 그릴 수 있습니다.
+//! - 같은 녹화와 설정이면 항상 같은 파일이 나오도록 배치는 모드 이름순으로 정렬
+//! - 배치에 없는 키의 입력은 그릴 수 없으므로 제외
+//! - 녹화 종료 시 눌려 있던 키는 종료 시각에 뗀 것으로 기록
+//! - 배치와 설정은 녹화를 시작할 때의 값을 사용 (녹화 중 편집은 반영하지 않음)
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::{AppStoreData, KeyMappings, KeyPosition, KeyPositions, NoteSettings};
+use crate::services::preset::PresetIssue;
+
+/// 현재 렌더 스크립트 형식 버전
+pub const RENDER_SCRIPT_VERSION: u32 = 1;
+
+/// 녹화 한 번에 보관할 최대 이벤트 수 (초과분은 버림)
+const MAX_RECORDED_EVENTS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderKeyState {
+    Down,
+    Up,
+}
+
+/// 키 입력 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderEvent {
+    /// 녹화 시작 기준 시각 (ms)
+    pub t: u64,
+    pub mode: String,
+    pub key: String,
+    pub state: RenderKeyState,
+}
+
+/// 모드 하나의 키 배치 (`keys[i]`가 `positions[i]`에 그려짐)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderLayout {
+    pub keys: Vec<String>,
+    /// 노트 색상/글로우 등 키별 스타일 포함
+    pub positions: Vec<KeyPosition>,
+}
+
+/// 렌더 스크립트 파일 형식
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderScript {
+    pub version: u32,
+    /// 녹화 시작 시각 (UNIX ms)
+    pub recorded_at: u64,
+    pub duration_ms: u64,
+    pub background_color: String,
+    pub note_effect: bool,
+    pub note_settings: NoteSettings,
+    /// 이벤트에 등장하는 모드의 배치
+    pub layouts: BTreeMap<String, RenderLayout>,
+    pub events: Vec<RenderEvent>,
+}
+
+impl RenderScript {
+    /// 녹화 결과와 녹화 시작 시점의 배치/설정으로 스크립트 생성
+    pub fn from_recording(recording: Recording) -> Self {
+        let snapshot = &recording.base;
+        let mut layouts = BTreeMap::new();
+        for mode in recording.events.iter().map(|event| event.mode.as_str()) {
+            if layouts.contains_key(mode) {
+                continue;
+            }
+            let keys = snapshot.keys.get(mode).cloned().unwrap_or_default();
+            let positions = snapshot
+                .key_positions
+                .get(mode)
+                .cloned()
+                .unwrap_or_default();
+            let len = keys.len().min(positions.len());
+            layouts.insert(
+                mode.to_string(),
+                RenderLayout {
+                    keys: keys[..len].to_vec(),
+                    positions: positions[..len].to_vec(),
+                },
+            );
+        }
+
+        let mut held: HashSet<(String, String)> = HashSet::new();
+        let mut events = Vec::with_capacity(recording.events.len());
+        for event in recording.events {
+            let drawable = layouts
+                .get(&event.mode)
+                .is_some_and(|layout: &RenderLayout| layout.keys.contains(&event.key));
+            if !drawable {
+                continue;
+            }
+            let id = (event.mode.clone(), event.key.clone());
+            match event.state {
+                RenderKeyState::Down => {
+                    held.insert(id);
+                }
+                RenderKeyState::Up => {
+                    // 녹화 시작 전에 눌려 있던 키의 뗌은 짝이 없으므로 제외
+                    if !held.remove(&id) {
+                        continue;
+                    }
+                }
+            }
+            events.push(event);
+        }
+
+        let duration_ms = recording.duration_ms;
+        let mut released: Vec<(String, String)> = held.into_iter().collect();
+        released.sort();
+        events.extend(released.into_iter().map(|(mode, key)| RenderEvent {
+            t: duration_ms,
+            mode,
+            key,
+            state: RenderKeyState::Up,
+        }));
+
+        Self {
+            version: RENDER_SCRIPT_VERSION,
+            recorded_at: recording.recorded_at,
+            duration_ms,
+            background_color: snapshot.background_color.clone(),
+            note_effect: snapshot.note_effect,
+            note_settings: snapshot.note_settings.clone(),
+            layouts,
+            events,
+        }
+    }
+}
+
+/// 녹화 시작 시점의 배치와 설정
+struct RecordingBase {
+    keys: KeyMappings,
+    key_positions: KeyPositions,
+    background_color: String,
+    note_effect: bool,
+    note_settings: NoteSettings,
+}
+
+impl RecordingBase {
+    fn capture(snapshot: &AppStoreData) -> Self {
+        Self {
+            keys: snapshot.keys.clone(),
+            key_positions: snapshot.key_positions.clone(),
+            background_color: snapshot.background_color.clone(),
+            note_effect: snapshot.note_effect,
+            note_settings: snapshot.note_settings.clone(),
+        }
+    }
+}
+
+/// 진행 중이거나 끝난 녹화
+pub struct Recording {
+    base: RecordingBase,
+    started: Instant,
+    recorded_at: u64,
+    duration_ms: u64,
+    events: Vec<RenderEvent>,
+    truncated: bool,
+    /// 눌린 키 → 누를 때의 모드 (뗌도 같은 모드로 기록)
+    held: HashMap<String, String>,
+}
+
+/// 녹화 상태
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingStatus {
+    pub recording: bool,
+    pub elapsed_ms: u64,
+    pub events: usize,
+    /// 최대 이벤트 수를 넘어 일부를 버렸는지 여부
+    pub truncated: bool,
+}
+
+/// 키 입력 녹화기
+#[derive(Default)]
+pub struct InputRecorder {
+    recording: Mutex<Option<Recording>>,
+}
+
+impl InputRecorder {
+    /// 현재 배치/설정으로 새 녹화 시작 (진행 중인 녹화는 버림)
+    pub fn start(&self, snapshot: &AppStoreData) {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+        *self.recording.lock() = Some(Recording {
+            base: RecordingBase::capture(snapshot),
+            started: Instant::now(),
+            recorded_at,
+            duration_ms: 0,
+            events: Vec::new(),
+            truncated: false,
+            held: HashMap::new(),
+        });
+    }
+
+    /// 녹화 종료 (녹화 중이 아니면 `None`)
+    pub fn stop(&self) -> Option<Recording> {
+        let mut recording = self.recording.lock().take()?;
+        recording.duration_ms = recording.started.elapsed().as_millis() as u64;
+        Some(recording)
+    }
+
+    pub fn status(&self) -> RecordingStatus {
+        match self.recording.lock().as_ref() {
+            Some(recording) => RecordingStatus {
+                recording: true,
+                elapsed_ms: recording.started.elapsed().as_millis() as u64,
+                events: recording.events.len(),
+                truncated: recording.truncated,
+            },
+            None => RecordingStatus {
+                recording: false,
+                elapsed_ms: 0,
+                events: 0,
+                truncated: false,
+            },
+        }
+    }
+
+    /// 키 눌림/뗌 기록 (녹화 중이 아니면 무시)
+    pub fn record(&self, mode: &str, key: &str, down: bool) {
+        let mut guard = self.recording.lock();
+        let Some(recording) = guard.as_mut() else {
+            return;
+        };
+        if recording.events.len() >= MAX_RECORDED_EVENTS {
+            recording.truncated = true;
+            return;
+        }
+        let (mode, state) = if down {
+            recording.held.insert(key.to_string(), mode.to_string());
+            (mode.to_string(), RenderKeyState::Down)
+        } else {
+            let mode = recording
+                .held
+                .remove(key)
+                .unwrap_or_else(|| mode.to_string());
+            (mode, RenderKeyState::Up)
+        };
+        recording.events.push(RenderEvent {
+            t: recording.started.elapsed().as_millis() as u64,
+            mode,
+            key: key.to_string(),
+            state,
+        });
+    }
+}
+
+/// JSON 값을 렌더 스크립트로 변환하고 내용까지 검증 (문제를 모두 수집)
+pub fn script_from_value(value: Value) -> Result<RenderScript, Vec<PresetIssue>> {
+    let Value::Object(object) = value else {
+        return Err(vec![PresetIssue::new(
+            "",
+            "render script must be a JSON object",
+        )]);
+    };
+
+    let mut issues = Vec::new();
+    check_field::<u32>(&object, "version", &mut issues);
+    check_field::<u64>(&object, "recordedAt", &mut issues);
+    check_field::<u64>(&object, "durationMs", &mut issues);
+    check_field::<String>(&object, "backgroundColor", &mut issues);
+    check_field::<bool>(&object, "noteEffect", &mut issues);
+    check_field::<NoteSettings>(&object, "noteSettings", &mut issues);
+    check_field::<BTreeMap<String, RenderLayout>>(&object, "layouts", &mut issues);
+    check_field::<Vec<RenderEvent>>(&object, "events", &mut issues);
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    let script: RenderScript = serde_json::from_value(Value::Object(object))
+        .map_err(|err| vec![PresetIssue::new("", err.to_string())])?;
+    let issues = validate(&script);
+    if issues.is_empty() {
+        Ok(script)
+    } else {
+        Err(issues)
+    }
+}
+
+/// 렌더러가 그대로 재생할 수 있는지 내용 검사
+pub fn validate(script: &RenderScript) -> Vec<PresetIssue> {
+    let mut issues = Vec::new();
+    if script.version == 0 || script.version > RENDER_SCRIPT_VERSION {
+        issues.push(PresetIssue::new(
+            "version",
+            format!(
+                "unsupported render script version {} (max {RENDER_SCRIPT_VERSION})",
+                script.version
+            ),
+        ));
+    }
+    if script.note_settings.speed == 0 {
+        issues.push(PresetIssue::new(
+            "noteSettings.speed",
+            "note speed must be greater than 0",
+        ));
+    }
+
+    for (mode, layout) in &script.layouts {
+        let field = format!("layouts.{mode}");
+        if layout.keys.len() != layout.positions.len() {
+            issues.push(PresetIssue::new(
+                &field,
+                format!(
+                    "{} keys but {} positions",
+                    layout.keys.len(),
+                    layout.positions.len()
+                ),
+            ));
+        }
+        for (index, position) in layout.positions.iter().enumerate() {
+            let valid = [position.dx, position.dy, position.width, position.height]
+                .iter()
+                .all(|value| value.is_finite())
+                && position.width > 0.0
+                && position.height > 0.0;
+            if !valid {
+                issues.push(PresetIssue::new(
+                    &format!("{field}.positions[{index}]"),
+                    "position must be finite with a positive size",
+                ));
+            }
+        }
+    }
+
+    let mut last_t = 0;
+    let mut held: HashMap<(&str, &str), usize> = HashMap::new();
+    for (index, event) in script.events.iter().enumerate() {
+        let field = format!("events[{index}]");
+        if event.t < last_t {
+            issues.push(PresetIssue::new(
+                &format!("{field}.t"),
+                format!("events must be sorted by time ({} < {last_t})", event.t),
+            ));
+        }
+        last_t = last_t.max(event.t);
+        if event.t > script.duration_ms {
+            issues.push(PresetIssue::new(
+                &format!("{field}.t"),
+                format!(
+                    "event after the end of the recording ({}ms)",
+                    script.duration_ms
+                ),
+            ));
+        }
+        match script.layouts.get(&event.mode) {
+            None => issues.push(PresetIssue::new(
+                &format!("{field}.mode"),
+                format!("no layout for mode {}", event.mode),
+            )),
+            Some(layout) if !layout.keys.contains(&event.key) => issues.push(PresetIssue::new(
+                &format!("{field}.key"),
+                format!("key {} is not in the {} layout", event.key, event.mode),
+            )),
+            Some(_) => {}
+        }
+
+        let id = (event.mode.as_str(), event.key.as_str());
+        match event.state {
+            RenderKeyState::Down => {
+                if held.insert(id, index).is_some() {
+                    issues.push(PresetIssue::new(
+                        &format!("{field}.state"),
+                        format!("key {} pressed again before release", event.key),
+                    ));
+                }
+            }
+            RenderKeyState::Up => {
+                if held.remove(&id).is_none() {
+                    issues.push(PresetIssue::new(
+                        &format!("{field}.state"),
+                        format!("key {} released without a press", event.key),
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut unreleased: Vec<usize> = held.into_values().collect();
+    unreleased.sort_unstable();
+    for index in unreleased {
+        issues.push(PresetIssue::new(
+            &format!("events[{index}]"),
+            "key is never released",
+        ));
+    }
+    issues
+}
+
+fn check_field<T: DeserializeOwned>(
+    object: &Map<String, Value>,
+    field: &str,
+    issues: &mut Vec<PresetIssue>,
+) {
+    let Some(value) = object.get(field) else {
+        issues.push(PresetIssue::new(field, "missing field"));
+        return;
+    };
+    if let Err(err) = serde_json::from_value::<T>(value.clone()) {
+        issues.push(PresetIssue::new(field, err.to_string()));
+    }
+}
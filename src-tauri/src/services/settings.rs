@@ -53,6 +53,44 @@ impl SettingsService {
     }
 }
 
+/// 두 설정 상태를 비교해 달라진 필드만 담은 diff 생성 (스토어 전체 교체 시 사용)
+pub fn diff_settings(previous: &SettingsState, next: &SettingsState) -> SettingsDiff {
+    fn changed<T: PartialEq + Clone>(prev: &T, next: &T) -> Option<T> {
+        (prev != next).then(|| next.clone())
+    }
+
+    SettingsDiff {
+        changed: SettingsPatch {
+            hardware_acceleration: changed(
+                &previous.hardware_acceleration,
+                &next.hardware_acceleration,
+            ),
+            always_on_top: changed(&previous.always_on_top, &next.always_on_top),
+            overlay_locked: changed(&previous.overlay_locked, &next.overlay_locked),
+            note_effect: changed(&previous.note_effect, &next.note_effect),
+            note_settings: changed(&previous.note_settings, &next.note_settings),
+            angle_mode: changed(&previous.angle_mode, &next.angle_mode),
+            language: changed(&previous.language, &next.language),
+            laboratory_enabled: changed(&previous.laboratory_enabled, &next.laboratory_enabled),
+            developer_mode_enabled: changed(
+                &previous.developer_mode_enabled,
+                &next.developer_mode_enabled,
+            ),
+            background_color: changed(&previous.background_color, &next.background_color),
+            use_custom_css: changed(&previous.use_custom_css, &next.use_custom_css),
+            custom_css: changed(&previous.custom_css, &next.custom_css),
+            use_custom_js: changed(&previous.use_custom_js, &next.use_custom_js),
+            custom_js: changed(&previous.custom_js, &next.custom_js),
+            overlay_resize_anchor: changed(
+                &previous.overlay_resize_anchor,
+                &next.overlay_resize_anchor,
+            ),
+            key_counter_enabled: changed(&previous.key_counter_enabled, &next.key_counter_enabled),
+        },
+        full: next.clone(),
+    }
+}
+
 fn normalize_patch(patch: &SettingsPatchInput, current: &SettingsState) -> SettingsPatch {
     let mut normalized = SettingsPatch::default();
     if let Some(value) = patch.hardware_acceleration {
@@ -0,0 +1,85 @@
+//! store.json 외부 수정 감지 서비스
+//!
+//! 사용자가 직접 편집하거나 동기화 도구가 덮어쓴 store.json을 감지해 즉시 반영합니다.
+//! - 앱이 직접 기록한 내용은 무시
+//! - 파싱/정규화 후 현재 상태와 비교해 달라진 부분만 이벤트로 전파
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify::RecommendedWatcher;
+use notify_debouncer_mini::{new_debouncer, DebouncedEventKind, Debouncer};
+use tauri::{AppHandle, Manager};
+
+use crate::app_state::AppState;
+use crate::store::AppStore;
+
+/// 편집기 저장이 여러 이벤트로 나뉘는 경우를 고려한 디바운스 시간
+const STORE_WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub struct StoreWatcher {
+    #[allow(dead_code)]
+    debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl StoreWatcher {
+    pub fn start(store: Arc<AppStore>, app: AppHandle) -> Result<Self, String> {
+        let store_path = store.path().to_path_buf();
+        let watch_dir = store_path
+            .parent()
+            .map(PathBuf::from)
+            .ok_or_else(|| "store file has no parent directory".to_string())?;
+        let file_name = store_path.file_name().map(|name| name.to_os_string());
+
+        let mut debouncer = new_debouncer(
+            STORE_WATCH_DEBOUNCE,
+            move |res: Result<Vec<notify_debouncer_mini::DebouncedEvent>, notify::Error>| {
+                let events = match res {
+                    Ok(events) => events,
+                    Err(err) => {
+                        log::error!("Store watcher error: {:?}", err);
+                        return;
+                    }
+                };
+                let touched = events.iter().any(|event| {
+                    event.kind == DebouncedEventKind::Any
+                        && event.path.file_name() == file_name.as_deref()
+                });
+                if touched {
+                    handle_store_change(&store, &app);
+                }
+            },
+        )
+        .map_err(|e| format!("Failed to create debouncer: {}", e))?;
+
+        // 편집기의 원자적 저장(임시 파일 교체)도 잡을 수 있도록 디렉토리를 워칭
+        debouncer
+            .watcher()
+            .watch(&watch_dir, notify::RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to start watching: {}", e))?;
+
+        log::info!("[StoreWatcher] Started watching: {:?}", store_path);
+        Ok(Self { debouncer })
+    }
+}
+
+/// 외부 수정 내용을 스토어에 반영하고 변경 이벤트 전파
+fn handle_store_change(store: &AppStore, app: &AppHandle) {
+    let previous = match store.reload_from_disk() {
+        Ok(Some(previous)) => previous,
+        Ok(None) => return,
+        Err(err) => {
+            log::warn!("[StoreWatcher] Ignoring external change: {:#}", err);
+            return;
+        }
+    };
+
+    log::info!("[StoreWatcher] Applying external store.json changes");
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    if let Err(err) = state.sync_with_store(app, &previous) {
+        log::error!("[StoreWatcher] Failed to apply external changes: {}", err);
+    }
+}
@@ -2,11 +2,12 @@ use std::{
     collections::HashSet,
     fs,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use anyhow::{Context, Result};
 use dirs_next::config_dir;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use serde::Deserialize;
 use serde_json::Value;
 use tauri::path::PathResolver;
@@ -18,14 +19,26 @@ use crate::{
         AppStoreData, KeyCounters, KeyMappings, KeyPositions, NoteSettings, OverlayBounds,
         SettingsState,
     },
+    services::{
+        history::StoreHistory,
+        persistence::{PersistenceWorker, DEFAULT_PERSIST_WINDOW},
+        plugin_storage::{apply_batch, BatchOutcome, StorageOp},
+    },
 };
 
 const LEGACY_OVERLAY_WIDTH: f64 = 860.0;
 const LEGACY_OVERLAY_HEIGHT: f64 = 320.0;
 
 pub struct AppStore {
+    data_dir: PathBuf,
     path: PathBuf,
-    state: RwLock<AppStoreData>,
+    /// 마지막으로 직접 기록한 store.json 내용
+    last_written: Arc<Mutex<Option<String>>>,
+    state: Arc<RwLock<AppStoreData>>,
+    /// store.json 쓰기를 모아서 처리하는 백그라운드 워커
+    persistence: PersistenceWorker,
+    /// 되돌리기/다시 실행 기록 (상태 잠금 이후에만 잠금)
+    history: Mutex<StoreHistory>,
 }
 
 impl AppStore {
@@ -51,23 +64,93 @@ impl AppStore {
             (default_path, initialize_default_state(), true)
         };
 
-        let store = Self {
-            path: path.clone(),
-            state: RwLock::new(state),
-        };
-
+        let last_written = Arc::new(Mutex::new(None));
         if needs_persist || !path.exists() {
-            let snapshot = store.state.read().clone();
-            store.persist_locked(&snapshot)?;
+            *last_written.lock() = Some(write_store_file(&path, &state)?);
         }
 
-        Ok(store)
+        let history = Mutex::new(StoreHistory::new(&state));
+        let state = Arc::new(RwLock::new(state));
+        let persistence = {
+            let state = state.clone();
+            let path = path.clone();
+            let last_written = last_written.clone();
+            PersistenceWorker::spawn("store", DEFAULT_PERSIST_WINDOW, move || {
+                let snapshot = state.read().clone();
+                let json = write_store_file(&path, &snapshot)?;
+                *last_written.lock() = Some(json);
+                Ok(())
+            })?
+        };
+
+        Ok(Self {
+            data_dir: dir,
+            path,
+            last_written,
+            state,
+            persistence,
+            history,
+        })
+    }
+
+    /// 대기 중인 변경을 즉시 store.json에 기록
+    pub fn flush(&self) -> Result<()> {
+        self.persistence.flush()
+    }
+
+    /// 저장 워커를 종료하고 남은 변경을 기록 (이후 변경은 동기적으로 기록)
+    pub fn shutdown(&self) -> Result<()> {
+        self.persistence.shutdown()
+    }
+
+    /// 앱 데이터 디렉토리 (store.json이 위치한 곳)
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// store.json 경로
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 외부에서 수정된 store.json을 다시 읽어 적용하고 적용 전 상태 반환
+    ///
+    /// 직접 기록한 내용이거나 정규화 후 현재 상태와 같으면 `None`을 반환합니다.
+    /// 파싱에 실패한 파일(편집 도중 저장 등)은 적용하지 않고 오류로 반환합니다.
+    pub fn reload_from_disk(&self) -> Result<Option<AppStoreData>> {
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("failed to read store file at {}", self.path.display()))?;
+        if self.last_written.lock().as_deref() == Some(content.as_str()) {
+            return Ok(None);
+        }
+        let parsed = serde_json::from_str::<AppStoreData>(&content)
+            .with_context(|| format!("invalid store file at {}", self.path.display()))?;
+        let next = normalize_state(parsed);
+
+        let previous = {
+            let mut guard = self.state.write();
+            if *guard == next {
+                return Ok(None);
+            }
+            let previous = guard.clone();
+            *guard = next;
+            self.history.lock().record(&guard);
+            previous
+        };
+        // 외부 편집 내용이 곧 디스크 상태이므로 다시 기록하지 않음
+        *self.last_written.lock() = Some(content);
+        Ok(Some(previous))
     }
 
     pub fn snapshot(&self) -> AppStoreData {
         self.state.read().clone()
     }
 
+    /// 전체를 복제하지 않고 현재 상태 일부만 읽기
+    pub fn read<T>(&self, reader: impl FnOnce(&AppStoreData) -> T) -> T {
+        reader(&self.state.read())
+    }
+
     pub fn settings_snapshot(&self) -> SettingsState {
         settings_from_store(&self.state.read())
     }
@@ -75,44 +158,111 @@ impl AppStore {
     where
         F: FnMut(&mut AppStoreData),
     {
-        let mut guard = self.state.write();
-        updater(&mut guard);
-        *guard = normalize_state(guard.clone());
-        self.persist_locked(&guard)?;
-        Ok(guard.clone())
+        let updated = {
+            let mut guard = self.state.write();
+            updater(&mut guard);
+            *guard = normalize_state(guard.clone());
+            self.history.lock().record(&guard);
+            guard.clone()
+        };
+        self.schedule_persist();
+        Ok(updated)
     }
 
     pub fn update_keys(&self, mappings: KeyMappings) -> Result<KeyMappings> {
-        let mut guard = self.state.write();
-        guard.keys = mappings.clone();
-        *guard = normalize_state(guard.clone());
-        self.persist_locked(&guard)?;
-        Ok(guard.keys.clone())
+        let updated = {
+            let mut guard = self.state.write();
+            guard.keys = mappings;
+            *guard = normalize_state(guard.clone());
+            self.history.lock().record(&guard);
+            guard.keys.clone()
+        };
+        self.schedule_persist();
+        Ok(updated)
     }
 
     pub fn update_positions(&self, positions: KeyPositions) -> Result<KeyPositions> {
-        let mut guard = self.state.write();
-        guard.key_positions = positions.clone();
-        *guard = normalize_state(guard.clone());
-        self.persist_locked(&guard)?;
-        Ok(guard.key_positions.clone())
+        let updated = {
+            let mut guard = self.state.write();
+            guard.key_positions = positions;
+            *guard = normalize_state(guard.clone());
+            self.history.lock().record(&guard);
+            guard.key_positions.clone()
+        };
+        self.schedule_persist();
+        Ok(updated)
     }
 
     pub fn set_key_counters(&self, counters: KeyCounters) -> Result<KeyCounters> {
-        let mut guard = self.state.write();
-        guard.key_counters = counters.clone();
-        *guard = normalize_state(guard.clone());
-        self.persist_locked(&guard)?;
-        Ok(guard.key_counters.clone())
+        let updated = {
+            let mut guard = self.state.write();
+            guard.key_counters = counters;
+            *guard = normalize_state(guard.clone());
+            guard.key_counters.clone()
+        };
+        self.schedule_persist();
+        Ok(updated)
     }
 
     pub fn set_selected_key_type(&self, key: impl Into<String>) -> Result<String> {
         let key = key.into();
-        let mut guard = self.state.write();
-        guard.selected_key_type = key.clone();
-        *guard = normalize_state(guard.clone());
-        self.persist_locked(&guard)?;
-        Ok(guard.selected_key_type.clone())
+        let updated = {
+            let mut guard = self.state.write();
+            guard.selected_key_type = key;
+            *guard = normalize_state(guard.clone());
+            // 모드 전환은 기록하지 않으므로 다음 변경에 섞이지 않도록 비교 기준만 갱신
+            self.history.lock().sync(&guard);
+            guard.selected_key_type.clone()
+        };
+        self.schedule_persist();
+        Ok(updated)
+    }
+
+    /// 직전 기록으로 되돌리고 되돌리기 전 상태 반환 (기록이 없으면 `None`)
+    pub fn undo(&self) -> Result<Option<AppStoreData>> {
+        let previous = {
+            let mut guard = self.state.write();
+            let mut history = self.history.lock();
+            let Some(target) = history.undo(&guard) else {
+                return Ok(None);
+            };
+            let previous = guard.clone();
+            *guard = normalize_state(target);
+            history.sync(&guard);
+            previous
+        };
+        self.schedule_persist();
+        Ok(Some(previous))
+    }
+
+    /// 되돌린 변경을 다시 적용하고 적용 전 상태 반환 (기록이 없으면 `None`)
+    pub fn redo(&self) -> Result<Option<AppStoreData>> {
+        let previous = {
+            let mut guard = self.state.write();
+            let mut history = self.history.lock();
+            let Some(target) = history.redo(&guard) else {
+                return Ok(None);
+            };
+            let previous = guard.clone();
+            *guard = normalize_state(target);
+            history.sync(&guard);
+            previous
+        };
+        self.schedule_persist();
+        Ok(Some(previous))
+    }
+
+    /// (되돌리기 가능 여부, 다시 실행 가능 여부)
+    pub fn history_state(&self) -> (bool, bool) {
+        let history = self.history.lock();
+        (history.can_undo(), history.can_redo())
+    }
+
+    /// 프로필 전환처럼 이전 기록이 의미 없어지는 경우 기록 초기화
+    pub fn clear_history(&self) {
+        // 다른 경로와 같은 순서(상태 → 기록)로 잠금
+        let state = self.state.read();
+        self.history.lock().clear(&state);
     }
 
     // 플러그인 데이터 관련 메서드
@@ -121,25 +271,50 @@ impl AppStore {
         Ok(guard.plugin_data.get(key).cloned())
     }
 
-    pub fn set_plugin_data(&self, key: &str, value: Value) -> Result<()> {
-        let mut guard = self.state.write();
-        guard.plugin_data.insert(key.to_string(), value);
-        self.persist_locked(&guard)?;
-        Ok(())
+    pub fn get_plugin_data_many(&self, keys: &[String]) -> Result<Vec<(String, Option<Value>)>> {
+        let guard = self.state.read();
+        Ok(keys
+            .iter()
+            .map(|key| (key.clone(), guard.plugin_data.get(key).cloned()))
+            .collect())
     }
 
-    pub fn remove_plugin_data(&self, key: &str) -> Result<()> {
-        let mut guard = self.state.write();
-        guard.plugin_data.remove(key);
-        self.persist_locked(&guard)?;
-        Ok(())
+    /// 작업 묶음을 원자적으로 적용 (충돌 시 아무것도 바꾸지 않음)
+    pub fn apply_plugin_data_batch(&self, ops: Vec<StorageOp>) -> Result<BatchOutcome> {
+        let outcome = apply_batch(&mut self.state.write().plugin_data, ops);
+        if matches!(&outcome, BatchOutcome::Applied(changes) if !changes.is_empty()) {
+            self.schedule_persist();
+        }
+        Ok(outcome)
+    }
+
+    /// 값을 저장하고 이전 값 반환
+    pub fn set_plugin_data(&self, key: &str, value: Value) -> Result<Option<Value>> {
+        let previous = self
+            .state
+            .write()
+            .plugin_data
+            .insert(key.to_string(), value);
+        self.schedule_persist();
+        Ok(previous)
     }
 
-    pub fn clear_all_plugin_data(&self) -> Result<()> {
-        let mut guard = self.state.write();
-        guard.plugin_data.clear();
-        self.persist_locked(&guard)?;
-        Ok(())
+    /// 값을 삭제하고 이전 값 반환
+    pub fn remove_plugin_data(&self, key: &str) -> Result<Option<Value>> {
+        let previous = self.state.write().plugin_data.remove(key);
+        if previous.is_some() {
+            self.schedule_persist();
+        }
+        Ok(previous)
+    }
+
+    /// 모든 값을 삭제하고 삭제된 항목 반환
+    pub fn clear_all_plugin_data(&self) -> Result<Vec<(String, Value)>> {
+        let removed: Vec<(String, Value)> = std::mem::take(&mut self.state.write().plugin_data)
+            .into_iter()
+            .collect();
+        self.schedule_persist();
+        Ok(removed)
     }
 
     pub fn get_all_plugin_keys(&self) -> Result<Vec<String>> {
@@ -147,52 +322,59 @@ impl AppStore {
         Ok(guard.plugin_data.keys().cloned().collect())
     }
 
-    fn persist_locked(&self, state: &AppStoreData) -> Result<()> {
-        // JSON 출력 시 key 모드 순서를 4,5,6,8 순으로 고정하고 나머지는 사전순으로 정렬합니다.
-        use serde_json::{to_value, Map, Value};
-
-        let mut root = to_value(state)?;
-        if let Value::Object(ref mut obj) = root {
-            // 정렬 도우미
-            let reorder = |value: &mut Value| {
-                if let Value::Object(current) = value {
-                    let desired = ["4key", "5key", "6key", "8key"];
-                    let mut next = Map::new();
-                    // 우선순위 키들 먼저
-                    for k in desired.iter() {
-                        if let Some(v) = current.get(*k) {
-                            next.insert((*k).to_string(), v.clone());
-                        }
-                    }
-                    // 나머지 키들 알파벳 순
-                    let mut rest: Vec<(String, Value)> = current
-                        .iter()
-                        .filter(|(k, _)| !desired.contains(&k.as_str()))
-                        .map(|(k, v)| (k.clone(), v.clone()))
-                        .collect();
-                    rest.sort_by(|a, b| a.0.cmp(&b.0));
-                    for (k, v) in rest.into_iter() {
-                        next.insert(k, v);
+    /// 상태 잠금을 해제한 뒤 호출해야 합니다 (워커 종료 후에는 동기 기록으로 대체됨)
+    fn schedule_persist(&self) {
+        self.persistence.schedule();
+    }
+}
+
+/// store.json 기록 후 기록한 내용을 반환 (파일 워처가 자체 기록을 구분하는 데 사용)
+fn write_store_file(path: &Path, state: &AppStoreData) -> Result<String> {
+    // JSON 출력 시 key 모드 순서를 4,5,6,8 순으로 고정하고 나머지는 사전순으로 정렬합니다.
+    use serde_json::{to_value, Map, Value};
+
+    let mut root = to_value(state)?;
+    if let Value::Object(ref mut obj) = root {
+        // 정렬 도우미
+        let reorder = |value: &mut Value| {
+            if let Value::Object(current) = value {
+                let desired = ["4key", "5key", "6key", "8key"];
+                let mut next = Map::new();
+                // 우선순위 키들 먼저
+                for k in desired.iter() {
+                    if let Some(v) = current.get(*k) {
+                        next.insert((*k).to_string(), v.clone());
                     }
-                    *value = Value::Object(next);
                 }
-            };
-
-            if let Some(v) = obj.get_mut("keys") {
-                reorder(v);
-            }
-            if let Some(v) = obj.get_mut("keyPositions") {
-                reorder(v);
-            }
-            if let Some(v) = obj.get_mut("keyCounters") {
-                reorder(v);
+                // 나머지 키들 알파벳 순
+                let mut rest: Vec<(String, Value)> = current
+                    .iter()
+                    .filter(|(k, _)| !desired.contains(&k.as_str()))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect();
+                rest.sort_by(|a, b| a.0.cmp(&b.0));
+                for (k, v) in rest.into_iter() {
+                    next.insert(k, v);
+                }
+                *value = Value::Object(next);
             }
-        }
+        };
 
-        let json = serde_json::to_string_pretty(&root)?;
-        fs::write(&self.path, json)
-            .with_context(|| format!("failed to write store file at {}", self.path.display()))
+        if let Some(v) = obj.get_mut("keys") {
+            reorder(v);
+        }
+        if let Some(v) = obj.get_mut("keyPositions") {
+            reorder(v);
+        }
+        if let Some(v) = obj.get_mut("keyCounters") {
+            reorder(v);
+        }
     }
+
+    let json = serde_json::to_string_pretty(&root)?;
+    fs::write(path, &json)
+        .with_context(|| format!("failed to write store file at {}", path.display()))?;
+    Ok(json)
 }
 
 fn load_store_from_path(path: &Path) -> Result<AppStoreData> {
@@ -294,7 +476,7 @@ fn merge_default_counters(target: &mut KeyCounters, keys: &KeyMappings) {
     target.retain(|mode, _| available_modes.contains(mode));
 }
 
-fn settings_from_store(store: &AppStoreData) -> SettingsState {
+pub fn settings_from_store(store: &AppStoreData) -> SettingsState {
     let mut custom_js = store.custom_js.clone();
     let _ = custom_js.normalize();
 
@@ -1,4 +1,3 @@
-import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { usePluginMenuStore } from "@stores/usePluginMenuStore";
 import {
@@ -11,6 +10,7 @@ import {
 } from "@utils/pluginComponents";
 import { displayElementApi } from "./pluginDisplayElements";
 import { rawKeyEventBus } from "@utils/rawKeyEventBus";
+import { currentPluginToken, invoke, runWithPluginToken } from "./ipcSession";
 
 import type {
   CssLoadResult,
@@ -22,6 +22,7 @@ import type {
   KeyCounterUpdate,
   KeysModeResponse,
   KeysResetAllResponse,
+  LaunchNotice,
   OverlayBounds,
   OverlayAnchorPayload,
   OverlayLockPayload,
@@ -29,6 +30,11 @@ import type {
   OverlayState,
   OverlayVisibilityPayload,
   Unsubscribe,
+  PluginStorageApi,
+  PluginStorageChangeEvent,
+  PluginStorageChangeListener,
+  PluginStorageOp,
+  PluginStorageTransactionResult,
   ModeChangePayload,
   CustomTabsChangePayload,
   KeyStatePayload,
@@ -37,11 +43,21 @@ import type {
   JsSetContentResult,
   JsTogglePayload,
   JsReloadResult,
+  JsPluginError,
+  JsPluginReloadPayload,
+  JsRemoveOptions,
   JsRemoveResult,
   JsPluginUpdateResult,
   BridgeMessage,
   BridgeMessageListener,
   BridgeAnyListener,
+  BridgeRequest,
+  BridgeRequestHandler,
+  BridgeCallOptions,
+  BridgeWindowInfo,
+  PluginWindowInfo,
+  PluginWindowOptions,
+  WindowType,
   WindowTarget,
   ButtonOptions,
   CheckboxOptions,
@@ -51,10 +67,21 @@ import type {
   PluginDisplayElementConfig,
   PluginDisplayElementInternal,
   RawInputPayload,
+  RemoteServerPatch,
+  RemoteServerStatus,
+  OscOutputPatch,
+  OscOutputStatus,
+  MidiMapping,
+  MidiOutputPatch,
+  MidiOutputStatus,
+  RenderRecordingStatus,
+  RenderScript,
+  RenderScriptExportResult,
+  RenderScriptValidation,
 } from "@src/types/api";
 import type { BootstrapPayload } from "@src/types/app";
 import type { CustomCss } from "@src/types/css";
-import type { CustomJs } from "@src/types/js";
+import type { JsState, PluginPermission } from "@src/types/js";
 import type {
   CustomTab,
   KeyMappings,
@@ -72,6 +99,10 @@ const DEFAULT_LOCALE = "ko";
 const SUPPORTED_LOCALES = new Set(["ko", "en"]);
 
 let cachedLocale: string | null = null;
+// 플러그인 윈도우라면 Rust가 초기화 스크립트로 주입한 정보
+const pluginWindowInfo = (window as any).__dmn_plugin_window as
+  | PluginWindowInfo
+  | undefined;
 const i18nListeners = new Set<(locale: string) => void>();
 
 function initializeCachedLocale() {
@@ -117,17 +148,94 @@ function subscribe<T>(
   };
 }
 
+/** 플러그인 저장소 API (`pluginId`가 없으면 공유 키 공간, 있으면 플러그인별 저장소) */
+const createStorageApi = (pluginId?: string): PluginStorageApi => ({
+  get: <T = any>(key: string) =>
+    invoke<T | null>("plugin_storage_get", { key, pluginId }),
+
+  set: (key: string, value: any) =>
+    invoke<void>("plugin_storage_set", { key, value, pluginId }),
+
+  remove: (key: string) =>
+    invoke<void>("plugin_storage_remove", { key, pluginId }),
+
+  clear: () => invoke<void>("plugin_storage_clear", { pluginId }),
+
+  keys: () => invoke<string[]>("plugin_storage_keys", { pluginId }),
+
+  hasData: (prefix: string) =>
+    invoke<boolean>("plugin_storage_has_data", { prefix, pluginId }),
+
+  clearByPrefix: (prefix: string) =>
+    invoke<number>("plugin_storage_clear_by_prefix", { prefix, pluginId }),
+
+  getMany: <T = any>(keys: string[]) =>
+    invoke<Record<string, T | null>>("plugin_storage_get_many", {
+      keys,
+      pluginId,
+    }),
+
+  setMany: (entries: Record<string, any>) =>
+    invoke<void>("plugin_storage_set_many", { entries, pluginId }),
+
+  transaction: (ops: PluginStorageOp[]) =>
+    invoke<PluginStorageTransactionResult>("plugin_storage_transaction", {
+      ops,
+      pluginId,
+    }),
+
+  onChanged: <T = any>(
+    listener: PluginStorageChangeListener<T>,
+    prefix?: string
+  ) =>
+    subscribe<PluginStorageChangeEvent<T>>(
+      "plugin-storage:changed",
+      (event) => {
+        // 같은 저장소(공유 키 공간 또는 같은 플러그인)의 변경만 전달
+        if ((event.pluginId ?? undefined) !== pluginId) return;
+        const changes = prefix
+          ? event.changes.filter((change) => change.key.startsWith(prefix))
+          : event.changes;
+        if (changes.length === 0) return;
+        listener({ ...event, changes });
+      }
+    ),
+});
+
 const api: DMNoteAPI = {
   app: {
     bootstrap: () => invoke<BootstrapPayload>("app_bootstrap"),
     openExternal: (url: string) => invoke("app_open_external", { url }),
     restart: () => invoke("app_restart"),
+    takeLaunchNotices: () => invoke<LaunchNotice[]>("app_take_launch_notices"),
+    onLaunchNotice: (listener: () => void) =>
+      subscribe<null>("app:launchNotice", () => listener()),
   },
   window: {
-    type: (window as any).__dmn_window_type as "main" | "overlay",
+    type: (window as any).__dmn_window_type as WindowType,
+    plugin: pluginWindowInfo,
     minimize: () => invoke("window_minimize"),
-    close: () => invoke("window_close"),
+    close: () =>
+      pluginWindowInfo
+        ? invoke("plugin_window_close", {
+            pluginId: pluginWindowInfo.pluginId,
+            name: pluginWindowInfo.name,
+          }).then(() => undefined)
+        : invoke("window_close"),
     openDevtoolsAll: () => invoke("window_open_devtools_all"),
+    openPluginWindow: (
+      pluginId: string,
+      name: string,
+      options?: PluginWindowOptions
+    ) =>
+      invoke<PluginWindowInfo>("plugin_window_open", {
+        pluginId,
+        name,
+        options: options ?? null,
+      }),
+    closePluginWindow: (pluginId: string, name: string) =>
+      invoke<boolean>("plugin_window_close", { pluginId, name }),
+    list: () => invoke<BridgeWindowInfo[]>("plugin_window_list"),
   },
   settings: {
     get: () => invoke<SettingsState>("settings_get"),
@@ -136,6 +244,28 @@ const api: DMNoteAPI = {
     onChanged: (listener: (diff: SettingsDiff) => void) =>
       subscribe<SettingsDiff>("settings:changed", listener),
   },
+  remoteServer: {
+    get: () => invoke<RemoteServerStatus>("remote_server_get"),
+    update: (patch: RemoteServerPatch) =>
+      invoke<RemoteServerStatus>("remote_server_update", { patch }),
+  },
+  midi: {
+    get: () => invoke<MidiOutputStatus>("midi_output_get"),
+    update: (patch: MidiOutputPatch) =>
+      invoke<MidiOutputStatus>("midi_output_update", { patch }),
+    setMapping: (mode: string, key: string, mapping: MidiMapping | null) =>
+      invoke<MidiOutputStatus>("midi_output_set_mapping", {
+        mode,
+        key,
+        mapping,
+      }),
+    listPorts: () => invoke<string[]>("midi_output_ports"),
+  },
+  osc: {
+    get: () => invoke<OscOutputStatus>("osc_output_get"),
+    update: (patch: OscOutputPatch) =>
+      invoke<OscOutputStatus>("osc_output_update", { patch }),
+  },
   keys: {
     get: () => invoke<KeyMappings>("keys_get"),
     update: (mappings: KeyMappings) =>
@@ -162,21 +292,41 @@ const api: DMNoteAPI = {
     onKeyState: (listener: (payload: KeyStatePayload) => void) =>
       subscribe<KeyStatePayload>("keys:state", listener),
     onRawInput: (listener: (payload: RawInputPayload) => void): Unsubscribe => {
-      // rawKeyEventBus를 통해 구독 - 구독자가 있을 때만 백엔드가 emit
+      // 플러그인은 먼저 자신의 토큰으로 백엔드 구독을 요청해 raw-input 권한을 확인
+      const pluginToken = currentPluginToken();
+      const backendSubscription = pluginToken
+        ? invoke("raw_input_subscribe").then(() => true)
+        : Promise.resolve(false);
       let unsubscribeFn: (() => void) | null = null;
+      let disposed = false;
 
-      rawKeyEventBus
-        .subscribe(listener)
-        .then((unsub) => {
-          unsubscribeFn = unsub;
-        })
+      // rawKeyEventBus를 통해 구독 - 구독자가 있을 때만 백엔드가 emit
+      backendSubscription
+        .then((holdsSubscription) =>
+          rawKeyEventBus.subscribe(listener).then((unsub) => {
+            unsubscribeFn = () => {
+              unsub();
+              if (holdsSubscription && pluginToken) {
+                runWithPluginToken(pluginToken, () =>
+                  invoke("raw_input_unsubscribe")
+                ).catch(() => undefined);
+              }
+            };
+            if (disposed) {
+              unsubscribeFn();
+              unsubscribeFn = null;
+            }
+          })
+        )
         .catch((error) => {
           console.error("[API] Failed to subscribe to raw input:", error);
         });
 
       return () => {
+        disposed = true;
         if (unsubscribeFn) {
           unsubscribeFn();
+          unsubscribeFn = null;
         }
       };
     },
@@ -262,27 +412,56 @@ const api: DMNoteAPI = {
     },
   },
   js: {
-    get: () => invoke<CustomJs>("js_get"),
+    get: () => invoke<JsState>("js_get"),
     getUse: () => invoke<boolean>("js_get_use"),
     toggle: (enabled: boolean) =>
       invoke<JsTogglePayload>("js_toggle", { enabled }),
     load: () => invoke<JsLoadResult>("js_load"),
     reload: () => invoke<JsReloadResult>("js_reload"),
-    remove: (id: string) => invoke<JsRemoveResult>("js_remove_plugin", { id }),
+    remove: (id: string, options?: JsRemoveOptions) =>
+      invoke<JsRemoveResult>("js_remove_plugin", {
+        id,
+        purgeData: options?.purgeData,
+      }),
+    reorder: (ids: string[]) => invoke<JsState>("js_reorder_plugins", { ids }),
     setPluginEnabled: (id: string, enabled: boolean) =>
       invoke<JsPluginUpdateResult>("js_set_plugin_enabled", { id, enabled }),
+    setPluginPermissions: (id: string, permissions: PluginPermission[]) =>
+      invoke<JsPluginUpdateResult>("js_set_plugin_permissions", {
+        id,
+        permissions,
+      }),
     setContent: (content: string) =>
       invoke<JsSetContentResult>("js_set_content", { content }),
     reset: () => invoke("js_reset"),
+    openPluginDir: () => invoke<string>("js_open_plugin_dir"),
     onUse: (listener: (payload: JsTogglePayload) => void) =>
       subscribe<JsTogglePayload>("js:use", listener),
-    onState: (listener: (payload: CustomJs) => void) =>
-      subscribe<CustomJs>("js:content", listener),
+    onState: (listener: (payload: JsState) => void) =>
+      subscribe<JsState>("js:content", listener),
+    onPluginReload: (listener: (payload: JsPluginReloadPayload) => void) =>
+      subscribe<JsPluginReloadPayload>("js:pluginReload", listener),
+    onPluginError: (listener: (payload: JsPluginError) => void) =>
+      subscribe<JsPluginError>("js:pluginError", listener),
   },
   presets: {
     save: () => invoke<PresetOperationResult>("preset_save"),
     load: () => invoke<PresetOperationResult>("preset_load"),
   },
+  renderScript: {
+    startRecording: () =>
+      invoke<RenderRecordingStatus>("render_record_start"),
+    getStatus: () => invoke<RenderRecordingStatus>("render_record_status"),
+    stopRecording: (path?: string) =>
+      invoke<RenderScriptExportResult>("render_record_stop", {
+        path: path ?? null,
+      }),
+    validate: (source: { path?: string; payload?: RenderScript | string }) =>
+      invoke<RenderScriptValidation>("render_script_validate", {
+        path: source.path ?? null,
+        payload: source.payload ?? null,
+      }),
+  },
   bridge: (() => {
     const listeners = new Map<string, Set<BridgeMessageListener>>();
     const anyListeners = new Set<BridgeAnyListener>();
@@ -332,6 +511,42 @@ const api: DMNoteAPI = {
       console.error("[Bridge] Failed to setup message listener:", error);
     });
 
+    const handlers = new Map<string, BridgeRequestHandler>();
+
+    const reply = (requestId: string, data: any, error?: string) =>
+      invoke("plugin_bridge_reply", {
+        requestId,
+        data: data ?? null,
+        error: error ?? null,
+      }).catch((replyError) => {
+        console.error("[Bridge] Failed to send reply:", replyError);
+      });
+
+    // 다른 윈도우의 call 요청 처리
+    listen<BridgeRequest>("plugin-bridge:request", async ({ payload }) => {
+      const label =
+        pluginWindowInfo?.label ?? (window as any).__dmn_window_type;
+      if (label && payload.target !== label) return;
+
+      const handler = handlers.get(payload.method);
+      if (!handler) {
+        reply(payload.requestId, null, "no-handler");
+        return;
+      }
+      try {
+        const result = await handler(payload.data, payload);
+        reply(payload.requestId, result);
+      } catch (error) {
+        reply(
+          payload.requestId,
+          null,
+          error instanceof Error ? error.message : String(error)
+        );
+      }
+    }).catch((error) => {
+      console.error("[Bridge] Failed to setup request listener:", error);
+    });
+
     return {
       send: (type: string, data?: any) =>
         invoke("plugin_bridge_send", {
@@ -346,6 +561,34 @@ const api: DMNoteAPI = {
           data: data ?? null,
         }),
 
+      call: <R = any, T = any>(
+        target: WindowTarget,
+        method: string,
+        data?: T,
+        options?: BridgeCallOptions
+      ) =>
+        invoke<R>("plugin_bridge_call", {
+          target,
+          method,
+          data: data ?? null,
+          timeoutMs: options?.timeout ?? null,
+        }),
+
+      handle: <T = any, R = any>(
+        method: string,
+        handler: BridgeRequestHandler<T, R>
+      ) => {
+        if (handlers.has(method)) {
+          console.warn(`[Bridge] Replacing handler for '${method}'`);
+        }
+        handlers.set(method, handler as BridgeRequestHandler);
+        return () => {
+          if (handlers.get(method) === handler) {
+            handlers.delete(method);
+          }
+        };
+      },
+
       on: <T = any>(type: string, listener: BridgeMessageListener<T>) => {
         if (!listeners.has(type)) {
           listeners.set(type, new Set());
@@ -426,23 +669,8 @@ const api: DMNoteAPI = {
   },
   plugin: {
     storage: {
-      get: <T = any>(key: string) =>
-        invoke<T | null>("plugin_storage_get", { key }),
-
-      set: (key: string, value: any) =>
-        invoke<void>("plugin_storage_set", { key, value }),
-
-      remove: (key: string) => invoke<void>("plugin_storage_remove", { key }),
-
-      clear: () => invoke<void>("plugin_storage_clear"),
-
-      keys: () => invoke<string[]>("plugin_storage_keys"),
-
-      hasData: (prefix: string) =>
-        invoke<boolean>("plugin_storage_has_data", { prefix }),
-
-      clearByPrefix: (prefix: string) =>
-        invoke<number>("plugin_storage_clear_by_prefix", { prefix }),
+      ...createStorageApi(),
+      namespace: (pluginId: string) => createStorageApi(pluginId),
     },
     registerCleanup: () => {
       console.warn(
@@ -814,12 +1042,10 @@ const api: DMNoteAPI = {
   },
 };
 
-if (typeof window !== "undefined") {
-  window.api = api;
-  // dmn 별칭 추가 (window. 없이 바로 접근 가능)
-  (window as any).dmn = api;
-  (globalThis as any).dmn = api;
-}
+// 앱 세션 토큰으로 호출하는 API이므로 전역(`window.api`/`dmn`)에 노출하지 않음
+// - 앱 코드는 이 모듈을 가져와 사용
+// - 플러그인은 실행 래퍼가 넘기는 window 프록시의 `api`/`dmn`(플러그인 토큰으로 호출)만 사용하므로
+//   `globalThis`, `self`, `document.defaultView`로 실제 전역에 접근해도 이 API를 얻을 수 없음
 
 export {
   handlerRegistry,
@@ -0,0 +1,103 @@
+/**
+ * IPC 호출자 토큰
+ * 백엔드가 앱 자체 호출과 플러그인 호출을 구분할 수 있도록 Rust가 발급한 토큰을 헤더로 보냅니다.
+ *
+ * - 앱 세션 토큰은 이 모듈이 로드될 때(플러그인 실행 전) 웹뷰마다 한 번만 받아 모듈 안에만 보관
+ * - 플러그인 API는 실행 중인 동안 그 플러그인의 토큰으로 호출
+ * - 토큰이 없는 호출은 백엔드가 거부하므로 `__TAURI_INTERNALS__.invoke`를 직접 불러도 권한을 얻을 수 없음
+ */
+
+import { invoke as tauriInvoke, type InvokeArgs } from "@tauri-apps/api/core";
+
+const APP_TOKEN_HEADER = "dmnote-app-token";
+const PLUGIN_TOKEN_HEADER = "dmnote-plugin-token";
+
+// 플러그인 API 함수가 실행 중인 동안의 플러그인 토큰
+let activePluginToken: string | null = null;
+
+// 플러그인이 IPC 요청을 가로채 헤더의 토큰을 읽지 못하도록 플러그인 실행 전에 고정
+function lockIpcGlobals() {
+  const lock = (target: any, key: string) => {
+    if (!target || !(key in target)) return;
+    try {
+      Object.defineProperty(target, key, {
+        value: target[key],
+        writable: false,
+        configurable: false,
+      });
+    } catch {
+      // 이미 고정된 속성
+    }
+  };
+  lock(window, "fetch");
+  lock(window, "Headers");
+  lock(window, "ipc");
+  lock((window as any).ipc, "postMessage");
+  Object.freeze(Headers.prototype);
+}
+
+const appToken: Promise<string> = (() => {
+  lockIpcGlobals();
+  const token = tauriInvoke<string>("plugin_session_open");
+  token.catch((error) => {
+    console.error("[IPC] Failed to open app session", error);
+  });
+  return token;
+})();
+
+function invokeWithToken<T>(
+  pluginToken: string | null,
+  command: string,
+  args?: InvokeArgs
+): Promise<T> {
+  if (pluginToken) {
+    return tauriInvoke<T>(command, args, {
+      headers: { [PLUGIN_TOKEN_HEADER]: pluginToken },
+    });
+  }
+  return appToken.then((token) =>
+    tauriInvoke<T>(command, args, {
+      headers: { [APP_TOKEN_HEADER]: token },
+    })
+  );
+}
+
+/** 백엔드 명령 호출 (플러그인 API 함수 안에서 호출되면 그 플러그인의 토큰 사용) */
+export function invoke<T = unknown>(
+  command: string,
+  args?: InvokeArgs
+): Promise<T> {
+  return invokeWithToken<T>(activePluginToken, command, args);
+}
+
+/** 앱 세션 토큰으로 호출 (플러그인 컨텍스트와 무관한 앱 내부 동작용) */
+export function invokeAsApp<T = unknown>(
+  command: string,
+  args?: InvokeArgs
+): Promise<T> {
+  return invokeWithToken<T>(null, command, args);
+}
+
+/**
+ * 플러그인 토큰으로 함수 실행
+ * 토큰은 동기 구간에서 보낸 호출에만 적용되므로, 나중에 이어지는 호출은 `currentPluginToken`으로 다시 감쌉니다.
+ */
+export function runWithPluginToken<T>(token: string, fn: () => T): T {
+  const previous = activePluginToken;
+  activePluginToken = token;
+  try {
+    return fn();
+  } finally {
+    activePluginToken = previous;
+  }
+}
+
+/** 현재 실행 중인 플러그인 API의 토큰 (앱 코드면 `null`) */
+export function currentPluginToken(): string | null {
+  return activePluginToken;
+}
+
+/** 플러그인을 실행하기 전에 그 플러그인 전용 토큰 발급 (`id`는 스토어의 내부 ID) */
+export function issuePluginToken(id: string): Promise<string> {
+  return invokeAsApp<string>("plugin_session_token", { id });
+}
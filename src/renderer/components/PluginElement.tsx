@@ -30,6 +30,7 @@ import {
   registerExposedActions,
   clearExposedActions,
 } from "@utils/displayElementActions";
+import api from "@api/dmnoteApi";
 
 /**
  * 리사이즈 앵커에 따라 크기 변경 시 위치 보정값 계산
@@ -876,8 +877,8 @@ export const PluginElement: React.FC<PluginElementProps> = ({
         // 오버레이 로컬 스토어 업데이트
         updateElement(element.fullId, { resizeAnchor: anchor });
         // 메인 윈도우로 동기화 (브릿지 통해)
-        if (window.api?.bridge) {
-          window.api.bridge.sendTo(
+        if (api?.bridge) {
+          api.bridge.sendTo(
             "main",
             "plugin:displayElement:updateAnchor",
             {
@@ -941,8 +942,8 @@ export const PluginElement: React.FC<PluginElementProps> = ({
       locale: localeRef.current,
       t: pluginTranslateStable,
       onLocaleChange: (listener: (locale: string) => void) => {
-        if (window.api?.i18n?.onLocaleChange) {
-          return window.api.i18n.onLocaleChange(listener);
+        if (api?.i18n?.onLocaleChange) {
+          return api.i18n.onLocaleChange(listener);
         }
         console.warn(
           "[PluginElement] i18n API is not available in this context"
@@ -1118,7 +1119,7 @@ export const PluginElement: React.FC<PluginElementProps> = ({
             if (typeof prop !== "string") return undefined;
             return (...args: any[]) => {
               try {
-                window.api?.bridge?.sendTo(
+                api?.bridge?.sendTo(
                   "overlay",
                   "plugin:displayElement:invokeAction",
                   {
@@ -1151,8 +1152,8 @@ export const PluginElement: React.FC<PluginElementProps> = ({
         }
       }
 
-      if (window.api?.ui?.displayElement) {
-        window.api.ui.displayElement.remove(element.fullId);
+      if (api?.ui?.displayElement) {
+        api.ui.displayElement.remove(element.fullId);
       } else {
         usePluginDisplayElementStore.getState().removeElement(element.fullId);
       }
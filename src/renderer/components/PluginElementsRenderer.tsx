@@ -8,6 +8,7 @@ import {
   useGridSelectionStore,
   SelectedElement,
 } from "@stores/useGridSelectionStore";
+import api from "@api/dmnoteApi";
 
 /**
  * Main에서 온 elements와 Overlay의 기존 elements를 병합
@@ -87,7 +88,7 @@ export const PluginElementsRenderer: React.FC<PluginElementsRendererProps> = ({
   useEffect(() => {
     if (windowType !== "overlay") return;
 
-    const unsubscribe = window.api.bridge.on<{
+    const unsubscribe = api.bridge.on<{
       elements: PluginDisplayElementInternal[];
     }>("plugin:displayElements:sync", (data) => {
       if (data?.elements) {
@@ -103,7 +104,7 @@ export const PluginElementsRenderer: React.FC<PluginElementsRendererProps> = ({
     });
 
     // 오버레이 초기 로드 시 메인에 현재 상태 요청
-    window.api.bridge.sendTo("main", "plugin:displayElements:request", {});
+    api.bridge.sendTo("main", "plugin:displayElements:request", {});
 
     return () => {
       unsubscribe();
@@ -114,7 +115,7 @@ export const PluginElementsRenderer: React.FC<PluginElementsRendererProps> = ({
   useEffect(() => {
     if (windowType !== "overlay") return;
 
-    const unsubscribe = window.api.bridge.on<{
+    const unsubscribe = api.bridge.on<{
       elementId: string;
       action: string;
       args?: any[];
@@ -136,13 +137,13 @@ export const PluginElementsRenderer: React.FC<PluginElementsRendererProps> = ({
   useEffect(() => {
     if (windowType !== "main") return;
 
-    const unsubscribe = window.api.bridge.on(
+    const unsubscribe = api.bridge.on(
       "plugin:displayElements:request",
       () => {
         // 현재 상태를 오버레이로 전송
         const currentElements =
           usePluginDisplayElementStore.getState().elements;
-        window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+        api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
           elements: currentElements,
         });
       }
@@ -157,7 +158,7 @@ export const PluginElementsRenderer: React.FC<PluginElementsRendererProps> = ({
   useEffect(() => {
     if (windowType !== "main") return;
 
-    const unsubscribe = window.api.bridge.on<{
+    const unsubscribe = api.bridge.on<{
       fullId: string;
       resizeAnchor: string;
     }>("plugin:displayElement:updateAnchor", (data) => {
@@ -26,6 +26,7 @@ import {
   useGridContextMenu,
   useGridMarquee,
 } from "@hooks/Grid";
+import api from "@api/dmnoteApi";
 
 export default function Grid({
   showConfirm,
@@ -632,7 +633,7 @@ export default function Grid({
                 t("confirm.resetKeyCounter", { name: displayName }),
                 async () => {
                   try {
-                    await window.api.keys.resetSingleCounter(
+                    await api.keys.resetSingleCounter(
                       selectedKeyType,
                       globalKey
                     );
@@ -733,6 +734,7 @@ export default function Grid({
       </div>
       {selectedKey && (
         <UnifiedKeySetting
+          mode={selectedKeyType}
           keyData={{
             key: selectedKey.key,
             activeImage:
@@ -3,10 +3,13 @@ import { useTranslation } from "@contexts/I18nContext";
 import { useSettingsStore } from "@stores/useSettingsStore";
 import { getKeyInfoByGlobalKey } from "@utils/KeyMaps";
 import ImagePicker from "./ImagePicker";
+import Dropdown from "@components/main/common/Dropdown";
 import type {
   KeyTabState,
   KeyPreviewData,
+  MidiMappingType,
 } from "@hooks/Modal/useUnifiedKeySettingState";
+import api from "@api/dmnoteApi";
 
 // ============================================================================
 // 타입 정의
@@ -70,11 +73,11 @@ const KeyTabContent: React.FC<KeyTabContentProps> = ({
   // 키 리스닝 effect
   useEffect(() => {
     if (!state.isListening) return undefined;
-    if (typeof window === "undefined" || !window.api?.keys?.onRawInput) {
+    if (typeof window === "undefined" || !api?.keys?.onRawInput) {
       return undefined;
     }
 
-    const unsubscribe = window.api.keys.onRawInput((payload: any) => {
+    const unsubscribe = api.keys.onRawInput((payload: any) => {
       if (!payload || payload.state !== "DOWN") return;
       const targetLabel =
         payload.label ||
@@ -192,6 +195,36 @@ const KeyTabContent: React.FC<KeyTabContentProps> = ({
     [setState, onPreview]
   );
 
+  // MIDI 매핑 핸들러
+  const midiTypeOptions = [
+    { label: t("keySetting.midiNone"), value: "none" },
+    { label: t("keySetting.midiNote"), value: "note" },
+    { label: "CC", value: "cc" },
+  ];
+
+  const handleMidiTypeChange = React.useCallback(
+    (value: string) => {
+      setState((prev) => ({ ...prev, midiType: value as MidiMappingType }));
+    },
+    [setState]
+  );
+
+  const handleMidiNumberChange = React.useCallback(
+    (e: React.ChangeEvent<HTMLInputElement>) => {
+      const newValue = e.target.value;
+      if (newValue === "") {
+        setState((prev) => ({ ...prev, midiNumber: "" }));
+        return;
+      }
+      const numValue = parseInt(newValue, 10);
+      if (!Number.isNaN(numValue)) {
+        const clamped = Math.min(Math.max(numValue, 0), 127);
+        setState((prev) => ({ ...prev, midiNumber: clamped }));
+      }
+    },
+    [setState]
+  );
+
   return (
     <div className="flex flex-col gap-[19px]">
       {/* 키 매핑 */}
@@ -306,6 +339,37 @@ const KeyTabContent: React.FC<KeyTabContentProps> = ({
         </div>
       )}
 
+      {/* MIDI 매핑 - MIDI 출력 활성화 시에만 표시 */}
+      {state.midiEnabled && (
+        <div className="flex justify-between w-full items-center">
+          <p className="text-white text-style-2">{t("keySetting.midiMapping")}</p>
+          <div className="flex items-center gap-[10.5px]">
+            {state.midiType !== "none" && (
+              <input
+                type="number"
+                min={0}
+                max={127}
+                value={state.midiNumber}
+                onChange={handleMidiNumberChange}
+                onBlur={(e) => {
+                  const val = parseInt(e.target.value, 10);
+                  setState((prev) => ({
+                    ...prev,
+                    midiNumber: Number.isNaN(val) ? 60 : val,
+                  }));
+                }}
+                className="text-center w-[47px] h-[23px] bg-[#2A2A30] rounded-[7px] border-[1px] border-[#3A3943] focus:border-[#459BF8] text-style-4 text-[#DBDEE8]"
+              />
+            )}
+            <Dropdown
+              options={midiTypeOptions}
+              value={state.midiType}
+              onChange={handleMidiTypeChange}
+            />
+          </div>
+        </div>
+      )}
+
       {/* 이미지 피커 */}
       {state.showImagePicker && (
         <ImagePicker
@@ -2,6 +2,7 @@ import React from "react";
 import Modal from "@components/main/Modal/Modal";
 import Checkbox from "@components/main/common/Checkbox";
 import TrashIcon from "@assets/svgs/trash.svg";
+import { getPendingPermissions } from "@utils/pluginUtils";
 
 export function PluginManagerModal({
   isOpen,
@@ -9,6 +10,7 @@ export function PluginManagerModal({
   onAdd,
   onToggle,
   onRemove,
+  onRequestPermissions,
   plugins,
   isAdding,
   pendingPluginAction,
@@ -74,6 +76,18 @@ export function PluginManagerModal({
                       </p>
                     ) : null}
                   </div>
+                  {getPendingPermissions(plugin).length > 0 ? (
+                    <button
+                      className={localActionButtonClass(!pendingPluginAction)}
+                      onClick={() => {
+                        if (!pendingPluginAction) onRequestPermissions(plugin);
+                      }}
+                      disabled={!!pendingPluginAction}
+                      title={t("settings.grantPluginPermissions")}
+                    >
+                      {t("settings.grantPluginPermissions")}
+                    </button>
+                  ) : null}
                   <div>
                     <Checkbox
                       checked={plugin.enabled}
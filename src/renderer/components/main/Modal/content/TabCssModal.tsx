@@ -4,6 +4,7 @@ import Checkbox from "@components/main/common/Checkbox";
 import { useTranslation } from "@contexts/I18nContext";
 import { useKeyStore } from "@stores/useKeyStore";
 import type { TabCss } from "@src/types/css";
+import api from "@api/dmnoteApi";
 
 type Props = {
   isOpen: boolean;
@@ -26,7 +27,7 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
     if (!isOpen) return;
 
     setIsLoading(true);
-    window.api.css.tab
+    api.css.tab
       .get(selectedKeyType)
       .then((tabResponse) => {
         const css = tabResponse.css || null;
@@ -46,7 +47,7 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
   useEffect(() => {
     if (!isOpen) return;
 
-    const unsubTabCss = window.api.css.tab.onChanged((payload) => {
+    const unsubTabCss = api.css.tab.onChanged((payload) => {
       if (payload.tabId === selectedKeyType) {
         setTabCss(payload.css || null);
       }
@@ -59,7 +60,7 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
 
   const handleLoadCss = async () => {
     try {
-      const result = await window.api.css.tab.load(selectedKeyType);
+      const result = await api.css.tab.load(selectedKeyType);
       if (result.success && result.css) {
         setTabCss(result.css);
       } else if (result.error) {
@@ -72,7 +73,7 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
 
   const handleClearCss = async () => {
     try {
-      const result = await window.api.css.tab.clear(selectedKeyType);
+      const result = await api.css.tab.clear(selectedKeyType);
       if (result.success) {
         setTabCss(null);
       }
@@ -84,7 +85,7 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
   const handleToggleCss = async () => {
     const newEnabled = !(tabCss?.enabled ?? true);
     try {
-      const result = await window.api.css.tab.toggle(
+      const result = await api.css.tab.toggle(
         selectedKeyType,
         newEnabled
       );
@@ -113,13 +114,13 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
       if (original === null) {
         // 원본이 없었으면 현재 설정 제거
         if (tabCss !== null) {
-          await window.api.css.tab.clear(selectedKeyType);
+          await api.css.tab.clear(selectedKeyType);
         }
       } else {
         // 원본 상태로 복원
         // enabled 상태 복원
         if (tabCss?.enabled !== original.enabled) {
-          await window.api.css.tab.toggle(selectedKeyType, original.enabled);
+          await api.css.tab.toggle(selectedKeyType, original.enabled);
         }
 
         // 파일이 변경된 경우 (원본에 파일이 있었는데 지금 없거나, 다른 파일인 경우)
@@ -131,10 +132,10 @@ export default function TabCssModal({ isOpen, onClose, showAlert }: Props) {
 
         // 원본에 파일이 없었는데 지금 있으면 제거
         if (!original.path && tabCss?.path) {
-          await window.api.css.tab.clear(selectedKeyType);
+          await api.css.tab.clear(selectedKeyType);
           // enabled 상태도 복원
           if (original.enabled !== true) {
-            await window.api.css.tab.toggle(selectedKeyType, original.enabled);
+            await api.css.tab.toggle(selectedKeyType, original.enabled);
           }
         }
       }
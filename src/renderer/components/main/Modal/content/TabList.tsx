@@ -5,6 +5,7 @@ import MinusIcon from "@assets/svgs/minus.svg";
 import { useKeyStore } from "@stores/useKeyStore";
 import Alert from "./Alert.jsx";
 import TabNameModal from "./TabNameModal";
+import api from "@api/dmnoteApi";
 
 type TabListProps = {
   onClose?: () => void;
@@ -23,7 +24,7 @@ const TabList = ({ onClose }: TabListProps) => {
 
   useEffect(() => {
     let disposed = false;
-    window.api.keys.customTabs
+    api.keys.customTabs
       .list()
       .then((tabs) => {
         if (disposed || !Array.isArray(tabs)) return;
@@ -51,7 +52,7 @@ const TabList = ({ onClose }: TabListProps) => {
   const maxReached = Array.isArray(customTabs) && customTabs.length >= 5;
 
   const handleCreate = async (name: string) => {
-    const result = await window.api.keys.customTabs.create(name);
+    const result = await api.keys.customTabs.create(name);
     if (!result?.error) {
       onClose?.();
     }
@@ -60,7 +61,7 @@ const TabList = ({ onClose }: TabListProps) => {
 
   const handleSelect = async (id: string) => {
     try {
-      const result = await window.api.keys.customTabs.select(id);
+      const result = await api.keys.customTabs.select(id);
       if (result?.success) {
         setSelectedKeyType(result.selected);
         onClose?.();
@@ -74,7 +75,7 @@ const TabList = ({ onClose }: TabListProps) => {
 
   const handleDelete = async () => {
     try {
-      const result = await window.api.keys.customTabs.delete(selectedKeyType);
+      const result = await api.keys.customTabs.delete(selectedKeyType);
       if (!result?.success) {
         console.warn("Failed to delete custom tab", result?.error);
       }
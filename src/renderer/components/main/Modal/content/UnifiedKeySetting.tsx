@@ -20,6 +20,7 @@ import type { KeyCounterSettings } from "@src/types/keys";
 
 interface UnifiedKeySettingProps {
   keyData: KeyData;
+  mode?: string;
   initialCounterSettings?: KeyCounterSettings | null;
   onSave: (data: SaveData) => void;
   onClose: () => void;
@@ -70,6 +71,7 @@ const TabSwitch: React.FC<TabSwitchProps> = ({ activeTab, onTabChange }) => {
 
 const UnifiedKeySetting: React.FC<UnifiedKeySettingProps> = ({
   keyData,
+  mode,
   initialCounterSettings,
   onSave,
   onClose,
@@ -100,6 +102,7 @@ const UnifiedKeySetting: React.FC<UnifiedKeySettingProps> = ({
     handleClose,
   } = useUnifiedKeySettingState({
     keyData,
+    mode,
     initialCounterSettings,
     onPreview,
     onSave,
@@ -4,6 +4,7 @@ import { getKeyInfoByGlobalKey } from "@utils/KeyMaps";
 import { useSettingsStore } from "@stores/useSettingsStore";
 import ImagePicker from "../ImagePicker";
 import Modal from "../../Modal";
+import api from "@api/dmnoteApi";
 
 export default function KeySetting({
   keyData,
@@ -42,11 +43,11 @@ export default function KeySetting({
 
   useEffect(() => {
     if (!isListening) return undefined;
-    if (typeof window === "undefined" || !window.api?.keys?.onRawInput) {
+    if (typeof window === "undefined" || !api?.keys?.onRawInput) {
       return undefined;
     }
 
-    const unsubscribe = window.api.keys.onRawInput((payload) => {
+    const unsubscribe = api.keys.onRawInput((payload) => {
       if (!payload || payload.state !== "DOWN") return;
       const targetLabel =
         payload.label ||
@@ -8,7 +8,12 @@ import FlaskIcon from "@assets/svgs/flask.svg";
 import { PluginManagerModal } from "@components/main/Modal/content/PluginManagerModal";
 import { PluginDataDeleteModal } from "@components/main/Modal/content/PluginDataDeleteModal";
 import { applyCounterSnapshot } from "@stores/keyCounterSignals";
-import { extractPluginId } from "@utils/pluginUtils";
+import {
+  extractPluginId,
+  getPendingPermissions,
+  getRequestablePermissions,
+} from "@utils/pluginUtils";
+import api from "@api/dmnoteApi";
 
 export default function Settings({ showAlert, showConfirm }) {
   const { t, i18n } = useTranslation();
@@ -51,6 +56,11 @@ export default function Settings({ showAlert, showConfirm }) {
   const [isReloadingPlugins, setIsReloadingPlugins] = useState(false);
   const [isAddingPlugins, setIsAddingPlugins] = useState(false);
   const [pendingPluginId, setPendingPluginId] = useState(null);
+  const [remoteServer, setRemoteServer] = useState(null);
+  const [oscOutput, setOscOutput] = useState(null);
+  const [oscTarget, setOscTarget] = useState("");
+  const [midiOutput, setMidiOutput] = useState(null);
+  const [midiPorts, setMidiPorts] = useState([]);
 
   const VIDEO_SOURCES = {
     overlayLock:
@@ -97,8 +107,8 @@ export default function Settings({ showAlert, showConfirm }) {
     const apply = async () => {
       setHardwareAcceleration(next);
       try {
-        await window.api.settings.update({ hardwareAcceleration: next });
-        await window.api.app.restart();
+        await api.settings.update({ hardwareAcceleration: next });
+        await api.app.restart();
       } catch (error) {
         console.error("Failed to toggle hardware acceleration", error);
       }
@@ -115,7 +125,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !alwaysOnTop;
     setAlwaysOnTop(next);
     try {
-      await window.api.settings.update({ alwaysOnTop: next });
+      await api.settings.update({ alwaysOnTop: next });
     } catch (error) {
       console.error("Failed to toggle always-on-top", error);
     }
@@ -125,7 +135,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !overlayLocked;
     setOverlayLocked(next);
     try {
-      await window.api.overlay.setLock(next);
+      await api.overlay.setLock(next);
     } catch (error) {
       console.error("Failed to toggle overlay lock", error);
     }
@@ -135,7 +145,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !useCustomCSS;
     setUseCustomCSS(next);
     try {
-      await window.api.css.toggle(next);
+      await api.css.toggle(next);
     } catch (error) {
       console.error("Failed to toggle custom CSS", error);
     }
@@ -144,7 +154,7 @@ export default function Settings({ showAlert, showConfirm }) {
   const handleLoadCustomCSS = async () => {
     if (!useCustomCSS) return;
     try {
-      const result = await window.api.css.load();
+      const result = await api.css.load();
       if (result?.success) {
         if (result.content) setCustomCSSContent(result.content);
         if (result.path) setCustomCSSPath(result.path);
@@ -165,7 +175,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !useCustomJS;
     setUseCustomJS(next);
     try {
-      await window.api.js.toggle(next);
+      await api.js.toggle(next);
     } catch (error) {
       console.error("Failed to toggle custom JS", error);
     }
@@ -185,7 +195,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const startTime = performance.now();
     setIsReloadingPlugins(true);
     try {
-      const result = await window.api.js.reload();
+      const result = await api.js.reload();
       const updated = result?.updated ?? [];
       const errors = result?.errors ?? [];
 
@@ -221,6 +231,14 @@ export default function Settings({ showAlert, showConfirm }) {
     }
   };
 
+  const handleOpenPluginDir = async () => {
+    try {
+      await api.js.openPluginDir();
+    } catch (error) {
+      console.error("Failed to open plugin folder", error);
+    }
+  };
+
   const handleOpenPluginModal = () => {
     setPluginModalOpen(true);
   };
@@ -229,15 +247,65 @@ export default function Settings({ showAlert, showConfirm }) {
     setPluginModalOpen(false);
   };
 
+  const confirmAsync = (message) =>
+    new Promise((resolve) => {
+      if (!showConfirm) {
+        resolve(false);
+        return;
+      }
+      showConfirm(
+        message,
+        () => resolve(true),
+        () => resolve(false)
+      );
+    });
+
+  // 아직 허용하지 않은 권한이 있으면 플러그인마다 확인
+  // (@permissions를 선언하지 않은 플러그인은 예전처럼 모든 권한을 허용할지 확인)
+  const requestPluginPermissions = async (plugins) => {
+    for (const plugin of plugins) {
+      if (getPendingPermissions(plugin).length === 0) {
+        continue;
+      }
+      const requested = getRequestablePermissions(plugin);
+      const declared = (plugin.manifest?.permissions ?? []).length > 0;
+      const approved = await confirmAsync(
+        t(
+          declared
+            ? "settings.jsPermissionRequest"
+            : "settings.jsLegacyPermissionRequest",
+          {
+            name: plugin.manifest?.name ?? plugin.name,
+            permissions: requested
+              .map((permission) =>
+                t(`settings.jsPermissionNames.${permission}`)
+              )
+              .join(", "),
+          }
+        )
+      );
+      if (!approved) {
+        continue;
+      }
+      try {
+        await api.js.setPluginPermissions(plugin.id, requested);
+      } catch (error) {
+        console.error("Failed to update plugin permissions", error);
+      }
+    }
+  };
+
   const handleAddPlugins = async () => {
     if (isAddingPlugins) return;
     setIsAddingPlugins(true);
     try {
-      const result = await window.api.js.load();
+      const result = await api.js.load();
       if (!result) return;
       const added = result.added ?? [];
       const errors = result.errors ?? [];
 
+      await requestPluginPermissions([...added, ...(result.updated ?? [])]);
+
       if (errors.length && added.length) {
         showAlert?.(
           `${t("settings.jsAddPartial", {
@@ -263,7 +331,7 @@ export default function Settings({ showAlert, showConfirm }) {
     if (pendingPluginId) return;
     setPendingPluginId(pluginId);
     try {
-      const result = await window.api.js.setPluginEnabled(pluginId, nextState);
+      const result = await api.js.setPluginEnabled(pluginId, nextState);
       if (!result?.success) {
         showAlert?.(t("settings.jsPluginToggleFailed"));
       }
@@ -283,11 +351,13 @@ export default function Settings({ showAlert, showConfirm }) {
 
     try {
       // 실제 플러그인 네임스페이스 추출 (@id 또는 파일명 기반)
-      const pluginNamespace = extractPluginId(plugin.content, plugin.name);
+      const pluginNamespace =
+        plugin.manifest?.id ?? extractPluginId(plugin.content, plugin.name);
 
-      // 네임스페이스를 prefix로 사용하는 데이터가 있는지 확인
-      // 백엔드에서 자동으로 "plugin_data_" 를 붙이므로 순수 네임스페이스만 전달
-      const hasData = await window.api.plugin.storage.hasData(pluginNamespace);
+      // 플러그인별 저장소에 데이터가 있는지 확인
+      const hasData = await api.plugin.storage
+        .namespace(pluginNamespace)
+        .hasData("");
       console.debug(
         "[PluginRemove] namespace=",
         pluginNamespace,
@@ -314,7 +384,7 @@ export default function Settings({ showAlert, showConfirm }) {
   const removePluginOnly = async (pluginId) => {
     setPendingPluginId(pluginId);
     try {
-      const result = await window.api.js.remove(pluginId);
+      const result = await api.js.remove(pluginId);
       if (!result?.success) {
         showAlert?.(t("settings.jsPluginRemoveFailed"));
       }
@@ -337,16 +407,17 @@ export default function Settings({ showAlert, showConfirm }) {
       }
 
       // 실제 네임스페이스를 다시 추출
-      const pluginNamespace = extractPluginId(plugin.content, plugin.name);
+      const pluginNamespace =
+        plugin.manifest?.id ?? extractPluginId(plugin.content, plugin.name);
 
-      // 1) 먼저 플러그인 제거 → 클린업이 실행되며 일부 플러그인은 저장을 시도할 수 있음
-      const result = await window.api.js.remove(pluginId);
+      // 1) 플러그인 제거와 함께 저장소 파일 삭제
+      const result = await api.js.remove(pluginId, { purgeData: true });
       if (!result?.success) {
         showAlert?.(t("settings.jsPluginRemoveFailed"));
       }
 
-      // 2) 그 다음 스토리지 정리 → 클린업 중 재생성된 값까지 함께 제거
-      await window.api.plugin.storage.clearByPrefix(pluginNamespace);
+      // 2) 제거 시 클린업이 실행되며 다시 저장된 값까지 정리
+      await api.plugin.storage.namespace(pluginNamespace).clear();
     } catch (error) {
       console.error("Failed to remove JS plugin with data", error);
       showAlert?.(t("settings.jsPluginRemoveFailed"));
@@ -367,7 +438,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !noteEffect;
     setNoteEffect(next);
     try {
-      await window.api.settings.update({ noteEffect: next });
+      await api.settings.update({ noteEffect: next });
     } catch (error) {
       console.error("Failed to toggle note effect", error);
     }
@@ -377,8 +448,8 @@ export default function Settings({ showAlert, showConfirm }) {
     const apply = async () => {
       setAngleMode(val);
       try {
-        await window.api.settings.update({ angleMode: val });
-        await window.api.app.restart();
+        await api.settings.update({ angleMode: val });
+        await api.app.restart();
       } catch (error) {
         console.error("Failed to change angle mode", error);
       }
@@ -395,7 +466,7 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !laboratoryEnabled;
     setLaboratoryEnabled(next);
     try {
-      await window.api.settings.update({ laboratoryEnabled: next });
+      await api.settings.update({ laboratoryEnabled: next });
     } catch (error) {
       console.error("Failed to toggle laboratory mode", error);
     }
@@ -405,11 +476,11 @@ export default function Settings({ showAlert, showConfirm }) {
     const next = !developerModeEnabled;
     setDeveloperModeEnabled(next);
     try {
-      await window.api.settings.update({ developerModeEnabled: next });
+      await api.settings.update({ developerModeEnabled: next });
       // 개발자 모드가 활성화되면 즉시 DevTools 오픈 (메인 & 오버레이)
       if (next) {
         try {
-          await window.api.window.openDevtoolsAll?.();
+          await api.window.openDevtoolsAll?.();
         } catch (e) {}
       }
     } catch (error) {
@@ -417,11 +488,132 @@ export default function Settings({ showAlert, showConfirm }) {
     }
   };
 
+  useEffect(() => {
+    api.remoteServer
+      .get()
+      .then(setRemoteServer)
+      .catch((error) => console.error("Failed to load remote server", error));
+  }, []);
+
+  const updateRemoteServer = async (patch) => {
+    try {
+      setRemoteServer(await api.remoteServer.update(patch));
+    } catch (error) {
+      console.error("Failed to update remote server", error);
+      showAlert?.(`${t("settings.remoteServerFailed")}${error}`);
+      api.remoteServer
+        .get()
+        .then(setRemoteServer)
+        .catch(() => undefined);
+    }
+  };
+
+  const handleRemoteServerToggle = () => {
+    if (!remoteServer) return;
+    updateRemoteServer({ enabled: !remoteServer.enabled });
+  };
+
+  const handleCopyRemoteServerUrl = async () => {
+    if (!remoteServer) return;
+    const url = `ws://127.0.0.1:${remoteServer.port}/ws?token=${remoteServer.token}`;
+    try {
+      await navigator.clipboard.writeText(url);
+      showAlert?.(t("settings.remoteServerUrlCopied"));
+    } catch (error) {
+      console.error("Failed to copy remote server url", error);
+    }
+  };
+
+  const handleRegenerateRemoteServerToken = () => {
+    updateRemoteServer({ token: "" });
+  };
+
+  const handleRemoteControlToggle = () => {
+    if (!remoteServer) return;
+    updateRemoteServer({ controlEnabled: !remoteServer.controlEnabled });
+  };
+
+  useEffect(() => {
+    api.midi
+      .get()
+      .then(setMidiOutput)
+      .catch((error) => console.error("Failed to load MIDI output", error));
+    api.midi
+      .listPorts()
+      .then(setMidiPorts)
+      .catch(() => undefined);
+  }, []);
+
+  const updateMidiOutput = async (patch) => {
+    try {
+      setMidiOutput(await api.midi.update(patch));
+    } catch (error) {
+      console.error("Failed to update MIDI output", error);
+      showAlert?.(`${t("settings.midiOutputFailed")}${error}`);
+      api.midi
+        .get()
+        .then(setMidiOutput)
+        .catch(() => undefined);
+    }
+  };
+
+  const handleMidiOutputToggle = () => {
+    if (!midiOutput) return;
+    updateMidiOutput({ enabled: !midiOutput.enabled });
+  };
+
+  const handleMidiPortChange = (portName) => {
+    updateMidiOutput({ backend: "port", portName });
+  };
+
+  const applyOscOutput = (status) => {
+    setOscOutput(status);
+    setOscTarget(`${status.host}:${status.port}`);
+  };
+
+  useEffect(() => {
+    api.osc
+      .get()
+      .then(applyOscOutput)
+      .catch((error) => console.error("Failed to load OSC output", error));
+  }, []);
+
+  const updateOscOutput = async (patch) => {
+    try {
+      applyOscOutput(await api.osc.update(patch));
+    } catch (error) {
+      console.error("Failed to update OSC output", error);
+      showAlert?.(`${t("settings.oscOutputFailed")}${error}`);
+      api.osc
+        .get()
+        .then(applyOscOutput)
+        .catch(() => undefined);
+    }
+  };
+
+  const handleOscOutputToggle = () => {
+    if (!oscOutput) return;
+    updateOscOutput({ enabled: !oscOutput.enabled });
+  };
+
+  // "host:port" 입력 (IPv6는 [::1]:9000 형식)
+  const handleOscTargetCommit = () => {
+    if (!oscOutput) return;
+    const match = oscTarget.trim().match(/^\[?([^\]]*?)\]?:(\d+)$/);
+    const port = match ? Number(match[2]) : 0;
+    if (!match || !match[1] || port < 1 || port > 65535) {
+      setOscTarget(`${oscOutput.host}:${oscOutput.port}`);
+      return;
+    }
+    if (match[1] === oscOutput.host && port === oscOutput.port) return;
+    updateOscOutput({ host: match[1], port });
+  };
+
   const handleKeyCounterToggle = async () => {
     const next = !keyCounterEnabled;
     setKeyCounterEnabled(next);
     try {
-      await window.api.settings.update({ keyCounterEnabled: next });
+      await api.settings.update({ keyCounterEnabled: next });
     } catch (error) {
       console.error("Failed to toggle key counter", error);
     }
@@ -430,7 +622,7 @@ export default function Settings({ showAlert, showConfirm }) {
   const handleResetCounters = async (event) => {
     event.stopPropagation();
     try {
-      const snapshot = await window.api.keys.resetCounters();
+      const snapshot = await api.keys.resetCounters();
       applyCounterSnapshot(snapshot);
       showAlert?.(t("settings.counterReset"));
     } catch (error) {
@@ -442,7 +634,7 @@ export default function Settings({ showAlert, showConfirm }) {
   const handleResetAll = () => {
     const reset = async () => {
       try {
-        const result = await window.api.keys.resetAll();
+        const result = await api.keys.resetAll();
         if (result) {
           // 리셋 직후 메모리 상태도 바로 초기값으로 변경
           useKeyStore.setState({
@@ -577,7 +769,7 @@ export default function Settings({ showAlert, showConfirm }) {
                   onChange={async (val) => {
                     setOverlayResizeAnchor(val);
                     try {
-                      await window.api.overlay.setAnchor(val);
+                      await api.overlay.setAnchor(val);
                     } catch (error) {
                       console.error("Failed to set overlay anchor", error);
                     }
@@ -673,6 +865,12 @@ export default function Settings({ showAlert, showConfirm }) {
                     >
                       {t("settings.reloadPlugins")}
                     </button>
+                    <button
+                      onClick={handleOpenPluginDir}
+                      className={actionButtonClass(true)}
+                    >
+                      {t("settings.openPluginFolder")}
+                    </button>
                     <button
                       onClick={handleOpenPluginModal}
                       className={actionButtonClass(true)}
@@ -719,6 +917,118 @@ export default function Settings({ showAlert, showConfirm }) {
                   onChange={handleDeveloperModeToggle}
                 />
               </div>
+              <div
+                className="flex flex-row justify-between items-center h-[25px] cursor-pointer"
+                onClick={handleRemoteServerToggle}
+              >
+                <p className="text-style-3 text-[#FFFFFF]">
+                  {t("settings.remoteServer")}
+                </p>
+                <Checkbox
+                  checked={!!remoteServer?.enabled}
+                  onChange={handleRemoteServerToggle}
+                />
+              </div>
+              {remoteServer?.enabled && (
+                <div className="flex flex-row justify-between items-center h-[25px]">
+                  <p className="text-[12px] truncate max-w-[150px] text-[#989BA6]">
+                    {remoteServer.running
+                      ? `127.0.0.1:${remoteServer.port}`
+                      : t("settings.remoteServerStopped")}
+                  </p>
+                  <div className="flex flex-row gap-[8px]">
+                    <button
+                      onClick={handleRegenerateRemoteServerToken}
+                      className={actionButtonClass(true)}
+                    >
+                      {t("settings.remoteServerNewToken")}
+                    </button>
+                    <button
+                      onClick={handleCopyRemoteServerUrl}
+                      className={actionButtonClass(true)}
+                    >
+                      {t("settings.remoteServerCopyUrl")}
+                    </button>
+                  </div>
+                </div>
+              )}
+              {remoteServer?.enabled && (
+                <div
+                  className="flex flex-row justify-between items-center h-[25px] cursor-pointer"
+                  onClick={handleRemoteControlToggle}
+                >
+                  <p className="text-[12px] text-[#989BA6]">
+                    {t("settings.remoteControl")}
+                  </p>
+                  <Checkbox
+                    checked={!!remoteServer.controlEnabled}
+                    onChange={handleRemoteControlToggle}
+                  />
+                </div>
+              )}
+              <div
+                className="flex flex-row justify-between items-center h-[25px] cursor-pointer"
+                onClick={handleOscOutputToggle}
+              >
+                <p className="text-style-3 text-[#FFFFFF]">
+                  {t("settings.oscOutput")}
+                </p>
+                <Checkbox
+                  checked={!!oscOutput?.enabled}
+                  onChange={handleOscOutputToggle}
+                />
+              </div>
+              {oscOutput?.enabled && (
+                <div className="flex flex-row justify-between items-center h-[25px]">
+                  <p className="text-[12px] truncate max-w-[150px] text-[#989BA6]">
+                    {oscOutput.running
+                      ? t("settings.oscOutputTarget")
+                      : t("settings.remoteServerStopped")}
+                  </p>
+                  <input
+                    type="text"
+                    value={oscTarget}
+                    placeholder="127.0.0.1:9000"
+                    onChange={(e) => setOscTarget(e.target.value)}
+                    onBlur={handleOscTargetCommit}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter") e.currentTarget.blur();
+                    }}
+                    className="text-center w-[130px] h-[23px] bg-[#2A2A30] rounded-[7px] border-[1px] border-[#3A3943] focus:border-[#459BF8] text-style-4 text-[#DBDEE8]"
+                  />
+                </div>
+              )}
+              <div
+                className="flex flex-row justify-between items-center h-[25px] cursor-pointer"
+                onClick={handleMidiOutputToggle}
+              >
+                <p className="text-style-3 text-[#FFFFFF]">
+                  {t("settings.midiOutput")}
+                </p>
+                <Checkbox
+                  checked={!!midiOutput?.enabled}
+                  onChange={handleMidiOutputToggle}
+                />
+              </div>
+              {midiOutput?.enabled && midiOutput.backend === "port" && (
+                <div className="flex flex-row justify-between items-center h-[25px]">
+                  <p className="text-[12px] truncate max-w-[150px] text-[#989BA6]">
+                    {midiOutput.running
+                      ? `CH ${midiOutput.channel}`
+                      : t("settings.remoteServerStopped")}
+                  </p>
+                  <Dropdown
+                    options={midiPorts.map((name) => ({
+                      label: name,
+                      value: name,
+                    }))}
+                    value={midiOutput.portName || midiPorts[0] || ""}
+                    onChange={handleMidiPortChange}
+                    placeholder={t("settings.midiOutputNoPorts")}
+                    disabled={midiPorts.length === 0}
+                  />
+                </div>
+              )}
               {/* 버전 및 설정 초기화 */}
               <div className="flex justify-between items-center py-[14px] px-[12px] bg-[#101013] rounded-[7px]">
                 <p className="text-style-3 text-[#FFFFFF]">Ver 1.3.0</p>
@@ -778,6 +1088,7 @@ export default function Settings({ showAlert, showConfirm }) {
           onAdd={handleAddPlugins}
           onToggle={handlePluginToggle}
           onRemove={handlePluginRemove}
+          onRequestPermissions={(plugin) => requestPluginPermissions([plugin])}
           plugins={jsPlugins}
           isAdding={isAddingPlugins}
           pendingPluginId={pendingPluginId}
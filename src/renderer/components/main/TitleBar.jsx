@@ -2,14 +2,15 @@ import React from "react";
 import Close from "@assets/svgs/close.svg";
 import Minimize from "@assets/svgs/minimize.svg";
 import Logo from "@assets/svgs/logo.svg";
+import api from "@api/dmnoteApi";
 
 export default function TitleBar() {
   const handleMinimize = () => {
-    window.api.window.minimize();
+    api.window.minimize();
   };
 
   const handleClose = () => {
-    window.api.window.close();
+    api.window.close();
   };
 
   return (
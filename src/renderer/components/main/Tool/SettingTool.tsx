@@ -11,6 +11,7 @@ import FloatingTooltip from "../Modal/FloatingTooltip";
 import ListPopup, { ListItem } from "../Modal/ListPopup";
 import { TooltipGroup } from "../Modal/TooltipGroup";
 import { useSettingsStore } from "@stores/useSettingsStore";
+import api from "@api/dmnoteApi";
 
 type SettingToolProps = {
   isSettingsOpen?: boolean;
@@ -49,7 +50,7 @@ const SettingTool = ({
 
   useEffect(() => {
     let unsubscribe: (() => void) | null = null;
-    window.api.overlay
+    api.overlay
       .get()
       .then((state) => {
         setIsOverlayVisible(state.visible);
@@ -58,7 +59,7 @@ const SettingTool = ({
         console.error("Failed to fetch overlay visibility", error);
       });
 
-    unsubscribe = window.api.overlay.onVisibility(({ visible }) => {
+    unsubscribe = api.overlay.onVisibility(({ visible }) => {
       setIsOverlayVisible(visible);
     });
 
@@ -78,14 +79,14 @@ const SettingTool = ({
   const toggleOverlay = () => {
     const next = !isOverlayVisible;
     setIsOverlayVisible(next);
-    window.api.overlay.setVisible(next).catch((error) => {
+    api.overlay.setVisible(next).catch((error) => {
       console.error("Failed to toggle overlay", error);
     });
   };
 
   const handlePresetSave = async () => {
     try {
-      const result = await window.api.presets.save();
+      const result = await api.presets.save();
       showAlert?.(
         result?.success ? t("preset.saveSuccess") : t("preset.saveFail")
       );
@@ -97,7 +98,7 @@ const SettingTool = ({
 
   const handlePresetLoad = async () => {
     try {
-      const result = await window.api.presets.load();
+      const result = await api.presets.load();
       showAlert?.(
         result?.success ? t("preset.loadSuccess") : t("preset.loadFail")
       );
@@ -6,6 +6,7 @@ import Bug from "@assets/svgs/code.svg";
 import { TooltipGroup } from "../Modal/TooltipGroup";
 import { useTranslation } from "@contexts/I18nContext";
 import FloatingTooltip from "../Modal/FloatingTooltip";
+import api from "@api/dmnoteApi";
 
 type Props = {
   onAddKey: () => void;
@@ -42,7 +43,7 @@ const ToolBar = ({
 }: Props) => {
   const { t } = useTranslation();
   const handleClick = (link: string) => {
-    window.api.app.openExternal(link);
+    api.app.openExternal(link);
   };
 
   return (
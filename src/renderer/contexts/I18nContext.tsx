@@ -8,6 +8,7 @@ import {
 } from "react";
 import type { ReactNode } from "react";
 import type { SettingsDiff, SettingsState } from "@src/types/settings";
+import api from "@api/dmnoteApi";
 
 export type SupportedLocale = "ko" | "en";
 
@@ -97,7 +98,7 @@ export function I18nProvider({ children }: { children: ReactNode }) {
 
     (async () => {
       try {
-        const settings: SettingsState = await window.api.settings.get();
+        const settings: SettingsState = await api.settings.get();
         if (!cancelled && isSupportedLocale(settings.language)) {
           setLocaleState(settings.language);
           try {
@@ -128,7 +129,7 @@ export function I18nProvider({ children }: { children: ReactNode }) {
   }, []);
 
   useEffect(() => {
-    const unsubscribe = window.api.settings.onChanged((diff: SettingsDiff) => {
+    const unsubscribe = api.settings.onChanged((diff: SettingsDiff) => {
       const next = diff.changed.language;
       if (isSupportedLocale(next)) {
         setLocaleState(next);
@@ -156,7 +157,7 @@ export function I18nProvider({ children }: { children: ReactNode }) {
     } catch (error) {
       console.warn("Failed to persist locale", error);
     }
-    window.api.settings.update({ language: next }).catch((error) => {
+    api.settings.update({ language: next }).catch((error) => {
       console.error("Failed to update language", error);
     });
   }, []);
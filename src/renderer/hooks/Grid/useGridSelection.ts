@@ -16,6 +16,7 @@ import {
 } from "@stores/useGridSelectionStore";
 import { PASTE_OFFSET } from "./constants";
 import type { KeyMappings, KeyPositions, KeyPosition } from "@src/types/keys";
+import api from "@api/dmnoteApi";
 
 interface UseGridSelectionParams {
   selectedElements: SelectedElement[];
@@ -58,7 +59,7 @@ export function useGridSelection({
   const syncSelectedElementsToOverlay = useCallback(() => {
     // 키 위치 동기화
     const currentPositions = useKeyStore.getState().positions;
-    window.api.keys.updatePositions(currentPositions).catch((error: Error) => {
+    api.keys.updatePositions(currentPositions).catch((error: Error) => {
       console.error("Failed to sync key positions to overlay", error);
     });
     // 플러그인 요소는 setElements에서 자동으로 syncToOverlayThrottled 호출됨
@@ -112,7 +113,7 @@ export function useGridSelection({
 
         // syncToOverlay가 true일 때만 API 호출 (드래그 중에는 false)
         if (syncToOverlay) {
-          window.api.keys
+          api.keys
             .updatePositions(newPositions)
             .catch((error: Error) => {
               console.error("Failed to sync key positions to overlay", error);
@@ -199,8 +200,8 @@ export function useGridSelection({
 
       // API 동기화 (순차 실행으로 일관성 보장)
       try {
-        await window.api.keys.update(updatedMappings);
-        await window.api.keys.updatePositions(updatedPositions);
+        await api.keys.update(updatedMappings);
+        await api.keys.updatePositions(updatedPositions);
       } catch (error) {
         console.error("Failed to delete keys", error);
       } finally {
@@ -337,8 +338,8 @@ export function useGridSelection({
         .setKeyMappingsAndPositions(updatedMappings, updatedPositions);
 
       try {
-        await window.api.keys.update(updatedMappings);
-        await window.api.keys.updatePositions(updatedPositions);
+        await api.keys.update(updatedMappings);
+        await api.keys.updatePositions(updatedPositions);
       } catch (error) {
         console.error("Failed to paste keys", error);
       } finally {
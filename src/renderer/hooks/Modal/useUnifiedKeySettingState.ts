@@ -1,4 +1,4 @@
-import { useState, useMemo, useRef, useCallback } from "react";
+import { useState, useMemo, useRef, useCallback, useEffect } from "react";
 import { getKeyInfoByGlobalKey } from "@utils/KeyMaps";
 import { isGradientColor, normalizeColorInput } from "@utils/colorUtils";
 import {
@@ -6,6 +6,8 @@ import {
   normalizeCounterSettings,
 } from "@src/types/keys";
 import type { NoteColor, KeyCounterSettings } from "@src/types/keys";
+import type { MidiMapping } from "@src/types/api";
+import api from "@api/dmnoteApi";
 
 // ============================================================================
 // 타입 정의
@@ -26,6 +28,8 @@ export const COLOR_MODES = {
 
 export type ColorMode = (typeof COLOR_MODES)[keyof typeof COLOR_MODES];
 
+export type MidiMappingType = "none" | MidiMapping["type"];
+
 export interface GradientColor {
   type: "gradient";
   top: string;
@@ -71,6 +75,10 @@ export interface KeyTabState {
   showImagePicker: boolean;
   widthFocused: boolean;
   heightFocused: boolean;
+  // MIDI 출력이 켜져 있을 때만 표시
+  midiEnabled: boolean;
+  midiType: MidiMappingType;
+  midiNumber: number | string;
 }
 
 // 노트 탭 상태 타입
@@ -191,9 +199,26 @@ export function createInitialKeyState(keyData: KeyData): KeyTabState {
     showImagePicker: false,
     widthFocused: false,
     heightFocused: false,
+    midiEnabled: false,
+    midiType: "none",
+    midiNumber: 60,
   };
 }
 
+const toMidiMapping = (
+  type: MidiMappingType,
+  value: number | string
+): MidiMapping | null => {
+  const parsed = typeof value === "number" ? value : parseInt(value, 10);
+  const number = Math.min(Math.max(Number.isNaN(parsed) ? 0 : parsed, 0), 127);
+  if (type === "note") return { type: "note", note: number };
+  if (type === "cc") return { type: "cc", controller: number };
+  return null;
+};
+
+const isSameMidiMapping = (a: MidiMapping | null, b: MidiMapping | null) =>
+  JSON.stringify(a) === JSON.stringify(b);
+
 export function createInitialNoteState(keyData: KeyData): NoteTabState {
   const initialNoteColor = keyData.noteColor || "#FFFFFF";
   const initialGlowSource = keyData.noteGlowColor ?? initialNoteColor;
@@ -271,6 +296,8 @@ export function createInitialCounterState(
 
 export interface UseUnifiedKeySettingStateProps {
   keyData: KeyData;
+  /** 키가 속한 모드 (MIDI 매핑용) */
+  mode?: string;
   initialCounterSettings: KeyCounterSettings | null | undefined;
   onPreview?: (data: PreviewData) => void;
   onSave: (data: SaveData) => void;
@@ -279,6 +306,7 @@ export interface UseUnifiedKeySettingStateProps {
 
 export function useUnifiedKeySettingState({
   keyData,
+  mode,
   initialCounterSettings,
   onPreview,
   onSave,
@@ -298,6 +326,35 @@ export function useUnifiedKeySettingState({
     createInitialKeyState(keyData)
   );
 
+  // MIDI 매핑 불러오기 (저장 시 변경 여부 비교용으로 원본 보관)
+  const originalMidiRef = useRef<MidiMapping | null>(null);
+  useEffect(() => {
+    if (!mode || !api?.midi) return;
+    let cancelled = false;
+    api.midi
+      .get()
+      .then((status) => {
+        if (cancelled || !status.enabled) return;
+        const mapping = status.mappings[mode]?.[keyData.key] ?? null;
+        originalMidiRef.current = mapping;
+        setKeyState((prev) => ({
+          ...prev,
+          midiEnabled: true,
+          midiType: mapping?.type ?? "none",
+          midiNumber:
+            mapping?.type === "note"
+              ? mapping.note
+              : mapping?.type === "cc"
+              ? mapping.controller
+              : prev.midiNumber,
+        }));
+      })
+      .catch((error) => console.error("Failed to load MIDI output", error));
+    return () => {
+      cancelled = true;
+    };
+  }, [mode, keyData.key]);
+
   // 노트 탭 상태
   const [noteState, setNoteState] = useState<NoteTabState>(() =>
     createInitialNoteState(keyData)
@@ -362,6 +419,23 @@ export function useUnifiedKeySettingState({
         ? toGradient(noteState.glowColor, noteState.glowGradientBottom)
         : noteState.glowColor;
 
+    // MIDI 매핑은 키 이름 기준이라 키를 바꾸면 이전 키의 매핑을 옮김
+    if (mode && keyState.midiEnabled) {
+      const original = originalMidiRef.current;
+      const mapping = toMidiMapping(keyState.midiType, keyState.midiNumber);
+      const keyChanged = keyState.key !== keyData.key;
+      if (keyChanged && original) {
+        api.midi
+          .setMapping(mode, keyData.key, null)
+          .catch((error) => console.error("Failed to clear MIDI mapping", error));
+      }
+      if (keyChanged || !isSameMidiMapping(original, mapping)) {
+        api.midi
+          .setMapping(mode, keyState.key, mapping)
+          .catch((error) => console.error("Failed to save MIDI mapping", error));
+      }
+    }
+
     onSave({
       // 키 데이터
       key: keyState.key,
@@ -400,7 +474,7 @@ export function useUnifiedKeySettingState({
         },
       }),
     });
-  }, [keyState, noteState, counterState, onSave]);
+  }, [keyState, noteState, counterState, onSave, mode, keyData.key]);
 
   // 취소 핸들러 (원본으로 롤백)
   const handleClose = useCallback(() => {
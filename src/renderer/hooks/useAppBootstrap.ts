@@ -8,6 +8,7 @@ import { applyCounterSnapshot, setKeyCounter } from "@stores/keyCounterSignals";
 import type { SettingsDiff } from "@src/types/settings";
 import type { OverlayResizeAnchor } from "@src/types/settings";
 import type { CustomJs, JsPlugin } from "@src/types/js";
+import api from "@api/dmnoteApi";
 
 function clonePlugins(source?: CustomJs | null): JsPlugin[] {
   if (!source) return [];
@@ -79,7 +80,7 @@ export function useAppBootstrap() {
     };
 
     (async () => {
-      const bootstrap = await window.api.app.bootstrap();
+      const bootstrap = await api.app.bootstrap();
       if (disposed) return;
       setAll({
         hardwareAcceleration: bootstrap.settings.hardwareAcceleration,
@@ -113,30 +114,30 @@ export function useAppBootstrap() {
     })();
 
     const unsubscribers = [
-      window.api.settings.onChanged((diff: SettingsDiff) => {
+      api.settings.onChanged((diff: SettingsDiff) => {
         if (disposed || !diff) return;
         applyDiff(diff);
       }),
-      window.api.keys.onChanged((keys) => {
+      api.keys.onChanged((keys) => {
         // 로컬 업데이트 중에는 백엔드 이벤트 무시 (삭제 작업 등)
         if (useKeyStore.getState().isLocalUpdateInProgress) return;
         useKeyStore.setState((state) => ({ ...state, keyMappings: keys }));
       }),
-      window.api.keys.onPositionsChanged((positions) => {
+      api.keys.onPositionsChanged((positions) => {
         // 로컬 업데이트 중에는 백엔드 이벤트 무시 (삭제 작업 등)
         if (useKeyStore.getState().isLocalUpdateInProgress) return;
         useKeyStore.setState((state) => ({ ...state, positions }));
       }),
-      window.api.keys.onModeChanged(({ mode }) => {
+      api.keys.onModeChanged(({ mode }) => {
         useKeyStore.setState((state) => ({ ...state, selectedKeyType: mode }));
       }),
-      window.api.keys.onCounterChanged(({ mode, key, count }) => {
+      api.keys.onCounterChanged(({ mode, key, count }) => {
         setKeyCounter(mode, key, count);
       }),
-      window.api.keys.onCountersChanged((snapshot) => {
+      api.keys.onCountersChanged((snapshot) => {
         applyCounterSnapshot(snapshot);
       }),
-      window.api.keys.customTabs.onChanged(
+      api.keys.customTabs.onChanged(
         ({ customTabs, selectedKeyType }) => {
           useKeyStore.setState((state) => ({
             ...state,
@@ -145,31 +146,38 @@ export function useAppBootstrap() {
           }));
         }
       ),
-      window.api.overlay.onLock(({ locked }) => {
+      api.overlay.onLock(({ locked }) => {
         useSettingsStore.setState({ overlayLocked: locked });
       }),
-      window.api.overlay.onAnchor(({ anchor }) => {
+      api.overlay.onAnchor(({ anchor }) => {
         useSettingsStore.setState({
           overlayResizeAnchor: anchor as OverlayResizeAnchor,
         });
       }),
-      window.api.css.onUse(({ enabled }) => {
+      api.css.onUse(({ enabled }) => {
         useSettingsStore.setState({ useCustomCSS: enabled });
       }),
-      window.api.css.onContent((css) => {
+      api.css.onContent((css) => {
         useSettingsStore.setState({
           customCSSContent: css.content,
           customCSSPath: css.path,
         });
       }),
-      window.api.js.onUse(({ enabled }) => {
+      api.js.onUse(({ enabled }) => {
         useSettingsStore.setState({ useCustomJS: enabled });
       }),
-      window.api.js.onState((script) => {
+      api.js.onState((script) => {
         useSettingsStore.setState({
           jsPlugins: clonePlugins(script),
         });
       }),
+      api.js.onPluginReload(({ plugin }) => {
+        useSettingsStore.setState((state) => ({
+          jsPlugins: state.jsPlugins.map((item) =>
+            item.id === plugin.id ? { ...plugin } : item
+          ),
+        }));
+      }),
     ];
 
     return () => {
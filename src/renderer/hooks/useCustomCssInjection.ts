@@ -2,6 +2,7 @@ import { useEffect, useRef } from "react";
 import { useKeyStore } from "@stores/useKeyStore";
 import type { TabCss, TabCssOverrides } from "@src/types/css";
 import type { CustomCss } from "@src/types/css";
+import api from "@api/dmnoteApi";
 
 const STYLE_ELEMENT_ID = "dmn-custom-css";
 
@@ -81,13 +82,13 @@ export function useCustomCssInjection() {
 
     // 초기 데이터 로드
     const initPromises = [
-      window.api.css.get().then((data) => {
+      api.css.get().then((data) => {
         globalCssRef.current = data;
       }),
-      window.api.css.getUse().then((enabled) => {
+      api.css.getUse().then((enabled) => {
         globalUseRef.current = enabled;
       }),
-      window.api.css.tab.getAll().then((overrides) => {
+      api.css.tab.getAll().then((overrides) => {
         tabCssOverridesRef.current = overrides;
       }),
     ];
@@ -97,18 +98,18 @@ export function useCustomCssInjection() {
     });
 
     // 전역 CSS 변경 구독
-    const unsubGlobalUse = window.api.css.onUse(({ enabled }) => {
+    const unsubGlobalUse = api.css.onUse(({ enabled }) => {
       globalUseRef.current = enabled;
       applyCssForCurrentTab();
     });
 
-    const unsubGlobalContent = window.api.css.onContent((css) => {
+    const unsubGlobalContent = api.css.onContent((css) => {
       globalCssRef.current = css;
       applyCssForCurrentTab();
     });
 
     // 탭별 CSS 변경 구독
-    const unsubTabCss = window.api.css.tab.onChanged((payload) => {
+    const unsubTabCss = api.css.tab.onChanged((payload) => {
       if (payload.css) {
         tabCssOverridesRef.current[payload.tabId] = payload.css;
       } else {
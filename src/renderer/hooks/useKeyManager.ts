@@ -13,6 +13,7 @@ import {
   createDefaultCounterSettings,
   normalizeCounterSettings,
 } from "@src/types/keys";
+import api from "@api/dmnoteApi";
 
 type SelectedKey = { key: string; index: number } | null;
 
@@ -85,7 +86,7 @@ export function useKeyManager() {
       ),
     };
     setPositions(nextPositions);
-    window.api.keys.updatePositions(nextPositions).catch((error) => {
+    api.keys.updatePositions(nextPositions).catch((error) => {
       console.error("Failed to update key positions", error);
     });
   };
@@ -137,8 +138,8 @@ export function useKeyManager() {
       setPositions(updatedPositions);
 
       Promise.all([
-        window.api.keys.update(updatedMappings),
-        window.api.keys.updatePositions(updatedPositions),
+        api.keys.update(updatedMappings),
+        api.keys.updatePositions(updatedPositions),
       ]).catch((error) => {
         console.error("Failed to persist key update", error);
       });
@@ -188,8 +189,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to persist new key", error);
     });
@@ -236,8 +237,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to persist new key at position", error);
     });
@@ -299,8 +300,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to duplicate key", error);
     });
@@ -342,7 +343,7 @@ export function useKeyManager() {
     };
 
     setPositions(updatedPositions);
-    window.api.keys.updatePositions(updatedPositions).catch((error) => {
+    api.keys.updatePositions(updatedPositions).catch((error) => {
       console.error("Failed to update note color settings", error);
     });
   };
@@ -382,7 +383,7 @@ export function useKeyManager() {
 
     setPositions(updatedPositions);
     // 미리보기라도 오버레이에 반영되도록 이벤트 브로드캐스트
-    window.api.keys.updatePositions(updatedPositions).catch((error) => {
+    api.keys.updatePositions(updatedPositions).catch((error) => {
       console.error("Failed to preview note color settings", error);
     });
   };
@@ -451,7 +452,7 @@ export function useKeyManager() {
     };
 
     setPositions(updatedPositions);
-    window.api.keys.updatePositions(updatedPositions).catch((error) => {
+    api.keys.updatePositions(updatedPositions).catch((error) => {
       console.error("Failed to preview key settings", error);
     });
   };
@@ -483,7 +484,7 @@ export function useKeyManager() {
     };
 
     setPositions(updatedPositions);
-    window.api.keys.updatePositions(updatedPositions).catch((error) => {
+    api.keys.updatePositions(updatedPositions).catch((error) => {
       console.error("Failed to update counter settings", error);
     });
   };
@@ -514,7 +515,7 @@ export function useKeyManager() {
 
     setPositions(updatedPositions);
     // 미리보기라도 오버레이에 반영되도록 이벤트 브로드캐스트
-    window.api.keys.updatePositions(updatedPositions).catch((error) => {
+    api.keys.updatePositions(updatedPositions).catch((error) => {
       console.error("Failed to preview counter settings", error);
     });
   };
@@ -539,8 +540,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to delete key", error);
     });
@@ -572,8 +573,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to move key to front", error);
     });
@@ -603,8 +604,8 @@ export function useKeyManager() {
     setPositions(updatedPositions);
 
     Promise.all([
-      window.api.keys.update(updatedMappings),
-      window.api.keys.updatePositions(updatedPositions),
+      api.keys.update(updatedMappings),
+      api.keys.updatePositions(updatedPositions),
     ]).catch((error) => {
       console.error("Failed to move key to back", error);
     });
@@ -612,7 +613,7 @@ export function useKeyManager() {
 
   const handleResetCurrentMode = async () => {
     try {
-      await window.api.keys.resetMode(selectedKeyType);
+      await api.keys.resetMode(selectedKeyType);
       setSelectedKey(null);
     } catch (error) {
       console.error("Failed to reset current mode", error);
@@ -689,8 +690,8 @@ export function useKeyManager() {
           setPluginElements(finalElements as any);
 
           // 오버레이로 동기화
-          if (window.api?.bridge) {
-            window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+          if (api?.bridge) {
+            api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
               elements: finalElements,
             });
           }
@@ -698,8 +699,8 @@ export function useKeyManager() {
 
         // 백엔드에도 반영
         Promise.all([
-          window.api.keys.update(previousState.keyMappings),
-          window.api.keys.updatePositions(previousState.positions),
+          api.keys.update(previousState.keyMappings),
+          api.keys.updatePositions(previousState.positions),
         ]).catch((error) => {
           console.error("Failed to apply undo", error);
         });
@@ -782,8 +783,8 @@ export function useKeyManager() {
           setPluginElements(finalElements as any);
 
           // 오버레이로 동기화
-          if (window.api?.bridge) {
-            window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+          if (api?.bridge) {
+            api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
               elements: finalElements,
             });
           }
@@ -791,8 +792,8 @@ export function useKeyManager() {
 
         // 백엔드에도 반영
         Promise.all([
-          window.api.keys.update(nextState.keyMappings),
-          window.api.keys.updatePositions(nextState.positions),
+          api.keys.update(nextState.keyMappings),
+          api.keys.updatePositions(nextState.positions),
         ]).catch((error) => {
           console.error("Failed to apply redo", error);
         });
@@ -1,5 +1,6 @@
 import { useState, useEffect } from "react";
 import { useSettingsStore } from "@stores/useSettingsStore";
+import api from "@api/dmnoteApi";
 
 export function usePalette() {
   const [palette, setPalette] = useState(false);
@@ -14,7 +15,7 @@ export function usePalette() {
   const handleColorChange = (newColor: string) => {
     setColor(newColor);
     setBackgroundColor(newColor);
-    window.api.settings.update({ backgroundColor: newColor }).catch((error) => {
+    api.settings.update({ backgroundColor: newColor }).catch((error) => {
       console.error("Failed to update background color", error);
     });
   };
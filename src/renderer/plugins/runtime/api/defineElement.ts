@@ -14,6 +14,7 @@ import type {
   PluginDefinition,
   PluginDefinitionInternal,
 } from "@src/types/api";
+import api from "@api/dmnoteApi";
 
 interface DefineElementDependencies {
   pluginId: string;
@@ -104,21 +105,21 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
       }
     };
 
-    if (window.api?.i18n?.getLocale) {
-      window.api.i18n
+    if (api?.i18n?.getLocale) {
+      api.i18n
         .getLocale()
         .then(applyLocale)
         .catch(() => undefined);
-    } else if (window.api?.settings?.get) {
-      window.api.settings
+    } else if (api?.settings?.get) {
+      api.settings
         .get()
         .then((settings) => applyLocale((settings as any)?.language))
         .catch(() => undefined);
     }
 
     let localeCleanup: (() => void) | null = null;
-    if (window.api?.i18n?.onLocaleChange) {
-      localeCleanup = window.api.i18n.onLocaleChange(applyLocale);
+    if (api?.i18n?.onLocaleChange) {
+      localeCleanup = api.i18n.onLocaleChange(applyLocale);
       if (localeCleanup) {
         registerCleanup(() => {
           try {
@@ -154,7 +155,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
             if (typeof prop !== "string") return undefined;
             return (...args: any[]) => {
               try {
-                window.api?.bridge?.sendTo(
+                api?.bridge?.sendTo(
                   "overlay",
                   "plugin:displayElement:invokeAction",
                   {
@@ -246,7 +247,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
             currentSettings[key] = newValue;
             const newSettings = { ...currentSettings };
 
-            window.api.ui.displayElement.update(instanceId, {
+            api.ui.displayElement.update(instanceId, {
               settings: newSettings,
             });
           };
@@ -254,7 +255,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
           const wrappedChange = wrapFunctionWithContext(handleChange);
 
           if (schema.type === "boolean") {
-            componentHtml = window.api.ui.components.checkbox({
+            componentHtml = api.ui.components.checkbox({
               checked: !!value,
               onChange: wrappedChange,
             });
@@ -282,7 +283,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
               target.classList.remove("border-[#3A3943]");
               target.classList.add("border-[#459BF8]");
 
-              window.api.ui.pickColor({
+              api.ui.pickColor({
                 initialColor: currentSettings[key],
                 id: pickerId,
                 referenceElement: target as HTMLElement,
@@ -326,7 +327,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
               else inputWidth = 200;
             }
 
-            componentHtml = window.api.ui.components.input({
+            componentHtml = api.ui.components.input({
               type: schema.type === "string" ? "text" : (schema.type as any),
               value: value,
               onChange: wrappedChange,
@@ -343,7 +344,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
                 label: translate(option.label, undefined, option.label),
               })
             );
-            componentHtml = window.api.ui.components.dropdown({
+            componentHtml = api.ui.components.dropdown({
               options: translatedOptions,
               selected: value,
               onChange: wrappedChange,
@@ -358,7 +359,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
           `;
         }
       } else {
-        const noSettingsText = await window.api.settings
+        const noSettingsText = await api.settings
           .get()
           .then((s) => {
             const locale = (s as any).language || "ko";
@@ -372,7 +373,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
 
       htmlContent += "</div>";
 
-      const [saveText, cancelText] = await window.api.settings
+      const [saveText, cancelText] = await api.settings
         .get()
         .then((s) => {
           const locale = (s as any).language || "ko";
@@ -380,14 +381,14 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
         })
         .catch(() => ["저장", "취소"]);
 
-      const confirmed = await window.api.ui.dialog.custom(htmlContent, {
+      const confirmed = await api.ui.dialog.custom(htmlContent, {
         showCancel: true,
         confirmText: saveText,
         cancelText: cancelText,
       });
 
       if (!confirmed) {
-        window.api.ui.displayElement.update(instanceId, {
+        api.ui.displayElement.update(instanceId, {
           settings: originalSettings,
         });
       }
@@ -414,7 +415,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
           ).length;
       };
 
-      const menuId = window.api.ui.contextMenu.addGridMenuItem({
+      const menuId = api.ui.contextMenu.addGridMenuItem({
         id: `create-${defId}`,
         label: createLabel,
         // maxInstances 제한 도달 시 메뉴 비활성화 (현재 탭 기준)
@@ -437,7 +438,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
             }
           }
 
-          window.api.ui.displayElement.add({
+          api.ui.displayElement.add({
             html: "<!-- plugin-element -->",
             position: {
               x: context.position.dx,
@@ -458,7 +459,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
       });
 
       registerCleanup(() => {
-        window.api.ui.contextMenu.removeMenuItem(menuId);
+        api.ui.contextMenu.removeMenuItem(menuId);
       });
     }
 
@@ -536,7 +537,7 @@ export const createDefineElement = (deps: DefineElementDependencies) => {
               // 각 add 호출 직전에 plugin context 재설정 (async race condition 방지)
               (window as any).__dmn_current_plugin_id = pluginId;
 
-              window.api.ui.displayElement.add({
+              api.ui.displayElement.add({
                 html: "<!-- plugin-element -->",
                 position: inst.position,
                 draggable: true,
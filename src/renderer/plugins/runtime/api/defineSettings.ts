@@ -12,6 +12,7 @@ import type {
   PluginSettingsInstance,
   Unsubscribe,
 } from "@src/types/api";
+import api from "@api/dmnoteApi";
 
 interface DefineSettingsDependencies {
   pluginId: string;
@@ -88,7 +89,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
           // JSON 직렬화/역직렬화로 순수 데이터만 복사 (순환 참조 및 특수 객체 제거)
           const safeSettings = JSON.parse(JSON.stringify(newSettings));
 
-          window.api?.bridge?.sendTo("overlay", "plugin:settings:changed", {
+          api?.bridge?.sendTo("overlay", "plugin:settings:changed", {
             pluginId,
             settings: safeSettings,
           });
@@ -183,7 +184,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
           };
 
           if (schema.type === "boolean") {
-            componentHtml = window.api.ui.components.checkbox({
+            componentHtml = api.ui.components.checkbox({
               checked: !!value,
               onChange: handleChange,
             });
@@ -211,7 +212,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
               target.classList.remove("border-[#3A3943]");
               target.classList.add("border-[#459BF8]");
 
-              window.api.ui.pickColor({
+              api.ui.pickColor({
                 initialColor: dialogSettings[key],
                 id: pickerId,
                 referenceElement: target as HTMLElement,
@@ -258,7 +259,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
               else inputWidth = 200;
             }
 
-            componentHtml = window.api.ui.components.input({
+            componentHtml = api.ui.components.input({
               type: schema.type === "string" ? "text" : (schema.type as any),
               value: value,
               onChange: handleChange,
@@ -275,7 +276,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
                 label: translate(option.label, undefined, option.label),
               })
             );
-            componentHtml = window.api.ui.components.dropdown({
+            componentHtml = api.ui.components.dropdown({
               options: translatedOptions,
               selected: value,
               onChange: handleChange,
@@ -290,7 +291,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
           `;
         }
       } else {
-        const noSettingsText = await window.api.settings
+        const noSettingsText = await api.settings
           .get()
           .then((s) => {
             const locale = (s as any).language || "ko";
@@ -304,7 +305,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
 
       htmlContent += "</div>";
 
-      const [saveText, cancelText] = await window.api.settings
+      const [saveText, cancelText] = await api.settings
         .get()
         .then((s) => {
           const locale = (s as any).language || "ko";
@@ -312,7 +313,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
         })
         .catch(() => ["저장", "취소"]);
 
-      const confirmed = await window.api.ui.dialog.custom(htmlContent, {
+      const confirmed = await api.ui.dialog.custom(htmlContent, {
         showCancel: true,
         confirmText: saveText,
         cancelText: cancelText,
@@ -350,7 +351,7 @@ export const createDefineSettings = (deps: DefineSettingsDependencies) => {
 
     // 오버레이에서 설정 변경 메시지 수신 리스너
     if ((window as any).__dmn_window_type === "overlay") {
-      const bridgeCleanup = window.api?.bridge?.on(
+      const bridgeCleanup = api?.bridge?.on(
         "plugin:settings:changed",
         (data: { pluginId: string; settings: Record<string, any> }) => {
           if (data.pluginId === pluginId) {
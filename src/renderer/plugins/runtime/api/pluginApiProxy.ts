@@ -9,10 +9,14 @@ import {
   wrapFunctionWithContext,
 } from "../context";
 import { createDefineElement } from "./defineElement";
+import type { DMNoteAPI, PluginWindowOptions } from "@src/types/api";
 import { createDefineSettings } from "./defineSettings";
+import api from "@api/dmnoteApi";
 
 interface CreatePluginApiProxyOptions {
   pluginId: string;
+  /** 백엔드가 발급한 이 플러그인 전용 호출 토큰 */
+  token: string;
   registerCleanup: (cleanup: () => void) => void;
   isReloading: () => boolean;
 }
@@ -22,15 +26,16 @@ interface CreatePluginApiProxyOptions {
  */
 export const createPluginApiProxy = (
   options: CreatePluginApiProxyOptions
-): typeof window.api => {
-  const { pluginId, registerCleanup, isReloading } = options;
+): DMNoteAPI => {
+  const { pluginId, token, registerCleanup, isReloading } = options;
 
-  const originalStorage = window.api.plugin.storage;
+  const originalStorage = api.plugin.storage;
   const namespacedStorage = createNamespacedStorage(pluginId, originalStorage);
 
-  const wrappedApi = wrapApiValue(window.api, pluginId);
+  const wrappedApi = wrapApiValue(api, pluginId, token);
 
-  const wrapWithContext = (fn: any) => wrapFunctionWithContext(fn, pluginId);
+  const wrapWithContext = (fn: any) =>
+    wrapFunctionWithContext(fn, pluginId, token);
 
   const defineElement = createDefineElement({
     pluginId,
@@ -50,16 +55,34 @@ export const createPluginApiProxy = (
     ...wrappedApi,
     window: {
       ...(wrappedApi.window || {}),
-      type: (window as any).__dmn_window_type as "main" | "overlay",
+      type: (window as any).__dmn_window_type as "main" | "overlay" | "plugin",
+      // 자신의 플러그인 ID로 윈도우 열기/닫기
+      open: (name: string, options?: PluginWindowOptions) =>
+        wrappedApi.window.openPluginWindow(pluginId, name, options),
+      closeWindow: (name: string) =>
+        wrappedApi.window.closePluginWindow(pluginId, name),
+    },
+    bridge: {
+      ...wrappedApi.bridge,
+      // 핸들러는 플러그인 컨텍스트에서 실행하고, 플러그인 정리 시 함께 해제
+      handle: (method: string, handler: (...args: any[]) => any) => {
+        const unsubscribe = api.bridge.handle(
+          method,
+          wrapWithContext(handler)
+        );
+        registerCleanup(unsubscribe);
+        return unsubscribe;
+      },
     },
     plugin: {
       ...(wrappedApi.plugin || {}),
-      storage: namespacedStorage,
+      // 비동기 콜백에서 호출해도 이 플러그인의 토큰으로 권한을 검사하도록 래핑
+      storage: wrapApiValue(namespacedStorage, pluginId, token),
       registerCleanup: (cleanup: () => void) => registerCleanup(cleanup),
       defineElement,
       defineSettings,
     },
-  } as typeof window.api;
+  } as DMNoteAPI;
 
   return proxiedApi;
 };
@@ -68,7 +91,7 @@ export const createPluginApiProxy = (
  * 플러그인용 Window 프록시를 생성합니다.
  */
 export const createPluginWindowProxy = (
-  proxiedApi: typeof window.api
+  proxiedApi: DMNoteAPI
 ): Window => {
   return new Proxy(window, {
     get(target, prop: string | symbol, receiver) {
@@ -3,11 +3,18 @@
  * 플러그인 컨텍스트를 유지하면서 함수를 실행할 수 있도록 래핑합니다.
  */
 
+import { runWithPluginToken } from "@api/ipcSession";
+
 /**
  * 함수를 플러그인 컨텍스트로 래핑합니다.
  * 비동기 함수의 경우 Promise가 완료될 때까지 컨텍스트를 유지합니다.
+ * `token`이 있으면 실행 중 보낸 백엔드 호출은 그 플러그인의 권한으로 검사됩니다.
  */
-export const wrapFunctionWithContext = (fn: any, pluginId: string) => {
+export const wrapFunctionWithContext = (
+  fn: any,
+  pluginId: string,
+  token?: string
+) => {
   if (typeof fn !== "function") return fn;
   if (fn.__dmn_plugin_wrapped__) return fn;
 
@@ -17,7 +24,9 @@ export const wrapFunctionWithContext = (fn: any, pluginId: string) => {
     let result: any;
     let threw = false;
     try {
-      result = fn.apply(this, args);
+      result = token
+        ? runWithPluginToken(token, () => fn.apply(this, args))
+        : fn.apply(this, args);
     } catch (error) {
       threw = true;
       throw error;
@@ -56,15 +65,19 @@ export const wrapFunctionWithContext = (fn: any, pluginId: string) => {
 /**
  * 객체/배열의 모든 함수를 재귀적으로 래핑합니다.
  */
-export const wrapApiValue = (value: any, pluginId: string): any => {
+export const wrapApiValue = (
+  value: any,
+  pluginId: string,
+  token?: string
+): any => {
   if (typeof value === "function") {
-    return wrapFunctionWithContext(value, pluginId);
+    return wrapFunctionWithContext(value, pluginId, token);
   }
 
   if (value && typeof value === "object") {
     const clone: any = Array.isArray(value) ? [] : {};
     Object.keys(value).forEach((key) => {
-      clone[key] = wrapApiValue(value[key], pluginId);
+      clone[key] = wrapApiValue(value[key], pluginId, token);
     });
     return clone;
   }
@@ -18,7 +18,9 @@ import { extractPluginId } from "@utils/pluginUtils";
 import { handlerRegistry } from "./handlers";
 import { displayElementInstanceRegistry, setInitialLoading } from "./displayElement";
 import { createPluginApiProxy, createPluginWindowProxy } from "./api";
-import type { JsPlugin } from "@src/types/js";
+import { issuePluginToken } from "@api/ipcSession";
+import type { JsPlugin, JsState } from "@src/types/js";
+import api from "@api/dmnoteApi";
 
 const SCRIPT_ID_PREFIX = "dmn-custom-js-";
 
@@ -43,9 +45,15 @@ export function createCustomJsRuntime(): CustomJsRuntime {
   let enabled = false;
   let disposed = false;
   let currentPlugins: JsPlugin[] = [];
+  // 백엔드가 의존성을 반영해 계산한 로드 순서 (내부 ID)
+  let loadOrder: string[] = [];
 
   // 전역 플래그: removeAll/injectAll 실행 중에는 저장 비활성화
   let isReloading = false;
+  // 토큰 발급을 기다리는 동안 다시 로드되면 이전 요청은 버림
+  let injectGeneration = 0;
+  // 백엔드가 발급한 플러그인별 호출 토큰 (내부 ID 기준, 페이지가 바뀌기 전까지 유지)
+  const pluginTokens = new Map<string, Promise<string>>();
 
   const getIsReloading = () => isReloading;
 
@@ -80,31 +88,36 @@ export function createCustomJsRuntime(): CustomJsRuntime {
     handlerRegistry.clearPlugin(pluginId);
   };
 
-  const removeAll = () => {
-    for (const [
-      id,
-      { element, cleanup, pluginId },
-    ] of activeElements.entries()) {
-      if (pluginId) {
-        const previousPluginId = (window as any).__dmn_current_plugin_id;
-        (window as any).__dmn_current_plugin_id = pluginId;
-
-        runPluginCleanups(pluginId);
-
-        if (cleanup) {
-          safeRun(cleanup, id);
-        }
+  const removeElement = (id: string) => {
+    const entry = activeElements.get(id);
+    if (!entry) return;
+    const { element, cleanup, pluginId } = entry;
+    activeElements.delete(id);
+
+    if (pluginId) {
+      const previousPluginId = (window as any).__dmn_current_plugin_id;
+      (window as any).__dmn_current_plugin_id = pluginId;
+
+      runPluginCleanups(pluginId);
 
-        (window as any).__dmn_current_plugin_id = previousPluginId;
-      } else if (cleanup) {
+      if (cleanup) {
         safeRun(cleanup, id);
       }
 
-      if (element && element.parentNode) {
-        element.remove();
-      }
+      (window as any).__dmn_current_plugin_id = previousPluginId;
+    } else if (cleanup) {
+      safeRun(cleanup, id);
+    }
+
+    if (element && element.parentNode) {
+      element.remove();
+    }
+  };
+
+  const removeAll = () => {
+    for (const id of Array.from(activeElements.keys())) {
+      removeElement(id);
     }
-    activeElements.clear();
 
     if ((window as any).__dmn_window_type === "main") {
       try {
@@ -112,8 +125,8 @@ export function createCustomJsRuntime(): CustomJsRuntime {
         usePluginDisplayElementStore.getState().setElements([]);
         displayElementInstanceRegistry.clearAll();
 
-        if (window.api?.bridge) {
-          window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+        if (api?.bridge) {
+          api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
             elements: [],
           });
         }
@@ -123,14 +136,36 @@ export function createCustomJsRuntime(): CustomJsRuntime {
     }
   };
 
-  const injectPlugin = (plugin: JsPlugin) => {
+  const pluginKey = (plugin: JsPlugin) =>
+    plugin.manifest?.id ?? extractPluginId(plugin.content, plugin.name);
+
+  const tokenFor = (plugin: JsPlugin) => {
+    let token = pluginTokens.get(plugin.id);
+    if (!token) {
+      token = issuePluginToken(plugin.id);
+      pluginTokens.set(plugin.id, token);
+      token.catch(() => pluginTokens.delete(plugin.id));
+    }
+    return token;
+  };
+
+  const finishReloading = (generation: number) => {
+    // 모든 플러그인의 복원이 완료될 때까지 딜레이 후 리로드 플래그 해제
+    setTimeout(() => {
+      if (generation !== injectGeneration) return;
+      isReloading = false;
+      setInitialLoading(false);
+    }, 100);
+  };
+
+  const injectPlugin = (plugin: JsPlugin, token: string) => {
     try {
       const previousCleanup = anyWindow.__dmn_custom_js_cleanup;
       if (previousCleanup) {
         delete anyWindow.__dmn_custom_js_cleanup;
       }
 
-      const pluginId = extractPluginId(plugin.content, plugin.name);
+      const pluginId = pluginKey(plugin);
 
       (anyWindow as any).__dmn_current_plugin_id = pluginId;
 
@@ -140,8 +175,8 @@ export function createCustomJsRuntime(): CustomJsRuntime {
           usePluginDisplayElementStore.getState().clearByPluginId(pluginId);
           displayElementInstanceRegistry.clearByPluginId(pluginId);
 
-          if (window.api?.bridge) {
-            window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+          if (api?.bridge) {
+            api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
               elements: usePluginDisplayElementStore.getState().elements,
             });
           }
@@ -156,24 +191,29 @@ export function createCustomJsRuntime(): CustomJsRuntime {
       // 플러그인용 API 프록시 생성
       const proxiedApi = createPluginApiProxy({
         pluginId,
+        token,
         registerCleanup: (cleanup) => registerCleanup(pluginId, cleanup),
         isReloading: getIsReloading,
       });
 
       // 플러그인용 Window 프록시 생성
       const proxyWindow = createPluginWindowProxy(proxiedApi);
-      (anyWindow as any).__dmn_plugin_window_proxy = proxyWindow;
+      // 플러그인 스크립트가 시작할 때 한 번만 가져가도록 전달 (다른 플러그인이 이 플러그인의 API를 얻지 못하게)
+      Object.defineProperty(anyWindow, "__dmn_plugin_window_proxy", {
+        configurable: true,
+        get() {
+          delete (anyWindow as any).__dmn_plugin_window_proxy;
+          return proxyWindow;
+        },
+      });
 
       const wrappedContent = `
 ;(function(window){
   'use strict';
   const __PLUGIN_ID__ = "${pluginId}";
   
-  // dmn을 전역 변수로 추가 (window. 없이 바로 접근 가능)
+  // dmn을 지역 변수로 추가 (window. 없이 바로 접근 가능)
   const dmn = window.api;
-  if (typeof globalThis !== 'undefined') {
-    globalThis.dmn = window.api;
-  }
   
   const __autoWrapAsync__ = () => {
     const globalWindow = typeof window !== 'undefined' ? window : globalThis;
@@ -256,7 +296,30 @@ ${plugin.content}
     }
   };
 
+  // 플러그인 윈도우에서는 윈도우를 연 플러그인과 그 의존성만 실행
+  const resolveWindowLoadOrder = () => {
+    const owner = (window as any).__dmn_plugin_window?.pluginId as
+      | string
+      | undefined;
+    if (!owner) return loadOrder;
+
+    const required = new Set<string>();
+    const visit = (key: string) => {
+      if (required.has(key)) return;
+      required.add(key);
+      const plugin = currentPlugins.find((item) => pluginKey(item) === key);
+      plugin?.manifest?.dependencies?.forEach(visit);
+    };
+    visit(owner);
+
+    return loadOrder.filter((id) => {
+      const plugin = currentPlugins.find((item) => item.id === id);
+      return !!plugin && required.has(pluginKey(plugin));
+    });
+  };
+
   const injectAll = () => {
+    const generation = ++injectGeneration;
     isReloading = true;
     setInitialLoading(true);
     removeAll();
@@ -266,19 +329,39 @@ ${plugin.content}
       return;
     }
 
-    currentPlugins
-      .filter((plugin) => plugin.enabled && plugin.content)
-      .forEach((plugin) => injectPlugin(plugin));
-
-    // 모든 플러그인의 복원이 완료될 때까지 딜레이 후 리로드 플래그 해제
-    setTimeout(() => {
-      isReloading = false;
-      setInitialLoading(false);
-    }, 100);
+    const plugins = resolveWindowLoadOrder()
+      .map((id) => currentPlugins.find((plugin) => plugin.id === id))
+      .filter(
+        (plugin): plugin is JsPlugin =>
+          !!plugin && plugin.enabled && !!plugin.content
+      );
+
+    // 로드 순서를 지키기 위해 모든 토큰을 받은 뒤 순서대로 실행
+    Promise.all(plugins.map((plugin) => tokenFor(plugin).catch(() => null)))
+      .then((tokens) => {
+        if (disposed || !enabled || generation !== injectGeneration) return;
+        plugins.forEach((plugin, index) => {
+          const token = tokens[index];
+          if (token) {
+            injectPlugin(plugin, token);
+          } else {
+            console.error(`Failed to authorize JS plugin '${plugin.name}'`);
+          }
+        });
+      })
+      .finally(() => finishReloading(generation));
   };
 
-  const syncPlugins = (next: JsPlugin[]) => {
-    currentPlugins = next.map((plugin) => ({ ...plugin }));
+  const syncPlugins = (state: JsState) => {
+    currentPlugins = (Array.isArray(state.plugins) ? state.plugins : []).map(
+      (plugin) => ({ ...plugin })
+    );
+    loadOrder = Array.isArray(state.loadOrder) ? state.loadOrder : [];
+    state.dependencyIssues?.forEach((issue) => {
+      console.warn(
+        `[Plugin] Skipped '${issue.pluginId}' (${issue.kind}: ${issue.dependencies.join(", ")})`
+      );
+    });
     if (enabled) {
       injectAll();
     } else {
@@ -286,18 +369,64 @@ ${plugin.content}
     }
   };
 
+  // 파일 변경으로 바뀐 플러그인 하나만 다시 실행
+  const reloadPlugin = (plugin: JsPlugin) => {
+    const index = currentPlugins.findIndex((item) => item.id === plugin.id);
+    if (index === -1) return;
+    currentPlugins[index] = { ...plugin };
+    if (!enabled) return;
+
+    isReloading = true;
+    setInitialLoading(true);
+    const previousPluginId = activeElements.get(plugin.id)?.pluginId;
+    removeElement(plugin.id);
+
+    // 선언된 ID가 바뀐 경우 이전 ID로 등록된 UI 정리
+    const nextPluginId = pluginKey(plugin);
+    if (
+      previousPluginId &&
+      previousPluginId !== nextPluginId &&
+      (window as any).__dmn_window_type === "main"
+    ) {
+      usePluginMenuStore.getState().clearByPluginId(previousPluginId);
+      usePluginDisplayElementStore.getState().clearByPluginId(previousPluginId);
+      displayElementInstanceRegistry.clearByPluginId(previousPluginId);
+    }
+
+    if (
+      !plugin.enabled ||
+      !plugin.content ||
+      !resolveWindowLoadOrder().includes(plugin.id)
+    ) {
+      finishReloading(injectGeneration);
+      return;
+    }
+
+    const generation = injectGeneration;
+    tokenFor(plugin)
+      .then((token) => {
+        if (disposed || !enabled || generation !== injectGeneration) return;
+        removeElement(plugin.id);
+        injectPlugin(plugin, token);
+      })
+      .catch((error) => {
+        console.error(`Failed to authorize JS plugin '${plugin.name}'`, error);
+      })
+      .finally(() => finishReloading(generation));
+  };
+
   const fetchInitialState = () => {
-    window.api.js
+    api.js
       .get()
       .then((data) => {
         if (disposed) return;
-        syncPlugins(Array.isArray(data.plugins) ? data.plugins : []);
+        syncPlugins(data);
       })
       .catch((error) => {
         console.error("Failed to fetch JS plugins", error);
       });
 
-    window.api.js
+    api.js
       .getUse()
       .then((value) => {
         if (disposed) return;
@@ -314,7 +443,7 @@ ${plugin.content}
   };
 
   const setupListeners = () => {
-    const unsubUse = window.api.js.onUse(({ enabled: next }) => {
+    const unsubUse = api.js.onUse(({ enabled: next }) => {
       enabled = next;
       if (enabled) {
         injectAll();
@@ -323,11 +452,19 @@ ${plugin.content}
       }
     });
 
-    const unsubState = window.api.js.onState((payload) => {
-      syncPlugins(Array.isArray(payload.plugins) ? payload.plugins : []);
+    const unsubState = api.js.onState((payload) => {
+      syncPlugins(payload);
+    });
+
+    const unsubReload = api.js.onPluginReload(({ plugin }) => {
+      reloadPlugin(plugin);
+    });
+
+    const unsubError = api.js.onPluginError(({ path, error }) => {
+      console.error(`Failed to hot reload JS plugin '${path}'`, error);
     });
 
-    unsubscribers.push(unsubUse, unsubState);
+    unsubscribers.push(unsubUse, unsubState, unsubReload, unsubError);
   };
 
   const cleanupSubscriptions = () => {
@@ -1,5 +1,6 @@
 import { create } from "zustand";
 import type { CustomTab, KeyMappings, KeyPositions } from "@src/types/keys";
+import api from "@api/dmnoteApi";
 
 interface KeyStoreState {
   selectedKeyType: string;
@@ -31,7 +32,7 @@ export const useKeyStore = create<KeyStoreState>((set, get) => ({
   setSelectedKeyType: (mode) => {
     set({ selectedKeyType: mode });
     if (get().isBootstrapped && typeof window !== "undefined") {
-      window.api.keys.setMode(mode).catch((error) => {
+      api.keys.setMode(mode).catch((error) => {
         console.error("Failed to set key mode", error);
       });
     }
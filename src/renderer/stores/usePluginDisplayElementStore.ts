@@ -3,6 +3,7 @@ import {
   PluginDisplayElementInternal,
   PluginDefinitionInternal,
 } from "@src/types/api";
+import api from "@api/dmnoteApi";
 
 // syncToOverlay 쓰로틀링을 위한 변수
 let syncScheduled = false;
@@ -146,8 +147,8 @@ export const usePluginDisplayElementStore = create<PluginDisplayElementStore>(
 // 메인 윈도우에서 오버레이로 동기화 (즉시 실행)
 function syncToOverlay(elements: PluginDisplayElementInternal[]) {
   try {
-    if (window.api?.bridge) {
-      window.api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
+    if (api?.bridge) {
+      api.bridge.sendTo("overlay", "plugin:displayElements:sync", {
         elements,
       });
     }
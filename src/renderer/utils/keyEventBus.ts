@@ -3,6 +3,8 @@
  * 백엔드에서 한 번만 구독하고, 플러그인들은 이 버스를 통해 이벤트 수신
  */
 
+import api from "@api/dmnoteApi";
+
 type KeyStatePayload = {
   key: string;
   state: string;
@@ -23,7 +25,7 @@ class KeyEventBus {
     this.initialized = true;
 
     // 백엔드에서 한 번만 구독
-    window.api.keys.onKeyState((payload) => {
+    api.keys.onKeyState((payload) => {
       // 모든 리스너에게 브로드캐스트
       this.listeners.forEach((listener) => {
         try {
@@ -3,6 +3,12 @@
  * 플러그인별로 컴포넌트 이벤트 핸들러를 등록하고 관리합니다.
  */
 
+import {
+  pluginPermissionSchema,
+  type JsPlugin,
+  type PluginPermission,
+} from "@src/types/js";
+
 let handlerIdCounter = 0;
 
 // 플러그인별 핸들러 맵: pluginId -> handlerId -> handler function
@@ -63,7 +69,7 @@ export function extractPluginId(content: string, filename: string): string {
   // 첫 20줄에서 @id 메타데이터 찾기
   const lines = content.split("\n").slice(0, 20);
   for (const line of lines) {
-    const match = line.match(/\/\/\s*@id:\s*([a-z0-9-_]+)/i);
+    const match = line.match(/\/\/\s*@id(?::\s*|\s+)([a-z0-9-_]+)/i);
     if (match) {
       return match[1].toLowerCase();
     }
@@ -77,3 +83,24 @@ export function extractPluginId(content: string, filename: string): string {
     .replace(/--+/g, "-")
     .replace(/^-|-$/g, "");
 }
+
+/**
+ * 사용자에게 허용받을 수 있는 권한 (백엔드의 `requestable_permissions`와 같은 규칙)
+ * `@permissions`를 선언하지 않은 플러그인은 모든 권한을 요청할 수 있습니다.
+ */
+export function getRequestablePermissions(plugin: JsPlugin): PluginPermission[] {
+  const declared = plugin.manifest?.permissions ?? [];
+  return declared.length > 0 ? declared : [...pluginPermissionSchema.options];
+}
+
+/**
+ * 아직 허용하지 않은 권한
+ * `grantedPermissions`가 없으면 권한 모델 도입 전 플러그인으로, 요청할 수 있는 권한이 모두 허용된 상태입니다.
+ */
+export function getPendingPermissions(plugin: JsPlugin): PluginPermission[] {
+  const granted = plugin.grantedPermissions;
+  if (!granted) return [];
+  return getRequestablePermissions(plugin).filter(
+    (permission) => !granted.includes(permission)
+  );
+}
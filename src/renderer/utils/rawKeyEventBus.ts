@@ -4,7 +4,7 @@
  * 구독자가 있을 때만 백엔드가 이벤트를 emit하도록 최적화
  */
 
-import { invoke } from "@tauri-apps/api/core";
+import { invokeAsApp } from "@api/ipcSession";
 import { listen } from "@tauri-apps/api/event";
 
 export type RawInputPayload = {
@@ -64,7 +64,7 @@ class RawKeyEventBus {
     if (wasEmpty) {
       await this.initialize();
       try {
-        await invoke("raw_input_subscribe");
+        await invokeAsApp("raw_input_subscribe");
       } catch (error) {
         console.error(
           "[RawKeyEventBus] Failed to subscribe to backend:",
@@ -89,7 +89,7 @@ class RawKeyEventBus {
     // 마지막 구독자가 제거되면 백엔드에 알림
     if (this.listeners.size === 0) {
       try {
-        await invoke("raw_input_unsubscribe");
+        await invokeAsApp("raw_input_unsubscribe");
       } catch (error) {
         console.error(
           "[RawKeyEventBus] Failed to unsubscribe from backend:",
@@ -115,7 +115,7 @@ class RawKeyEventBus {
 
     if (hadListeners) {
       try {
-        await invoke("raw_input_unsubscribe");
+        await invokeAsApp("raw_input_unsubscribe");
       } catch (error) {
         // 무시 - 앱 종료 시 발생할 수 있음
       }
@@ -17,6 +17,7 @@ import Palette from "@components/main/Modal/content/Palette";
 import ColorPicker from "@components/main/Modal/content/ColorPicker";
 import { useKeyStore } from "@stores/useKeyStore";
 import { useAppBootstrap } from "@hooks/useAppBootstrap";
+import api from "@api/dmnoteApi";
 
 export default function App() {
   const { selectedKeyType, setSelectedKeyType, isBootstrapped } = useKeyStore();
@@ -205,6 +206,40 @@ export default function App() {
     setAlertState({ isOpen: true, message, confirmText, type: "confirm" });
   };
 
+  // 명령줄(파일 열기, --profile)로 적용된 프리셋/프로필 결과 알림
+  useEffect(() => {
+    if (!isBootstrapped) return;
+    const showLaunchNotices = async () => {
+      try {
+        const notices = await api.app.takeLaunchNotices();
+        notices.forEach((notice) => {
+          if (notice.kind === "profile") {
+            showAlert(
+              t(
+                notice.success
+                  ? "preset.profileSwitched"
+                  : "preset.profileSwitchFail",
+                { name: notice.target }
+              )
+            );
+          } else {
+            showAlert(
+              notice.success
+                ? t("preset.loadSuccess")
+                : `${t("preset.loadFail")} (${notice.error ?? ""})`
+            );
+          }
+        });
+      } catch (error) {
+        console.error("Failed to read launch notices", error);
+      }
+    };
+    showLaunchNotices();
+    return api.app.onLaunchNotice(() => {
+      showLaunchNotices();
+    });
+  }, [isBootstrapped]);
+
   const closeAlert = () => {
     setAlertState({
       isOpen: false,
@@ -441,7 +476,7 @@ export default function App() {
           showConfirm(
             t("confirm.resetCountersCurrentTab"),
             async () => {
-              await window.api.keys.resetCountersMode(selectedKeyType);
+              await api.keys.resetCountersMode(selectedKeyType);
             },
             undefined,
             t("confirm.reset")
@@ -478,7 +513,7 @@ export default function App() {
           onClose={() => setIsNoteSettingOpen(false)}
           onSave={async (normalized) => {
             try {
-              await window.api.settings.update({ noteSettings: normalized });
+              await api.settings.update({ noteSettings: normalized });
               setNoteSettings(normalized);
             } catch (error) {
               console.error("Failed to update note settings", error);
@@ -500,7 +535,7 @@ export default function App() {
                 ...noteSettings,
                 ...payload,
               };
-              await window.api.settings.update({ noteSettings: updated });
+              await api.settings.update({ noteSettings: updated });
               setNoteSettings(updated);
             } catch (error) {
               console.error("Failed to update laboratory settings", error);
@@ -27,6 +27,7 @@ import {
 import KeyCounterLayer from "@components/overlay/KeyCounterLayer";
 import { PluginElementsRenderer } from "@components/PluginElementsRenderer";
 import { usePluginDisplayElementStore } from "@stores/usePluginDisplayElementStore";
+import api from "@api/dmnoteApi";
 
 const FALLBACK_POSITION: KeyPosition = {
   dx: 0,
@@ -410,7 +411,7 @@ export default function App() {
       contentTopOffset,
     };
 
-    window.api.overlay
+    api.overlay
       .resize({
         width: totalWidth,
         height: totalHeight,
@@ -0,0 +1,30 @@
+import { useEffect } from "react";
+import { useCustomJsInjection } from "@hooks/useCustomJsInjection";
+import { useAppBootstrap } from "@hooks/useAppBootstrap";
+
+/**
+ * 플러그인이 연 윈도우
+ * 윈도우를 연 플러그인(과 그 의존성)만 실행되며, 화면 구성은 플러그인이 담당합니다.
+ */
+export default function App() {
+  useCustomJsInjection();
+  useAppBootstrap();
+
+  // 윈도우 타입
+  useEffect(() => {
+    try {
+      (window as any).__dmn_window_type = "plugin";
+    } catch (e) {
+      // ignore
+    }
+    return () => {
+      try {
+        delete (window as any).__dmn_window_type;
+      } catch (e) {
+        // ignore
+      }
+    };
+  }, []);
+
+  return null;
+}
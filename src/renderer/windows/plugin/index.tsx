@@ -0,0 +1,16 @@
+import "@api/dmnoteApi";
+import React from "react";
+import { createRoot } from "react-dom/client";
+import App from "./App";
+import { I18nProvider } from "@contexts/I18nContext";
+import "@styles/global.css";
+
+const container = document.getElementById("root");
+if (!container) throw new Error("Root container not found");
+
+const root = createRoot(container);
+root.render(
+  <I18nProvider>
+    <App />
+  </I18nProvider>
+);
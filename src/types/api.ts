@@ -1,12 +1,14 @@
 import { BootstrapPayload } from "@src/types/app";
 import { CustomCss } from "@src/types/css";
-import { CustomJs, JsPlugin } from "@src/types/js";
+import { JsPlugin, JsState, PluginPermission } from "@src/types/js";
 import {
   CustomTab,
   KeyMappings,
+  KeyPosition,
   KeyPositions,
   KeyCounters,
 } from "@src/types/keys";
+import { NoteSettings } from "@src/types/noteSettings";
 import {
   SettingsDiff,
   SettingsPatchInput,
@@ -71,9 +73,13 @@ export type TabCssToggleResult = {
 export type JsTogglePayload = { enabled: boolean };
 export type JsSetContentResult = { success: boolean; error?: string };
 export type JsPluginError = { path: string; error: string };
+/** 파일 변경으로 다시 불러온 플러그인 */
+export type JsPluginReloadPayload = { plugin: JsPlugin };
 export type JsLoadResult = {
   success: boolean;
   added: JsPlugin[];
+  /** 선언된 @id가 같은 기존 플러그인을 갱신한 경우 */
+  updated?: JsPlugin[];
   errors?: JsPluginError[];
 };
 export type JsReloadResult = {
@@ -106,12 +112,201 @@ export type CustomTabDeleteResult = {
 };
 export type KeyCounterUpdate = { mode: string; key: string; count: number };
 
+export type RemoteServerSettings = {
+  enabled: boolean;
+  port: number;
+  /** `?token=` 쿼리 또는 `Authorization: Bearer` 헤더로 전달 */
+  token: string;
+  /** `POST /control` 허용 여부 (기본값 꺼짐) */
+  controlEnabled: boolean;
+  /** `POST /control` 전용 토큰 (읽기용 `token`과 별개) */
+  controlToken: string;
+};
+export type RemoteServerStatus = RemoteServerSettings & {
+  running: boolean;
+  clients: number;
+};
+/** `token`/`controlToken`을 빈 문자열로 보내면 새 토큰 생성 */
+export type RemoteServerPatch = Partial<RemoteServerSettings>;
+
+export type OscOutputSettings = {
+  enabled: boolean;
+  host: string;
+  port: number;
+  /** 초당 최대 눌림/kps 메시지 수 (넘는 메시지는 버림, 뗌은 항상 전송) */
+  maxMessagesPerSecond: number;
+};
+export type OscOutputStatus = OscOutputSettings & { running: boolean };
+export type OscOutputPatch = Partial<OscOutputSettings>;
+
+export type MidiMapping =
+  | { type: "note"; note: number }
+  | { type: "cc"; controller: number };
+/** 모드 → 키 이름 → 매핑 */
+export type MidiMappings = Record<string, Record<string, MidiMapping>>;
+export type MidiOutputSettings = {
+  enabled: boolean;
+  /** `file`은 메시지를 텍스트로 기록 (테스트용) */
+  backend: "port" | "file";
+  /** 출력 장치 이름 (비어 있으면 첫 번째 장치) */
+  portName: string;
+  filePath: string;
+  /** 1~16 */
+  channel: number;
+  /** 노트 온 벨로시티 및 CC 눌림 값 (1~127) */
+  velocity: number;
+  mappings: MidiMappings;
+};
+export type MidiOutputStatus = MidiOutputSettings & { running: boolean };
+export type MidiOutputPatch = Partial<Omit<MidiOutputSettings, "mappings">>;
+
+export type LaunchNotice = {
+  kind: "preset" | "profile";
+  /** 프리셋 경로 또는 프로필 이름 */
+  target: string;
+  success: boolean;
+  error?: string;
+};
+
 export type PresetOperationResult = { success: boolean; error?: string };
 
+export type RenderRecordingStatus = {
+  recording: boolean;
+  elapsedMs: number;
+  events: number;
+  /** 최대 이벤트 수를 넘어 일부를 버렸는지 여부 */
+  truncated: boolean;
+};
+export type RenderEvent = {
+  /** 녹화 시작 기준 시각 (ms) */
+  t: number;
+  mode: string;
+  key: string;
+  state: "down" | "up";
+};
+export type RenderScript = {
+  version: number;
+  recordedAt: number;
+  durationMs: number;
+  backgroundColor: string;
+  noteEffect: boolean;
+  noteSettings: NoteSettings;
+  /** `keys[i]`가 `positions[i]`에 그려짐 */
+  layouts: Record<string, { keys: string[]; positions: KeyPosition[] }>;
+  events: RenderEvent[];
+};
+export type RenderScriptExportResult = {
+  success: boolean;
+  /** 경로 없이 종료한 경우 */
+  script?: RenderScript;
+  path?: string;
+  events: number;
+  truncated: boolean;
+};
+export type RenderScriptIssue = {
+  field: string;
+  message: string;
+  line?: number;
+  column?: number;
+};
+export type RenderScriptValidation = {
+  valid: boolean;
+  error?: string;
+  issues?: RenderScriptIssue[];
+};
+
 export type BridgeMessage<T = any> = { type: string; data?: T };
 export type BridgeMessageListener<T = any> = (data: T) => void;
 export type BridgeAnyListener = (type: string, data: any) => void;
-export type WindowTarget = "main" | "overlay";
+/** 앱 윈도우 또는 플러그인 윈도우 레이블 */
+export type WindowTarget = "main" | "overlay" | (string & {});
+export type WindowType = "main" | "overlay" | "plugin";
+/** 플러그인 윈도우 페이지에 주입되는 정보 */
+export type PluginWindowInfo = {
+  label: string;
+  pluginId: string;
+  name: string;
+};
+export type PluginWindowOptions = {
+  title?: string;
+  width?: number;
+  height?: number;
+  x?: number;
+  y?: number;
+  resizable?: boolean;
+  decorations?: boolean;
+  transparent?: boolean;
+  alwaysOnTop?: boolean;
+};
+/** 브릿지로 메시지를 보낼 수 있는 윈도우 */
+export type BridgeWindowInfo = {
+  label: string;
+  pluginId?: string;
+  name?: string;
+};
+/** 다른 윈도우에서 `bridge.call`로 전달된 요청 */
+export type BridgeRequest<T = any> = {
+  requestId: string;
+  method: string;
+  data?: T;
+  /** 요청을 보낸 윈도우 레이블 */
+  from: string;
+  target: string;
+};
+export type BridgeRequestHandler<T = any, R = any> = (
+  data: T,
+  request: BridgeRequest<T>
+) => R | Promise<R>;
+export type BridgeCallOptions = {
+  /** 응답 대기 시간(ms, 기본 5000, 최대 60000) */
+  timeout?: number;
+};
+
+export type PluginStorageChange<T = any> = {
+  key: string;
+  oldValue: T | null;
+  newValue: T | null;
+};
+/** 일괄 작업은 변경된 키 전체가 하나의 이벤트로 전달됨 */
+export type PluginStorageChangeEvent<T = any> = {
+  pluginId: string | null;
+  /** 변경을 일으킨 윈도우 레이블 */
+  origin: string;
+  changes: PluginStorageChange<T>[];
+};
+export type PluginStorageChangeListener<T = any> = (
+  event: PluginStorageChangeEvent<T>
+) => void;
+/** expected가 있으면 현재 값이 같을 때만 적용 (null은 키가 없어야 함) */
+export type PluginStorageOp =
+  | { op: "set"; key: string; value: any; expected?: any }
+  | { op: "remove"; key: string; expected?: any };
+export type PluginStorageTransactionResult = {
+  success: boolean;
+  error?: "conflict";
+  conflicts?: { key: string; current: any }[];
+};
+export type PluginStorageApi = {
+  get<T = any>(key: string): Promise<T | null>;
+  set(key: string, value: any): Promise<void>;
+  remove(key: string): Promise<void>;
+  clear(): Promise<void>;
+  keys(): Promise<string[]>;
+  hasData(prefix: string): Promise<boolean>;
+  clearByPrefix(prefix: string): Promise<number>;
+  getMany<T = any>(keys: string[]): Promise<Record<string, T | null>>;
+  setMany(entries: Record<string, any>): Promise<void>;
+  transaction(ops: PluginStorageOp[]): Promise<PluginStorageTransactionResult>;
+  /** prefix를 지정하면 해당 접두사로 시작하는 키의 변경만 전달 */
+  onChanged<T = any>(
+    listener: PluginStorageChangeListener<T>,
+    prefix?: string
+  ): Unsubscribe;
+};
+export type JsRemoveOptions = {
+  /** 플러그인 저장소 데이터도 함께 삭제 */
+  purgeData?: boolean;
+};
 
 // UI Plugin 컨텍스트 메뉴 types
 export type KeyMenuContext = {
@@ -519,18 +714,57 @@ export interface DMNoteAPI {
     bootstrap(): Promise<BootstrapPayload>;
     openExternal(url: string): Promise<void>;
     restart(): Promise<void>;
+    /** 명령줄로 적용한 프리셋/프로필 결과 (가져간 결과는 비워짐) */
+    takeLaunchNotices(): Promise<LaunchNotice[]>;
+    onLaunchNotice(listener: () => void): Unsubscribe;
   };
   window: {
-    type: "main" | "overlay";
+    type: WindowType;
+    /** 플러그인 윈도우에서만 설정됨 */
+    plugin?: PluginWindowInfo;
     minimize(): Promise<void>;
+    /** 플러그인 윈도우에서는 해당 윈도우만 닫음 */
     close(): Promise<void>;
     openDevtoolsAll?(): Promise<void>;
+    openPluginWindow(
+      pluginId: string,
+      name: string,
+      options?: PluginWindowOptions
+    ): Promise<PluginWindowInfo>;
+    closePluginWindow(pluginId: string, name: string): Promise<boolean>;
+    /** 브릿지 대상이 될 수 있는 윈도우 목록 */
+    list(): Promise<BridgeWindowInfo[]>;
+    /** 플러그인 API에서만 제공: 자신의 윈도우 열기/닫기 */
+    open?(name: string, options?: PluginWindowOptions): Promise<PluginWindowInfo>;
+    closeWindow?(name: string): Promise<boolean>;
   };
   settings: {
     get(): Promise<SettingsState>;
     update(patch: SettingsPatchInput): Promise<SettingsState>;
     onChanged(listener: (diff: SettingsDiff) => void): Unsubscribe;
   };
+  /** 외부 프로그램용 로컬 WebSocket/HTTP 서버 */
+  remoteServer: {
+    get(): Promise<RemoteServerStatus>;
+    update(patch: RemoteServerPatch): Promise<RemoteServerStatus>;
+  };
+  /** 키 입력 MIDI 출력 */
+  midi: {
+    get(): Promise<MidiOutputStatus>;
+    update(patch: MidiOutputPatch): Promise<MidiOutputStatus>;
+    /** `mapping`이 null이면 해제 */
+    setMapping(
+      mode: string,
+      key: string,
+      mapping: MidiMapping | null
+    ): Promise<MidiOutputStatus>;
+    listPorts(): Promise<string[]>;
+  };
+  /** 키 입력 OSC 출력 */
+  osc: {
+    get(): Promise<OscOutputStatus>;
+    update(patch: OscOutputPatch): Promise<OscOutputStatus>;
+  };
   keys: {
     get(): Promise<KeyMappings>;
     update(mappings: KeyMappings): Promise<KeyMappings>;
@@ -601,28 +835,67 @@ export interface DMNoteAPI {
     };
   };
   js: {
-    get(): Promise<CustomJs>;
+    /** 플러그인 목록과 계산된 로드 순서 */
+    get(): Promise<JsState>;
     getUse(): Promise<boolean>;
     toggle(enabled: boolean): Promise<JsTogglePayload>;
     load(): Promise<JsLoadResult>;
     reload(): Promise<JsReloadResult>;
-    remove(id: string): Promise<JsRemoveResult>;
+    remove(id: string, options?: JsRemoveOptions): Promise<JsRemoveResult>;
     setPluginEnabled(
       id: string,
       enabled: boolean
     ): Promise<JsPluginUpdateResult>;
+    /** 헤더에 선언된 권한 중 사용자가 허용한 목록 설정 */
+    setPluginPermissions(
+      id: string,
+      permissions: PluginPermission[]
+    ): Promise<JsPluginUpdateResult>;
+    /** 로드 순서 변경 (나열하지 않은 플러그인은 기존 순서대로 뒤에 배치) */
+    reorder(ids: string[]): Promise<JsState>;
     setContent(content: string): Promise<JsSetContentResult>;
     reset(): Promise<void>;
+    /** 관리 플러그인 폴더를 열고 경로 반환 */
+    openPluginDir(): Promise<string>;
     onUse(listener: (payload: JsTogglePayload) => void): Unsubscribe;
-    onState(listener: (payload: CustomJs) => void): Unsubscribe;
+    onState(listener: (payload: JsState) => void): Unsubscribe;
+    /** 플러그인 파일이 바뀌어 해당 플러그인만 다시 불러온 경우 */
+    onPluginReload(
+      listener: (payload: JsPluginReloadPayload) => void
+    ): Unsubscribe;
+    /** 핫리로드 중 파일을 읽지 못한 경우 */
+    onPluginError(listener: (payload: JsPluginError) => void): Unsubscribe;
   };
   presets: {
     save(): Promise<PresetOperationResult>;
     load(): Promise<PresetOperationResult>;
   };
+  /** 입력 녹화 및 렌더 스크립트 */
+  renderScript: {
+    startRecording(): Promise<RenderRecordingStatus>;
+    getStatus(): Promise<RenderRecordingStatus>;
+    /** `path`가 없으면 스크립트를 결과로 반환 */
+    stopRecording(path?: string): Promise<RenderScriptExportResult>;
+    validate(source: {
+      path?: string;
+      payload?: RenderScript | string;
+    }): Promise<RenderScriptValidation>;
+  };
   bridge: {
     send(type: string, data?: any): Promise<void>;
     sendTo(target: WindowTarget, type: string, data?: any): Promise<void>;
+    /** 대상 윈도우의 `handle` 핸들러를 호출하고 반환값을 기다림 */
+    call<R = any, T = any>(
+      target: WindowTarget,
+      method: string,
+      data?: T,
+      options?: BridgeCallOptions
+    ): Promise<R>;
+    /** 이 윈도우에서 `call` 요청을 처리할 핸들러 등록 (메서드당 하나) */
+    handle<T = any, R = any>(
+      method: string,
+      handler: BridgeRequestHandler<T, R>
+    ): Unsubscribe;
     on<T = any>(type: string, listener: BridgeMessageListener<T>): Unsubscribe;
     once<T = any>(
       type: string,
@@ -636,14 +909,10 @@ export interface DMNoteAPI {
     onLocaleChange(listener: (locale: string) => void): Unsubscribe;
   };
   plugin: {
-    storage: {
-      get<T = any>(key: string): Promise<T | null>;
-      set(key: string, value: any): Promise<void>;
-      remove(key: string): Promise<void>;
-      clear(): Promise<void>;
-      keys(): Promise<string[]>;
-      hasData(prefix: string): Promise<boolean>;
-      clearByPrefix(prefix: string): Promise<number>;
+    /** 공유 키 공간(store.json) */
+    storage: PluginStorageApi & {
+      /** 플러그인별 저장소 (별도 파일, 플러그인별 용량 제한) */
+      namespace(pluginId: string): PluginStorageApi;
     };
     registerCleanup(cleanup: () => void): void;
     defineElement(definition: PluginDefinition): void;
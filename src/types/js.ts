@@ -1,11 +1,41 @@
 import { z } from "zod";
 
+// 플러그인이 헤더의 @permissions로 요청하는 권한
+export const pluginPermissionSchema = z.enum([
+  "storage",
+  "bridge",
+  "raw-input",
+  "external",
+  "settings",
+  "keys",
+  "presets",
+  "windows",
+]);
+
+// 플러그인 헤더 주석(@id, @name, @version ...)에서 읽은 메타데이터
+export const pluginManifestSchema = z.object({
+  id: z.string().optional(),
+  name: z.string().optional(),
+  version: z.string().optional(),
+  author: z.string().optional(),
+  description: z.string().optional(),
+  minAppVersion: z.string().optional(),
+  target: z.enum(["main", "overlay", "all"]).optional(),
+  permissions: z.array(pluginPermissionSchema).optional(),
+  // @requires로 선언한, 먼저 로드되어야 하는 플러그인 ID
+  dependencies: z.array(z.string()).optional(),
+});
+
 export const jsPluginSchema = z.object({
   id: z.string(),
   name: z.string(),
   path: z.string().nullable(),
   content: z.string(),
   enabled: z.boolean(),
+  manifest: pluginManifestSchema.optional(),
+  // 없으면 권한 모델 도입 전에 설치된 플러그인 (선언된 권한 모두 허용)
+  grantedPermissions: z.array(pluginPermissionSchema).optional(),
+  order: z.number().optional(),
 });
 
 export const customJsSchema = z.object({
@@ -14,5 +44,21 @@ export const customJsSchema = z.object({
   plugins: z.array(jsPluginSchema).default([]),
 });
 
+// 백엔드가 계산한 로드 순서와 로드하지 못한 플러그인
+export const pluginDependencyIssueSchema = z.object({
+  pluginId: z.string(),
+  kind: z.enum(["missing", "disabled", "cycle", "blocked"]),
+  dependencies: z.array(z.string()),
+});
+
+export const jsStateSchema = customJsSchema.extend({
+  loadOrder: z.array(z.string()).default([]),
+  dependencyIssues: z.array(pluginDependencyIssueSchema).optional(),
+});
+
+export type PluginPermission = z.infer<typeof pluginPermissionSchema>;
+export type PluginManifest = z.infer<typeof pluginManifestSchema>;
 export type JsPlugin = z.infer<typeof jsPluginSchema>;
 export type CustomJs = z.infer<typeof customJsSchema>;
+export type PluginDependencyIssue = z.infer<typeof pluginDependencyIssueSchema>;
+export type JsState = z.infer<typeof jsStateSchema>;
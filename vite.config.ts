@@ -13,7 +13,7 @@ export default defineConfig(() => {
   const isAnalyze = process.env.ANALYZE === "true";
 
   return {
-    // Vite 개발 서버 루트: /main/index.html, /overlay/index.html 경로로 접근 가능
+    // Vite 개발 서버 루트: /main/index.html, /overlay/index.html, /plugin/index.html 경로로 접근 가능
     root: windowsRoot,
     base: "./",
     plugins: [
@@ -77,6 +77,7 @@ export default defineConfig(() => {
         input: {
           main: path.resolve(windowsRoot, "main/index.html"),
           overlay: path.resolve(windowsRoot, "overlay/index.html"),
+          plugin: path.resolve(windowsRoot, "plugin/index.html"),
         },
       },
     },